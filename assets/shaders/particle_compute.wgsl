@@ -28,12 +28,14 @@
 // Forces de nourriture par type
 @group(0) @binding(13) var<storage, read> food_forces: array<f32>;
 
+// Constantes physiques configurables (GlobalConfig / config.toml)
+@group(0) @binding(14) var<uniform> force_scale_factor: f32;
+@group(0) @binding(15) var<uniform> max_velocity: f32;
+
 // Constantes physiques
 const PARTICLE_RADIUS: f32 = 2.5;
 const FOOD_RADIUS: f32 = 1.0;
 const MIN_DISTANCE: f32 = 0.001;
-const FORCE_SCALE_FACTOR: f32 = 80.0;
-const MAX_VELOCITY: f32 = 200.0;
 const VELOCITY_HALF_LIFE: f32 = 0.043;
 const MAX_INTERACTIONS_PER_PARTICLE: u32 = 100;
 
@@ -204,13 +206,13 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
         interactions_count++;
 
-        let attraction = get_force_between_types(current_type, other_type) * FORCE_SCALE_FACTOR;
+        let attraction = get_force_between_types(current_type, other_type) * force_scale_factor;
         let accel = acceleration(min_distance, distance_vec, attraction, max_force_range);
         total_force += accel;
     }
 
     // Forces avec la nourriture
-    let particle_food_force = food_forces[current_type] * FORCE_SCALE_FACTOR;
+    let particle_food_force = food_forces[current_type] * force_scale_factor;
 
     if (abs(particle_food_force) > 0.001) {
         for (var i = 0u; i < food_count; i++) {
@@ -246,8 +248,8 @@ fn main(@builtin(global_invocation_id) global_id: vec3<u32>) {
 
     // Limiter la vitesse
     let speed = length(new_vel);
-    if (speed > MAX_VELOCITY) {
-        new_vel = normalize(new_vel) * MAX_VELOCITY;
+    if (speed > max_velocity) {
+        new_vel = normalize(new_vel) * max_velocity;
     }
 
     // Appliquer la vélocité
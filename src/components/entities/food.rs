@@ -20,7 +20,11 @@ impl Default for FoodRespawnTimer {
     }
 }
 
+/// Type de nourriture (0, 1, 2, etc.)
+#[derive(Component, Clone, Copy, Debug, Default)]
+pub struct FoodType(pub usize);
+
 /// Marqueur pour la nourriture
 #[derive(Component)]
-#[require(FoodValue, FoodRespawnTimer, Transform, Mesh3d, MeshMaterial3d<StandardMaterial>)]
+#[require(FoodValue, FoodType, FoodRespawnTimer, Transform, Mesh3d, MeshMaterial3d<StandardMaterial>)]
 pub struct Food;
@@ -1,3 +1,5 @@
 pub mod food;
+pub mod obstacle;
 pub mod particle;
-pub mod simulation;
\ No newline at end of file
+pub mod simulation;
+pub mod trail;
\ No newline at end of file
@@ -0,0 +1,79 @@
+use crate::globals::COLLISION_DAMPING;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Forme d'un obstacle, centrée sur sa position
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum ObstacleShape {
+    Sphere { radius: f32 },
+    Box { half_extents: Vec3 },
+}
+
+impl ObstacleShape {
+    /// Ressort une particule qui pénètre l'obstacle et réfléchit sa vitesse amortie
+    pub fn resolve_collision(
+        &self,
+        center: Vec3,
+        position: &mut Vec3,
+        velocity: &mut Vec3,
+        particle_radius: f32,
+    ) {
+        let offset = *position - center;
+
+        let normal = match *self {
+            ObstacleShape::Sphere { radius } => {
+                let min_distance = radius + particle_radius;
+                let distance = offset.length();
+                if distance >= min_distance {
+                    return;
+                }
+
+                // Particule exactement au centre : sortie vers le haut
+                let normal = offset.try_normalize().unwrap_or(Vec3::Y);
+                *position = center + normal * min_distance;
+                normal
+            }
+            ObstacleShape::Box { half_extents } => {
+                let expanded = half_extents + Vec3::splat(particle_radius);
+                let penetration = expanded - offset.abs();
+                if penetration.min_element() <= 0.0 {
+                    return;
+                }
+
+                // Sortie par la face la plus proche
+                let axis = if penetration.x <= penetration.y && penetration.x <= penetration.z {
+                    0
+                } else if penetration.y <= penetration.z {
+                    1
+                } else {
+                    2
+                };
+                let side = if offset[axis] >= 0.0 { 1.0 } else { -1.0 };
+
+                let mut normal = Vec3::ZERO;
+                normal[axis] = side;
+                position[axis] = center[axis] + side * expanded[axis];
+                normal
+            }
+        };
+
+        let normal_speed = velocity.dot(normal);
+        if normal_speed < 0.0 {
+            *velocity -= normal * normal_speed * (1.0 + COLLISION_DAMPING);
+        }
+    }
+
+    pub fn mesh(&self) -> Mesh {
+        match *self {
+            ObstacleShape::Sphere { radius } => Sphere::new(radius).mesh().ico(3).unwrap(),
+            ObstacleShape::Box { half_extents } => Cuboid::from_size(half_extents * 2.0).into(),
+        }
+    }
+}
+
+/// Obstacle statique sur lequel rebondissent les particules de toutes les simulations
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Transform, Mesh3d, MeshMaterial3d<StandardMaterial>)]
+pub struct Obstacle {
+    pub shape: ObstacleShape,
+}
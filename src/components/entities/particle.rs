@@ -8,6 +8,17 @@ pub struct ParticleType(pub usize);
 #[derive(Component, Default, Clone, Copy, Debug)]
 pub struct Velocity(pub Vec3);
 
+/// Âge de la particule (en secondes simulées) et durée de vie maximale
+#[derive(Component, Clone, Copy, Debug)]
+pub struct Age {
+    pub current: f32,
+    pub max: f32,
+}
+
+/// Particule née pendant l'époque grâce au budget d'énergie, retirée à la suivante
+#[derive(Component)]
+pub struct Offspring;
+
 /// Marqueur pour identifier une particule
 #[derive(Component)]
 #[require(ParticleType, Velocity, Transform, Mesh3d, MeshMaterial3d<StandardMaterial>)]
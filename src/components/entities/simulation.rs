@@ -8,5 +8,13 @@ pub struct SimulationId(pub usize);
 
 /// Marqueur pour une simulation
 #[derive(Component)]
-#[require(SimulationId, Genotype, Score, Transform, Visibility, InheritedVisibility, ViewVisibility)]
-pub struct Simulation;
\ No newline at end of file
+#[require(SimulationId, Genotype, Score, FoodEatenCount, ExplorationScore, ClusterScore, NoveltyScore, SecondaryScore, TotalDistanceTravelled, FoodEfficiency, Transform, Visibility, InheritedVisibility, ViewVisibility)]
+pub struct Simulation;
+
+/// Réserve d'énergie de la simulation (budget d'énergie activé uniquement)
+#[derive(Component, Debug, Clone, Copy)]
+pub struct Energy(pub f32);
+
+/// Simulation à court d'énergie : particules cachées, score figé jusqu'à la prochaine époque
+#[derive(Component)]
+pub struct Extinct;
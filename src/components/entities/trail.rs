@@ -0,0 +1,37 @@
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// Historique des positions récentes d'une particule (capacité fixe)
+#[derive(Component, Clone, Debug, Default)]
+pub struct TrailHistory {
+    pub positions: VecDeque<Vec3>,
+    pub capacity: usize,
+}
+
+impl TrailHistory {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            positions: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    /// Ajoute une position et retire la plus ancienne si la capacité est atteinte
+    pub fn push(&mut self, position: Vec3) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.positions.len() >= self.capacity {
+            self.positions.pop_front();
+        }
+        self.positions.push_back(position);
+    }
+
+    pub fn clear(&mut self) {
+        self.positions.clear();
+    }
+}
+
+/// Marqueur pour l'entité gizmo qui dessine les traînées d'une simulation
+#[derive(Component)]
+pub struct ParticleTrails;
@@ -1,3 +1,5 @@
+use crate::globals::DEFAULT_MUTATION_RATE;
+use crate::resources::config::simulation::GenomeEncoding;
 use bevy::prelude::*;
 use rand::Rng;
 
@@ -5,23 +7,63 @@ use rand::Rng;
 #[derive(Component, Clone, Debug, Default)]
 pub struct Genotype {
     pub force_matrix: Vec<f32>,  // Matrice des forces particule-particule
-    pub food_forces: Vec<f32>,   // Forces de nourriture par type
+    pub food_forces: Vec<f32>,   // Forces [type de nourriture][type de particule]
     pub type_count: usize,
+    pub food_type_count: usize,
+    pub lineage: Option<Vec<usize>>, // IDs des simulations parentes
+    pub meta_mutation_rate: f32,     // Taux de mutation propre au génome (auto-adaptatif)
 }
 
+/// Configurations de forces prédéfinies applicables à un génome
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GenomePreset {
+    Interesting,
+    Symmetric,
+    Antisymmetric,
+}
+
+impl GenomePreset {
+    pub const ALL: [GenomePreset; 3] = [
+        GenomePreset::Interesting,
+        GenomePreset::Symmetric,
+        GenomePreset::Antisymmetric,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GenomePreset::Interesting => "✨ Intéressant",
+            GenomePreset::Symmetric => "⇄ Symétrique",
+            GenomePreset::Antisymmetric => "⇆ Antisymétrique",
+        }
+    }
+
+    pub fn apply(&self, genotype: &mut Genotype, rng: &mut impl Rng) {
+        match self {
+            GenomePreset::Interesting => genotype.set_interesting_forces(rng),
+            GenomePreset::Symmetric => genotype.preset_symmetric(rng),
+            GenomePreset::Antisymmetric => genotype.preset_antisymmetric(rng),
+        }
+    }
+}
+
+/// Bornes du taux de mutation auto-adaptatif
+const META_MUTATION_RATE_RANGE: (f32, f32) = (0.001, 0.5);
+
 impl Genotype {
-    pub fn new(type_count: usize) -> Self {
+    pub fn new(type_count: usize, food_type_count: usize) -> Self {
         let matrix_size = type_count * type_count;
         Self {
             force_matrix: vec![0.0; matrix_size],
-            food_forces: vec![0.0; type_count],
+            food_forces: vec![0.0; food_type_count * type_count],
             type_count,
+            food_type_count,
+            lineage: None,
+            meta_mutation_rate: DEFAULT_MUTATION_RATE,
         }
     }
 
     /// Génère un génome aléatoire
-    pub fn random(type_count: usize) -> Self {
-        let mut rng = rand::rng();
+    pub fn random(type_count: usize, food_type_count: usize, rng: &mut impl Rng) -> Self {
         let matrix_size = type_count * type_count;
 
         let force_matrix = (0..matrix_size)
@@ -39,7 +81,7 @@ impl Genotype {
             })
             .collect();
 
-        let food_forces = (0..type_count)
+        let food_forces = (0..food_type_count * type_count)
             .map(|_| rng.random_range(-1.0..=1.0))
             .collect();
 
@@ -47,6 +89,39 @@ impl Genotype {
             force_matrix,
             food_forces,
             type_count,
+            food_type_count,
+            lineage: None,
+            meta_mutation_rate: DEFAULT_MUTATION_RATE,
+        }
+    }
+
+    /// Génome aléatoire dont les gènes sont sur la grille de l'encodage
+    pub fn random_encoded(
+        type_count: usize,
+        food_type_count: usize,
+        encoding: GenomeEncoding,
+        rng: &mut impl Rng,
+    ) -> Self {
+        let mut genotype = Self::random(type_count, food_type_count, rng);
+        genotype.quantise(encoding);
+
+        // L'arrondi ne doit pas annuler l'auto-répulsion
+        if let Some(step) = encoding.step() {
+            for i in 0..type_count {
+                let index = i * type_count + i;
+                genotype.force_matrix[index] = genotype.force_matrix[index].min(-step);
+            }
+        }
+        genotype
+    }
+
+    /// Ramène tous les gènes sur la grille de l'encodage (sans effet en flottant)
+    pub fn quantise(&mut self, encoding: GenomeEncoding) {
+        if encoding == GenomeEncoding::Float32 {
+            return;
+        }
+        for gene in self.force_matrix.iter_mut().chain(self.food_forces.iter_mut()) {
+            *gene = encoding.snap(*gene);
         }
     }
 
@@ -56,6 +131,32 @@ impl Genotype {
         self.force_matrix.get(index).copied().unwrap_or(0.0)
     }
 
+    /// Force exprimée : un gène sous le seuil d'expression est silencieux (0)
+    pub fn expressed_force(&self, type_a: usize, type_b: usize, threshold: f32) -> f32 {
+        expressed(self.get_force(type_a, type_b), threshold)
+    }
+
+    /// Matrice des forces après seuil d'expression, dans l'ordre de `force_matrix`
+    pub fn expressed_force_matrix(&self, threshold: f32) -> Vec<f32> {
+        self.force_matrix
+            .iter()
+            .map(|&force| expressed(force, threshold))
+            .collect()
+    }
+
+    /// Part des gènes particule-particule non nuls après seuil d'expression
+    pub fn active_gene_ratio(&self, threshold: f32) -> f32 {
+        if self.force_matrix.is_empty() {
+            return 0.0;
+        }
+        let active = self
+            .force_matrix
+            .iter()
+            .filter(|&&force| expressed(force, threshold) != 0.0)
+            .count();
+        active as f32 / self.force_matrix.len() as f32
+    }
+
     /// Définit la force entre deux types
     pub fn set_force(&mut self, type_a: usize, type_b: usize, force: f32) {
         let index = type_a * self.type_count + type_b;
@@ -64,9 +165,37 @@ impl Genotype {
         }
     }
 
-    /// Obtient la force de nourriture pour un type
-    pub fn get_food_force(&self, particle_type: usize) -> f32 {
-        self.food_forces.get(particle_type).copied().unwrap_or(0.0)
+    /// Obtient la force d'un type de nourriture sur un type de particule
+    pub fn get_food_force(&self, particle_type: usize, food_type: usize) -> f32 {
+        let index = food_type * self.type_count + particle_type;
+        self.food_forces.get(index).copied().unwrap_or(0.0)
+    }
+
+    /// Copie les forces d'un autre génome en gardant ses propres dimensions :
+    /// les types absents de `other` reçoivent des forces nulles, ceux en trop sont ignorés
+    pub fn copy_from(&mut self, other: &Genotype) {
+        let type_count = self.type_count;
+        self.force_matrix = (0..type_count * type_count)
+            .map(|index| {
+                let (type_a, type_b) = (index / type_count, index % type_count);
+                if type_a < other.type_count && type_b < other.type_count {
+                    other.get_force(type_a, type_b)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.food_forces = (0..self.food_type_count * type_count)
+            .map(|index| {
+                let (food_type, particle_type) = (index / type_count, index % type_count);
+                if food_type < other.food_type_count && particle_type < other.type_count {
+                    other.get_food_force(particle_type, food_type)
+                } else {
+                    0.0
+                }
+            })
+            .collect();
+        self.meta_mutation_rate = other.meta_mutation_rate;
     }
 
     /// Crossover avec un autre génome
@@ -96,15 +225,85 @@ impl Genotype {
             force_matrix: new_force_matrix,
             food_forces: new_food_forces,
             type_count: self.type_count,
+            food_type_count: self.food_type_count,
+            lineage: None,
+            meta_mutation_rate: (self.meta_mutation_rate + other.meta_mutation_rate) / 2.0,
+        }
+    }
+
+    /// Crossover BLX-α : chaque gène est tiré dans l'intervalle des parents élargi de α
+    pub fn crossover_blend(
+        parent1: &Genotype,
+        parent2: &Genotype,
+        alpha: f32,
+        rng: &mut impl Rng,
+    ) -> Genotype {
+        let mut blend_gene = |g1: f32, g2: f32| {
+            let spread = (g2 - g1).abs() * alpha;
+            let low = g1.min(g2) - spread;
+            let high = g1.max(g2) + spread;
+            rng.random_range(low..=high).clamp(-2.0, 2.0)
+        };
+
+        let force_matrix = parent1
+            .force_matrix
+            .iter()
+            .zip(&parent2.force_matrix)
+            .map(|(&g1, &g2)| blend_gene(g1, g2))
+            .collect();
+
+        let food_forces = parent1
+            .food_forces
+            .iter()
+            .zip(&parent2.food_forces)
+            .map(|(&g1, &g2)| blend_gene(g1, g2))
+            .collect();
+
+        Genotype {
+            force_matrix,
+            food_forces,
+            type_count: parent1.type_count,
+            food_type_count: parent1.food_type_count,
+            lineage: None,
+            meta_mutation_rate: (parent1.meta_mutation_rate + parent2.meta_mutation_rate) / 2.0,
+        }
+    }
+
+    /// Interpolation gène à gène : `a` pour `blend` = 0, `b` pour `blend` = 1
+    pub fn interpolate(a: &Genotype, b: &Genotype, blend: f32) -> Genotype {
+        let blend = blend.clamp(0.0, 1.0);
+        let mix = |g1: &f32, g2: &f32| (g1 * (1.0 - blend) + g2 * blend).clamp(-2.0, 2.0);
+
+        Genotype {
+            force_matrix: a
+                .force_matrix
+                .iter()
+                .zip(&b.force_matrix)
+                .map(|(g1, g2)| mix(g1, g2))
+                .collect(),
+            food_forces: a
+                .food_forces
+                .iter()
+                .zip(&b.food_forces)
+                .map(|(g1, g2)| mix(g1, g2))
+                .collect(),
+            type_count: a.type_count,
+            food_type_count: a.food_type_count,
+            lineage: None,
+            meta_mutation_rate: a.meta_mutation_rate * (1.0 - blend)
+                + b.meta_mutation_rate * blend,
         }
     }
 
-    /// Applique une mutation
-    pub fn mutate(&mut self, mutation_rate: f32, rng: &mut impl Rng) {
+    /// Applique une mutation. En encodage quantifié, le saut couvre au moins un pas de grille
+    /// et tout le génome est ramené sur la grille (les crossovers mélangeants en sortent)
+    pub fn mutate(&mut self, mutation_rate: f32, encoding: GenomeEncoding, rng: &mut impl Rng) {
+        let amplitude = encoding.step().map_or(0.2, |step| step.max(0.2));
+
         // Mutation de la matrice des forces
         for force in &mut self.force_matrix {
             if rng.random::<f32>() < mutation_rate {
-                *force += rng.random_range(-0.2..=0.2);
+                *force += rng.random_range(-amplitude..=amplitude);
                 *force = force.clamp(-2.0, 2.0);
             }
         }
@@ -112,10 +311,17 @@ impl Genotype {
         // Mutation des forces de nourriture
         for force in &mut self.food_forces {
             if rng.random::<f32>() < mutation_rate * 0.5 {
-                *force += rng.random_range(-0.2..=0.2);
+                *force += rng.random_range(-amplitude..=amplitude);
                 *force = force.clamp(-2.0, 2.0);
             }
         }
+
+        self.quantise(encoding);
+
+        // Le taux de mutation évolue lui aussi
+        let (min_rate, max_rate) = META_MUTATION_RATE_RANGE;
+        self.meta_mutation_rate =
+            (self.meta_mutation_rate * rng.random_range(0.9..=1.1)).clamp(min_rate, max_rate);
     }
 
     /// Retourne une matrice de toutes les forces d'interaction
@@ -132,7 +338,7 @@ impl Genotype {
     }
 
     /// Génère des forces intéressantes prédéfinies
-    pub fn set_interesting_forces(&mut self) {
+    pub fn set_interesting_forces(&mut self, rng: &mut impl Rng) {
         // Efface les forces actuelles
         self.force_matrix.fill(0.0);
         self.food_forces.fill(0.0);
@@ -153,7 +359,7 @@ impl Genotype {
                 }
 
                 // Forces de nourriture variées
-                self.food_forces = vec![0.8, -0.3, 0.5];
+                self.fill_food_forces(&[0.8, -0.3, 0.5]);
             },
             4 => {
                 // Configuration plus complexe
@@ -174,11 +380,10 @@ impl Genotype {
                 }
 
                 // Forces de nourriture équilibrées
-                self.food_forces = vec![0.6, -0.4, 0.8, -0.2];
+                self.fill_food_forces(&[0.6, -0.4, 0.8, -0.2]);
             },
             _ => {
                 // Configuration aléatoire pour autres nombres de types
-                let mut rng = rand::rng();
                 for i in 0..self.type_count {
                     for j in 0..self.type_count {
                         let force = if i == j {
@@ -188,9 +393,164 @@ impl Genotype {
                         };
                         self.set_force(i, j, force);
                     }
-                    self.food_forces[i] = rng.random_range(-1.0..=1.0);
                 }
+                for force in &mut self.food_forces {
+                    *force = rng.random_range(-1.0..=1.0);
+                }
+            }
+        }
+    }
+
+    /// Rend les interactions réciproques : chaque paire prend la moyenne de ses deux forces
+    pub fn enforce_symmetry(&mut self) {
+        let n = self.type_count;
+        for a in 0..n {
+            for b in (a + 1)..n {
+                let mean = (self.force_matrix[a * n + b] + self.force_matrix[b * n + a]) / 2.0;
+                self.force_matrix[a * n + b] = mean;
+                self.force_matrix[b * n + a] = mean;
+            }
+        }
+    }
+
+    /// Asymétrie moyenne |force(i, j) - force(j, i)| sur les paires de types distincts
+    pub fn asymmetry(&self) -> f32 {
+        let n = self.type_count;
+        let pair_count = n * n.saturating_sub(1) / 2;
+        if pair_count == 0 {
+            return 0.0;
+        }
+
+        let total: f32 = (0..n)
+            .flat_map(|a| ((a + 1)..n).map(move |b| (a, b)))
+            .map(|(a, b)| (self.get_force(a, b) - self.get_force(b, a)).abs())
+            .sum();
+        total / pair_count as f32
+    }
+
+    /// Forces aléatoires symétriques : force(i, j) = force(j, i)
+    pub fn preset_symmetric(&mut self, rng: &mut impl Rng) {
+        self.randomize_pairs(rng, |force| force);
+    }
+
+    /// Forces aléatoires antisymétriques : force(j, i) = -force(i, j), hors auto-répulsion
+    pub fn preset_antisymmetric(&mut self, rng: &mut impl Rng) {
+        self.randomize_pairs(rng, |force| -force);
+    }
+
+    /// Tire les forces au-dessus de la diagonale et en déduit leur miroir
+    fn randomize_pairs(&mut self, rng: &mut impl Rng, mirror: impl Fn(f32) -> f32) {
+
+        for i in 0..self.type_count {
+            self.set_force(i, i, rng.random_range(-0.5..=-0.1));
+            for j in (i + 1)..self.type_count {
+                let force = rng.random_range(-1.0..=1.0);
+                self.set_force(i, j, force);
+                self.set_force(j, i, mirror(force));
             }
         }
+
+        for force in &mut self.food_forces {
+            *force = rng.random_range(-1.0..=1.0);
+        }
     }
-}
\ No newline at end of file
+
+    /// Applique les mêmes forces de nourriture (par type de particule) à chaque type de nourriture
+    fn fill_food_forces(&mut self, per_particle_type: &[f32]) {
+        for food_type in 0..self.food_type_count {
+            for (particle_type, force) in per_particle_type.iter().enumerate() {
+                let index = food_type * self.type_count + particle_type;
+                if let Some(target) = self.food_forces.get_mut(index) {
+                    *target = *force;
+                }
+            }
+        }
+    }
+}
+
+/// Distance euclidienne entre deux matrices de forces (espace des génomes)
+pub fn calculate_genotype_distance(a: &Genotype, b: &Genotype) -> f32 {
+    a.force_matrix
+        .iter()
+        .zip(&b.force_matrix)
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Nombre de classes de l'histogramme utilisé pour l'entropie
+const ENTROPY_BINS: usize = 32;
+/// Intervalle discrétisé ; les forces hors bornes tombent dans les classes extrêmes
+const ENTROPY_RANGE: (f32, f32) = (-2.0, 2.0);
+
+/// Entropie de Shannon (bits) des forces particule-particule discrétisées en 32 classes
+pub fn entropy(genotype: &Genotype) -> f32 {
+    if genotype.force_matrix.is_empty() {
+        return 0.0;
+    }
+
+    let (min, max) = ENTROPY_RANGE;
+    let mut bins = [0usize; ENTROPY_BINS];
+    for force in &genotype.force_matrix {
+        let normalized = (force - min) / (max - min);
+        let bin = ((normalized * ENTROPY_BINS as f32) as usize).min(ENTROPY_BINS - 1);
+        bins[bin] += 1;
+    }
+
+    let total = genotype.force_matrix.len() as f32;
+    bins.iter()
+        .filter(|&&count| count > 0)
+        .map(|&count| {
+            let p = count as f32 / total;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Entropie moyenne d'une population de génomes
+pub fn average_entropy<'a>(genotypes: impl IntoIterator<Item = &'a Genotype>) -> f32 {
+    let (sum, count) = genotypes.into_iter().fold((0.0, 0usize), |(sum, count), genotype| {
+        (sum + entropy(genotype), count + 1)
+    });
+    if count == 0 { 0.0 } else { sum / count as f32 }
+}
+
+/// Gène silencieux sous le seuil d'expression, quel que soit son signe
+pub fn expressed(force: f32, threshold: f32) -> f32 {
+    if force.abs() < threshold { 0.0 } else { force }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn crossover_blend_without_alpha_stays_between_parents() {
+        let mut rng = StdRng::seed_from_u64(42);
+        let parent1 = Genotype::random(4, 3, &mut rng);
+        let parent2 = Genotype::random(4, 3, &mut rng);
+
+        let child = Genotype::crossover_blend(&parent1, &parent2, 0.0, &mut rng);
+
+        let genes = |genotype: &Genotype| {
+            genotype
+                .force_matrix
+                .iter()
+                .chain(&genotype.food_forces)
+                .copied()
+                .collect::<Vec<f32>>()
+        };
+        let (genes1, genes2, child_genes) = (genes(&parent1), genes(&parent2), genes(&child));
+        assert_eq!(child_genes.len(), genes1.len());
+
+        for ((&g1, &g2), &gene) in genes1.iter().zip(&genes2).zip(&child_genes) {
+            assert!(
+                gene >= g1.min(g2) && gene <= g1.max(g2),
+                "{gene} hors de [{g1}, {g2}]"
+            );
+            assert!((-2.0..=2.0).contains(&gene));
+        }
+    }
+}
@@ -15,4 +15,97 @@ impl Score {
     pub fn get(&self) -> f32 {
         self.0
     }
-}
\ No newline at end of file
+}
+
+/// Nombre de nourritures mangées pendant l'époque
+#[derive(Component, Default, Debug, Clone)]
+pub struct FoodEatenCount(pub u32);
+
+/// Bonus d'amas : taille moyenne du plus grand amas pendant l'époque × multiplicateur
+#[derive(Component, Default, Debug, Clone)]
+pub struct ClusterScore {
+    pub cluster_count: usize,
+    pub largest_cluster: usize,
+    samples: u32,
+    largest_cluster_sum: f32,
+    bonus: f32,
+}
+
+impl ClusterScore {
+    pub fn record(&mut self, cluster_count: usize, largest_cluster: usize, multiplier: f32) {
+        self.cluster_count = cluster_count;
+        self.largest_cluster = largest_cluster;
+        self.samples += 1;
+        self.largest_cluster_sum += largest_cluster as f32;
+        // Moyenne sur l'époque : seuls les amas qui durent rapportent vraiment
+        self.bonus = self.largest_cluster_sum / self.samples as f32 * multiplier;
+    }
+
+    pub fn bonus(&self) -> f32 {
+        self.bonus
+    }
+
+    /// Taille moyenne du plus grand amas sur l'époque
+    pub fn average_largest_cluster(&self) -> f32 {
+        if self.samples == 0 {
+            0.0
+        } else {
+            self.largest_cluster_sum / self.samples as f32
+        }
+    }
+
+    pub fn reset(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Second objectif : volume de la boîte englobante des positions visitées pendant l'époque
+#[derive(Component, Default, Debug, Clone)]
+pub struct ExplorationScore {
+    bounds: Option<(Vec3, Vec3)>,
+}
+
+impl ExplorationScore {
+    pub fn record(&mut self, position: Vec3) {
+        self.bounds = Some(match self.bounds {
+            Some((min, max)) => (min.min(position), max.max(position)),
+            None => (position, position),
+        });
+    }
+
+    pub fn get(&self) -> f32 {
+        self.bounds
+            .map(|(min, max)| {
+                let size = max - min;
+                size.x * size.y * size.z
+            })
+            .unwrap_or(0.0)
+    }
+
+    pub fn reset(&mut self) {
+        self.bounds = None;
+    }
+}
+
+/// Nouveauté du comportement : distance moyenne aux k plus proches voisins de l'archive
+#[derive(Component, Default, Debug, Clone)]
+pub struct NoveltyScore(pub f32);
+
+/// Distance totale parcourue par les particules de la simulation pendant l'époque
+#[derive(Component, Default, Debug, Clone)]
+pub struct TotalDistanceTravelled(pub f32);
+
+impl TotalDistanceTravelled {
+    /// Score par unité de distance : récompense les fourrageurs économes
+    pub fn efficiency(&self, score: &Score) -> f32 {
+        score.get() / self.0.max(0.001)
+    }
+}
+
+/// Efficacité de fourrage de la dernière époque terminée
+#[derive(Component, Default, Debug, Clone)]
+pub struct FoodEfficiency(pub f32);
+
+/// Valeur de chaque objectif sélectionné (`SimulationParameters::objectives`), dans l'ordre
+#[derive(Component, Default, Debug, Clone)]
+pub struct SecondaryScore(pub Vec<f32>);
@@ -2,6 +2,8 @@ pub const DEFAULT_PARTICLE_COUNT: usize = 100;
 pub const DEFAULT_PARTICLE_TYPES: usize = 3;
 pub const DEFAULT_SIMULATION_COUNT: usize = 6;
 pub const DEFAULT_EPOCH_DURATION: f32 = 60.0; // secondes
+pub const MIN_EPOCH_DURATION: f32 = 5.0; // secondes, pour l'ajustement en direct
+pub const DEFAULT_AUTO_SAVE_INTERVAL: usize = 10; // époques
 pub const DEFAULT_PARTICLES_PER_TYPE: usize = DEFAULT_PARTICLE_COUNT / DEFAULT_PARTICLE_TYPES;
 
 /// Timestep fixe pour la physique (60 FPS) - indépendant de la vitesse de simulation
@@ -11,11 +13,14 @@ pub const PHYSICS_TIMESTEP: f32 = 0.008;
 pub const DEFAULT_GRID_WIDTH: f32 = 800.0;
 pub const DEFAULT_GRID_HEIGHT: f32 = 800.0;
 pub const DEFAULT_GRID_DEPTH: f32 = 800.0;
+/// Profondeur de la grille en mode 2D
+pub const FLAT_GRID_DEPTH: f32 = 1.0;
 
 // Paramètres de la nourriture
 pub const DEFAULT_FOOD_COUNT: usize = 50;
 pub const DEFAULT_FOOD_RESPAWN_TIME: f32 = 5.0; // secondes
 pub const DEFAULT_FOOD_VALUE: f32 = 1.0;
+pub const DEFAULT_FOOD_TYPE_COUNT: usize = 1;
 pub const FOOD_RADIUS: f32 = 2.0;
 
 // Paramètres des particules
@@ -23,10 +28,24 @@ pub const PARTICLE_RADIUS: f32 = 4.0;
 pub const PARTICLE_MASS: f32 = 1.0;
 pub const MAX_VELOCITY: f32 = 200.0;
 pub const COLLISION_DAMPING: f32 = 0.5;
+pub const DEFAULT_FORCE_OSCILLATION_PERIOD: f32 = 20.0; // secondes
+pub const FORCE_OSCILLATION_AMPLITUDE: f32 = 0.5; // ±50 % autour des forces nominales
+pub const DEFAULT_GRAVITY: [f32; 3] = [0.0, -98.0, 0.0]; // Accélération proposée à l'activation
+pub const DEFAULT_VELOCITY_HALF_LIFE: f32 = 0.043; // secondes
+pub const DEFAULT_HALF_LIFE_SCHEDULE: (f32, f32) = (0.1, 0.02); // (début, fin) en secondes
+pub const HALF_LIFE_DECAY_STEPS: usize = 4; // Paliers du planning en escalier
+pub const DEFAULT_INTER_SIM_FORCE_SCALE: f32 = 0.1; // Forces entre simulations différentes
+pub const DEFAULT_SOFT_WALL_STIFFNESS: f32 = 500.0;
+pub const DEFAULT_SOFT_WALL_DECAY: f32 = 40.0;
 
 // Paramètres des forces
 pub const DEFAULT_MAX_FORCE_RANGE: f32 = 300.0;
 
+// Grille spatiale de recherche des voisins (cellules sur l'axe le plus long)
+pub const MIN_SPATIAL_GRID_RESOLUTION: usize = 5;
+pub const MAX_SPATIAL_GRID_RESOLUTION: usize = 200;
+pub const SPATIAL_GRID_CELL_WARNING: usize = 1_000_000; // Au-delà : avertissement mémoire
+
 pub const FORCE_SCALE_FACTOR: f32 = 80.0;
 
 pub const MIN_DISTANCE: f32 = 0.001;
@@ -34,8 +53,31 @@ pub const PARTICLE_REPULSION_STRENGTH: f32 = 100.0;
 
 // Paramètres génétiques
 pub const DEFAULT_ELITE_RATIO: f32 = 0.1; // 10% des génomes gardés
+pub const DEFAULT_TOURNAMENT_SIZE: usize = 3; // Candidats par tournoi de sélection
 pub const DEFAULT_MUTATION_RATE: f32 = 0.1; // 10% de chance de mutation
 pub const DEFAULT_CROSSOVER_RATE: f32 = 0.7; // 70% de crossover
+pub const DEFAULT_BLEND_ALPHA: f32 = 0.5; // BLX-0.5 classique
+pub const DEFAULT_DE_SCALE: f32 = 0.5; // Facteur F de l'évolution différentielle
+pub const DEFAULT_DE_CROSSOVER: f32 = 0.9; // Probabilité CR de l'évolution différentielle
+pub const DEFAULT_ISLAND_COUNT: usize = 2; // Nombre d'îles (modèle en îles)
+pub const DEFAULT_MIGRATION_INTERVAL: usize = 5; // Époques entre deux migrations
+pub const DEFAULT_NICHE_RADIUS: f32 = 1.0; // Rayon de niche pour la spéciation
+pub const DEFAULT_ROLLING_SELECTION_INTERVAL: f32 = 30.0; // Secondes entre deux sélections (mode continu)
+pub const DEFAULT_STAGNATION_WINDOW: usize = 5; // Époques observées pour détecter une stagnation
+pub const DEFAULT_STAGNATION_THRESHOLD: f32 = 0.5; // Écart minimal du meilleur score sur la fenêtre
+pub const DEFAULT_NOVELTY_WEIGHT: f32 = 0.5; // 0 = score seul, 1 = nouveauté seule
+pub const NOVELTY_ARCHIVE_CAPACITY: usize = 200; // Comportements mémorisés au maximum
+pub const NOVELTY_NEIGHBOURS: usize = 5; // k plus proches voisins pour la nouveauté
+pub const NOVELTY_ARCHIVE_ADDITIONS: usize = 3; // Comportements archivés par génération
+pub const DEFAULT_CLUSTER_BONUS_MULTIPLIER: f32 = 0.1; // Points par particule du plus grand amas
+
+// Budget d'énergie
+pub const ENERGY_PER_PARTICLE: f32 = 10.0; // Énergie initiale par particule = coût d'une naissance
+pub const ENERGY_COST_RATE: f32 = 0.01; // Consommation par particule et par seconde simulée
+pub const DEFAULT_BIRTH_THRESHOLD: f32 = 12.0; // Énergie par particule au-delà de laquelle on naît
+pub const MAX_PARTICLE_COUNT_FACTOR: usize = 2; // Population maximale : particle_count × facteur
 
 // Paramètres de rendu
-pub const PARTICLE_SUBDIVISIONS: u32 = 8;
\ No newline at end of file
+pub const PARTICLE_SUBDIVISIONS: u32 = 8;
+// Paramètres du rejeu
+pub const MAX_RECORDED_FRAMES: usize = 3600; // ~1 minute à 60 FPS
@@ -1,8 +1,13 @@
 // src/main.rs
+use bevy::app::ScheduleRunnerPlugin;
 use bevy::diagnostic::{FrameCount, FrameTimeDiagnosticsPlugin, LogDiagnosticsPlugin};
 use bevy::prelude::*;
-use bevy::window::{PresentMode, WindowMode};
+use bevy::render::settings::{RenderCreation, WgpuSettings};
+use bevy::render::RenderPlugin;
+use bevy::window::{ExitCondition, PresentMode, WindowMode};
+use bevy::winit::WinitPlugin;
 use bevy_app_compute::prelude::*;
+use std::time::Duration;
 
 mod components;
 mod globals;
@@ -13,14 +18,41 @@ mod systems;
 mod ui;
 
 use crate::states::app::AppState;
+use crate::plugins::core::audio::SoundEffectsPlugin;
 use crate::plugins::core::camera::CameraPlugin;
+use crate::plugins::core::cli::CliArgs;
+use crate::plugins::core::headless::{HeadlessArgs, HeadlessPlugin};
 use crate::plugins::core::setup::SetupPlugin;
 use crate::plugins::simulation::compute::ParticleComputePlugin;
+use crate::plugins::simulation::sandbox::SandboxPlugin;
 use crate::plugins::simulation::simulation::SimulationPlugin;
+use crate::plugins::simulation::sweep::SweepPlugin;
 use crate::plugins::simulation::visualizer::VisualizerPlugin;
 use crate::plugins::ui::ui_plugin::UIPlugin;
+use crate::resources::config::random::RunSeed;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
 
 fn main() {
+    if CliArgs::help_requested() {
+        CliArgs::print_help();
+        return;
+    }
+
+    let cli_args = match CliArgs::from_env() {
+        Ok(cli_args) => cli_args,
+        Err(e) => {
+            eprintln!("Erreur: {}\n", e);
+            CliArgs::print_help();
+            std::process::exit(2);
+        }
+    };
+    cli_args.print_summary();
+
+    if let Some(args) = HeadlessArgs::from_env() {
+        run_headless(args, cli_args);
+        return;
+    }
+
     App::new()
         .add_plugins((
             DefaultPlugins.set(WindowPlugin {
@@ -44,6 +76,8 @@ fn main() {
             FrameTimeDiagnosticsPlugin::default(),
             AppComputePlugin,
         ))
+        .insert_resource(RunSeed(cli_args.seed))
+        .insert_resource(cli_args)
         .add_plugins((
             SetupPlugin,
             SimulationPlugin,
@@ -51,11 +85,40 @@ fn main() {
             CameraPlugin,
             UIPlugin,
             VisualizerPlugin,
+            SandboxPlugin,
+            SoundEffectsPlugin,
+            SweepPlugin,
         ))
         .add_systems(Update, (make_visible, exit_game))
         .run();
 }
 
+/// Benchmark sans fenêtre ni UI : même physique et même génétique que le mode normal
+fn run_headless(args: HeadlessArgs, cli_args: CliArgs) {
+    App::new()
+        .add_plugins((
+            DefaultPlugins
+                .set(WindowPlugin {
+                    primary_window: None,
+                    exit_condition: ExitCondition::DontExit,
+                    ..default()
+                })
+                .set(RenderPlugin {
+                    render_creation: RenderCreation::Automatic(WgpuSettings {
+                        backends: None,
+                        ..default()
+                    }),
+                    ..default()
+                })
+                .disable::<WinitPlugin>(),
+            ScheduleRunnerPlugin::run_loop(Duration::ZERO),
+        ))
+        .insert_resource(RunSeed(cli_args.seed))
+        .insert_resource(cli_args)
+        .add_plugins((SetupPlugin, SimulationPlugin, HeadlessPlugin { args }))
+        .run();
+}
+
 fn make_visible(mut window: Single<&mut Window>, frames: Res<FrameCount>) {
     if frames.0 == 3 {
         window.visible = true;
@@ -67,8 +130,15 @@ fn exit_game(
     mut app_exit_events: EventWriter<AppExit>,
     state: Res<State<AppState>>,
     mut next_state: ResMut<NextState<AppState>>,
+    mut ui_state: ResMut<ForceMatrixUI>,
 ) {
     if keyboard_input.just_pressed(KeyCode::Escape) {
+        // Échap annule d'abord le mode copie de génome, sans quitter la simulation
+        if ui_state.genome_copy_source.is_some() {
+            ui_state.genome_copy_source = None;
+            return;
+        }
+
         match state.get() {
             AppState::MainMenu => {
                 app_exit_events.write(AppExit::Success);
@@ -82,6 +152,9 @@ fn exit_game(
             AppState::Visualizer => {
                 next_state.set(AppState::MainMenu);
             }
+            AppState::Sandbox => {
+                next_state.set(AppState::MainMenu);
+            }
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,15 @@
+use crate::resources::config::audio::AudioSettings;
+use crate::systems::audio::{load_sound_assets, play_sound_effects, SoundEffect};
+use bevy::prelude::*;
+
+/// Effets sonores : carillon de nourriture et fin d'époque (mode fenêtré uniquement)
+pub struct SoundEffectsPlugin;
+
+impl Plugin for SoundEffectsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AudioSettings>()
+            .add_event::<SoundEffect>()
+            .add_systems(Startup, load_sound_assets)
+            .add_systems(Update, play_sound_effects);
+    }
+}
@@ -1,7 +1,11 @@
 use bevy::prelude::*;
 use bevy::render::view::RenderLayers;
-use crate::resources::world::camera::CameraSettings;
+use crate::resources::world::camera::{BloomConfig, CameraSettings};
 use crate::resources::world::grid::GridParameters;
+use crate::systems::rendering::camera::{
+    camera_transition_system, free_fly, orbit, scroll_zoom, sync_bloom_settings,
+    toggle_camera_mode,
+};
 use crate::systems::rendering::viewport_manager::ViewportCamera;
 
 pub struct CameraPlugin;
@@ -9,8 +13,22 @@ pub struct CameraPlugin;
 impl Plugin for CameraPlugin {
     fn build(&self, app: &mut App) {
         app.init_resource::<CameraSettings>();
+        app.init_resource::<BloomConfig>();
         app.add_systems(Startup, setup_default_camera);
-        app.add_systems(Update, (manage_default_camera, update_default_camera_distance)); 
+        app.add_systems(
+            Update,
+            (
+                manage_default_camera,
+                update_default_camera_distance,
+                (
+                    toggle_camera_mode,
+                    (orbit, scroll_zoom, free_fly),
+                    camera_transition_system,
+                )
+                    .chain(),
+                sync_bloom_settings,
+            ),
+        );
     }
 }
 
@@ -33,6 +51,7 @@ fn calculate_default_camera_distance(grid: &GridParameters) -> f32 {
 fn setup_default_camera(
     mut commands: Commands,
     grid_params: Res<GridParameters>, // AJOUT pour l'adaptation immédiate
+    bloom_config: Res<BloomConfig>,
 ) {
     let camera_distance = calculate_default_camera_distance(&grid_params);
 
@@ -42,13 +61,7 @@ fn setup_default_camera(
         camera_distance * 0.7
     );
 
-    commands.spawn((
-        Camera3d::default(),
-        Transform::from_translation(camera_position)
-            .looking_at(Vec3::ZERO, Vec3::Y),
-        DefaultCamera,
-        RenderLayers::from_layers(&[0, 1]),
-    ));
+    spawn_default_camera(&mut commands, camera_position, &bloom_config);
 
     info!("🎥 Caméra par défaut positionnée à distance: {:.0}", camera_distance);
 }
@@ -90,6 +103,7 @@ fn manage_default_camera(
     default_camera: Query<Entity, With<DefaultCamera>>,
     viewport_cameras: Query<Entity, With<ViewportCamera>>,
     grid_params: Res<GridParameters>,
+    bloom_config: Res<BloomConfig>,
 ) {
     // S'il y a des caméras de viewport, supprimer la caméra par défaut
     if !viewport_cameras.is_empty() {
@@ -107,14 +121,24 @@ fn manage_default_camera(
             camera_distance * 0.7
         );
 
-        commands.spawn((
-            Camera3d::default(),
-            Transform::from_translation(camera_position)
-                .looking_at(Vec3::ZERO, Vec3::Y),
-            DefaultCamera,
-            RenderLayers::from_layers(&[0, 1]),
-        ));
+        spawn_default_camera(&mut commands, camera_position, &bloom_config);
 
         info!("🎥 Caméra par défaut recréée avec distance adaptée: {:.0}", camera_distance);
     }
-}
\ No newline at end of file
+}
+fn spawn_default_camera(commands: &mut Commands, position: Vec3, bloom_config: &BloomConfig) {
+    let mut camera = commands.spawn((
+        Camera {
+            hdr: bloom_config.enabled(),
+            ..default()
+        },
+        Camera3d::default(),
+        Transform::from_translation(position).looking_at(Vec3::ZERO, Vec3::Y),
+        DefaultCamera,
+        RenderLayers::from_layers(&[0, 1]),
+    ));
+
+    if let Some(bloom) = bloom_config.bloom() {
+        camera.insert(bloom);
+    }
+}
@@ -0,0 +1,143 @@
+use crate::plugins::core::headless::BenchmarkConfig;
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::world::boundary::BoundaryMode;
+use bevy::prelude::*;
+
+const HELP: &str = "\
+Simulation de Vie Artificielle
+
+Usage: gpu-particle-life [OPTIONS]
+
+Options:
+  --particles N            Nombre de particules par simulation
+  --types N                Nombre de types de particules
+  --epochs N               Nombre d'époques (mode sans affichage : époques du benchmark)
+  --simulations N          Nombre de simulations en parallèle
+  --epoch-duration F       Durée d'une époque en secondes
+  --boundary MODE          Bords de la grille : bounce | teleport
+  --seed N                 Graine aléatoire pour des résultats reproductibles
+  --headless               Benchmark sans fenêtre (voir benchmark.toml)
+  -h, --help               Affiche cette aide
+
+Les options remplacent les valeurs de config.toml et du menu principal.";
+
+/// Paramètres passés en ligne de commande, prioritaires sur `config.toml`
+#[derive(Resource, Clone, Debug, Default)]
+pub struct CliArgs {
+    pub particles: Option<usize>,
+    pub types: Option<usize>,
+    pub epochs: Option<usize>,
+    pub simulations: Option<usize>,
+    pub epoch_duration: Option<f32>,
+    pub boundary: Option<BoundaryMode>,
+    pub seed: Option<u64>,
+}
+
+impl CliArgs {
+    pub fn help_requested() -> bool {
+        std::env::args().any(|arg| arg == "--help" || arg == "-h")
+    }
+
+    pub fn print_help() {
+        println!("{}", HELP);
+    }
+
+    pub fn from_env() -> Result<Self, String> {
+        let args: Vec<String> = std::env::args().skip(1).collect();
+        Self::parse(&args)
+    }
+
+    fn parse(args: &[String]) -> Result<Self, String> {
+        let mut cli = Self::default();
+        let mut iter = args.iter();
+
+        while let Some(flag) = iter.next() {
+            let mut value = || {
+                iter.next()
+                    .ok_or_else(|| format!("valeur manquante pour {}", flag))
+            };
+
+            match flag.as_str() {
+                "--particles" => cli.particles = Some(parse_value(flag, value()?)?),
+                "--types" => cli.types = Some(parse_value(flag, value()?)?),
+                "--epochs" => cli.epochs = Some(parse_value(flag, value()?)?),
+                "--simulations" => cli.simulations = Some(parse_value(flag, value()?)?),
+                "--epoch-duration" => cli.epoch_duration = Some(parse_value(flag, value()?)?),
+                "--seed" => cli.seed = Some(parse_value(flag, value()?)?),
+                "--boundary" => {
+                    cli.boundary = Some(match value()?.as_str() {
+                        "bounce" => BoundaryMode::Bounce,
+                        "teleport" => BoundaryMode::Teleport,
+                        other => return Err(format!("mode de bords inconnu: {}", other)),
+                    })
+                }
+                "--headless" => {}
+                other => return Err(format!("option inconnue: {}", other)),
+            }
+        }
+
+        Ok(cli)
+    }
+
+    pub fn print_summary(&self) {
+        let overrides = [
+            ("particules", self.particles.map(|v| v.to_string())),
+            ("types", self.types.map(|v| v.to_string())),
+            ("époques", self.epochs.map(|v| v.to_string())),
+            ("simulations", self.simulations.map(|v| v.to_string())),
+            ("durée d'époque", self.epoch_duration.map(|v| format!("{} s", v))),
+            ("bords", self.boundary.map(|v| format!("{:?}", v))),
+            ("graine", self.seed.map(|v| v.to_string())),
+        ];
+
+        let given: Vec<_> = overrides
+            .iter()
+            .filter_map(|(name, value)| value.as_ref().map(|value| (name, value)))
+            .collect();
+
+        if given.is_empty() {
+            return;
+        }
+
+        println!("Paramètres de la ligne de commande:");
+        for (name, value) in given {
+            println!("  • {}: {}", name, value);
+        }
+    }
+
+    pub fn apply_to_global(&self, config: &mut GlobalConfig) {
+        if let Some(particles) = self.particles {
+            config.particle_count = particles;
+        }
+        if let Some(types) = self.types {
+            config.particle_types = types;
+        }
+        if let Some(simulations) = self.simulations {
+            config.simulation_count = simulations;
+        }
+        if let Some(epoch_duration) = self.epoch_duration {
+            config.epoch_duration = epoch_duration;
+        }
+    }
+
+    pub fn apply_to_benchmark(&self, config: &mut BenchmarkConfig) {
+        if let Some(particles) = self.particles {
+            config.particle_count = particles;
+        }
+        if let Some(types) = self.types {
+            config.particle_types = types;
+        }
+        if let Some(simulations) = self.simulations {
+            config.simulation_count = simulations;
+        }
+        if let Some(epoch_duration) = self.epoch_duration {
+            config.epoch_duration = epoch_duration;
+        }
+    }
+}
+
+fn parse_value<T: std::str::FromStr>(flag: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("valeur invalide pour {}: {}", flag, value))
+}
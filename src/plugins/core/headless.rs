@@ -0,0 +1,296 @@
+use crate::plugins::core::cli::CliArgs;
+use crate::plugins::simulation::compute::ComputeEnabled;
+use crate::resources::config::food::FoodParameters;
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::{OnMaxEpochs, SimulationParameters, SimulationSpeed};
+use crate::resources::world::grid::GridParameters;
+use crate::states::app::AppState;
+use crate::states::simulation::SimulationState;
+use crate::systems::simulation::reset::{reset_for_new_epoch, EpochHistoryResource};
+use bevy::prelude::*;
+use bevy::time::TimeUpdateStrategy;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+/// Paramètres du benchmark lus depuis ce fichier
+pub const BENCHMARK_CONFIG_PATH: &str = "benchmark.toml";
+/// Fichier de résultats écrit à la fin du benchmark
+pub const BENCHMARK_RESULTS_PATH: &str = "benchmark_results.json";
+
+/// Arguments du mode sans affichage (`--headless --epochs N`)
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct HeadlessArgs {
+    pub epochs: usize,
+}
+
+impl HeadlessArgs {
+    /// Renvoie `None` si `--headless` n'est pas passé en argument
+    pub fn from_env() -> Option<Self> {
+        let args: Vec<String> = std::env::args().collect();
+
+        if !args.iter().any(|arg| arg == "--headless") {
+            return None;
+        }
+
+        let epochs = args
+            .iter()
+            .position(|arg| arg == "--epochs")
+            .and_then(|index| args.get(index + 1))
+            .and_then(|value| value.parse::<usize>().ok())
+            .unwrap_or(10)
+            .max(1);
+
+        Some(Self { epochs })
+    }
+}
+
+/// Configuration du benchmark (une seule simulation par défaut)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct BenchmarkConfig {
+    pub simulation_count: usize,
+    pub particle_count: usize,
+    pub particle_types: usize,
+    pub epoch_duration: f32,
+
+    pub grid_width: f32,
+    pub grid_height: f32,
+    pub grid_depth: f32,
+
+    pub food_count: usize,
+    pub food_respawn_time: f32,
+    pub food_value: f32,
+
+    pub max_force_range: f32,
+    pub elite_ratio: f32,
+    pub mutation_rate: f32,
+    pub crossover_rate: f32,
+}
+
+impl Default for BenchmarkConfig {
+    fn default() -> Self {
+        let global = GlobalConfig::default();
+        Self {
+            simulation_count: 1,
+            particle_count: global.particle_count,
+            particle_types: global.particle_types,
+            epoch_duration: global.epoch_duration,
+
+            grid_width: global.grid_width,
+            grid_height: global.grid_height,
+            grid_depth: global.grid_depth,
+
+            food_count: global.food_count,
+            food_respawn_time: global.food_respawn_time,
+            food_value: global.food_value,
+
+            max_force_range: global.max_force_range,
+            elite_ratio: global.elite_ratio,
+            mutation_rate: global.mutation_rate,
+            crossover_rate: global.crossover_rate,
+        }
+    }
+}
+
+impl BenchmarkConfig {
+    /// Charge `benchmark.toml`, ou les valeurs par défaut s'il est absent ou invalide
+    pub fn load() -> Self {
+        let path = Path::new(BENCHMARK_CONFIG_PATH);
+
+        if !path.exists() {
+            info!("{:?} absent, configuration de benchmark par défaut", path);
+            return Self::default();
+        }
+
+        match fs::read_to_string(path).map(|content| toml::from_str::<BenchmarkConfig>(&content)) {
+            Ok(Ok(config)) => {
+                info!("Configuration de benchmark chargée depuis {:?}", path);
+                config
+            }
+            Ok(Err(e)) => {
+                warn!(
+                    "Erreur de lecture de {:?}, valeurs par défaut utilisées: {}",
+                    path, e
+                );
+                Self::default()
+            }
+            Err(e) => {
+                warn!("Impossible de lire {:?}: {}", path, e);
+                Self::default()
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BenchmarkEpoch {
+    pub epoch: usize,
+    pub best_score: f32,
+    pub average_score: f32,
+    pub worst_score: f32,
+    pub elapsed_seconds: f64,
+}
+
+/// Résultats accumulés au fil des époques
+#[derive(Resource, Serialize, Debug)]
+pub struct BenchmarkResults {
+    pub epochs_requested: usize,
+    pub total_seconds: f64,
+    pub epochs: Vec<BenchmarkEpoch>,
+    #[serde(skip)]
+    started_at: Instant,
+}
+
+impl BenchmarkResults {
+    fn new(epochs_requested: usize) -> Self {
+        Self {
+            epochs_requested,
+            total_seconds: 0.0,
+            epochs: Vec::new(),
+            started_at: Instant::now(),
+        }
+    }
+}
+
+/// Mode sans fenêtre : enchaîne N époques aussi vite que possible puis quitte
+pub struct HeadlessPlugin {
+    pub args: HeadlessArgs,
+}
+
+impl Plugin for HeadlessPlugin {
+    fn build(&self, app: &mut App) {
+        let mut config = BenchmarkConfig::load();
+        if let Some(cli_args) = app.world().get_resource::<CliArgs>() {
+            cli_args.apply_to_benchmark(&mut config);
+        }
+        let global_config = app
+            .world()
+            .get_resource::<GlobalConfig>()
+            .cloned()
+            .unwrap_or_default();
+
+        apply_benchmark_config(app, &config, &global_config, self.args.epochs);
+
+        // Pas d'attente entre les frames : chaque update avance d'un pas physique
+        app.insert_resource(TimeUpdateStrategy::ManualDuration(Duration::from_secs_f32(
+            global_config.physics_timestep,
+        )));
+
+        app.insert_resource(self.args)
+            .insert_resource(BenchmarkResults::new(self.args.epochs))
+            .add_systems(Startup, start_benchmark)
+            .add_systems(
+                OnEnter(SimulationState::Starting),
+                record_benchmark_epoch.after(reset_for_new_epoch),
+            );
+    }
+}
+
+fn apply_benchmark_config(
+    app: &mut App,
+    config: &BenchmarkConfig,
+    global_config: &GlobalConfig,
+    epochs: usize,
+) {
+    app.insert_resource(GridParameters {
+        width: config.grid_width,
+        height: config.grid_height,
+        depth: config.grid_depth,
+    });
+
+    app.insert_resource(SimulationParameters {
+        current_epoch: 0,
+        max_epochs: epochs,
+        // Le benchmark quitte lui-même l'application : pas de sauvegarde finale
+        on_max_epochs: OnMaxEpochs::Loop,
+        epoch_duration: config.epoch_duration,
+        epoch_timer: Timer::from_seconds(config.epoch_duration, TimerMode::Once),
+        simulation_count: config.simulation_count.max(1),
+        particle_count: config.particle_count,
+        particle_types: config.particle_types,
+        simulation_speed: SimulationSpeed::Normal,
+        auto_save_interval: None,
+        max_force_range: config.max_force_range,
+        spatial_grid_resolution: SimulationParameters::default_spatial_grid_resolution(
+            config.grid_width,
+            config.max_force_range,
+        ),
+        elite_ratio: config.elite_ratio,
+        mutation_rate: config.mutation_rate,
+        crossover_rate: config.crossover_rate,
+        island_count: global_config.island_count.min(config.simulation_count).max(1),
+        migration_interval: global_config.migration_interval,
+        trail_length: 0,
+        ..default()
+    });
+
+    app.insert_resource(ParticleTypesConfig::new(config.particle_types));
+
+    app.insert_resource(FoodParameters {
+        food_count: config.food_count,
+        respawn_cooldown: config.food_respawn_time,
+        food_value: config.food_value,
+        ..default()
+    });
+
+    // Le calcul GPU nécessite un rendu : le benchmark utilise toujours le CPU
+    app.insert_resource(ComputeEnabled(false));
+}
+
+fn start_benchmark(
+    args: Res<HeadlessArgs>,
+    sim_params: Res<SimulationParameters>,
+    mut results: ResMut<BenchmarkResults>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    info!(
+        "🧪 Benchmark sans affichage: {} époques, {} simulation(s) de {} particules",
+        args.epochs, sim_params.simulation_count, sim_params.particle_count
+    );
+    results.started_at = Instant::now();
+    next_state.set(AppState::Simulation);
+}
+
+fn record_benchmark_epoch(
+    args: Res<HeadlessArgs>,
+    epoch_history: Res<EpochHistoryResource>,
+    mut results: ResMut<BenchmarkResults>,
+    mut app_exit_events: EventWriter<AppExit>,
+) {
+    let Some(stats) = epoch_history.stats.get(results.epochs.len()) else {
+        return;
+    };
+
+    let elapsed_seconds = results.started_at.elapsed().as_secs_f64();
+    info!(
+        "🧪 Époque {} terminée en {:.2}s (meilleur {:.2}, moyenne {:.2})",
+        stats.epoch, elapsed_seconds, stats.best_score, stats.average_score
+    );
+
+    results.epochs.push(BenchmarkEpoch {
+        epoch: stats.epoch,
+        best_score: stats.best_score,
+        average_score: stats.average_score,
+        worst_score: stats.worst_score,
+        elapsed_seconds,
+    });
+
+    if results.epochs.len() < args.epochs {
+        return;
+    }
+
+    results.total_seconds = elapsed_seconds;
+
+    match serde_json::to_string_pretty(&*results) {
+        Ok(json) => match fs::write(BENCHMARK_RESULTS_PATH, json) {
+            Ok(()) => info!("Résultats du benchmark écrits dans {}", BENCHMARK_RESULTS_PATH),
+            Err(e) => error!("Impossible d'écrire {}: {}", BENCHMARK_RESULTS_PATH, e),
+        },
+        Err(e) => error!("Erreur de sérialisation du benchmark: {}", e),
+    }
+
+    app_exit_events.write(AppExit::Success);
+}
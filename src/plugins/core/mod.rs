@@ -1,2 +1,5 @@
+pub mod audio;
 pub mod camera;
-pub mod setup;
\ No newline at end of file
+pub mod cli;
+pub mod headless;
+pub mod setup;
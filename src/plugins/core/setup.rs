@@ -1,20 +1,41 @@
+use crate::plugins::core::cli::CliArgs;
 use crate::resources::config::food::FoodParameters;
+use crate::resources::config::global::GlobalConfig;
 use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::predation::{PredationEnabled, PredationStrength};
 use crate::resources::config::simulation::SimulationParameters;
-use crate::resources::world::boundary::BoundaryMode;
 use crate::resources::world::grid::GridParameters;
+use crate::resources::world::physics::PhysicsState;
 use crate::states::app::AppState;
+use crate::systems::persistence::session::{detect_previous_session, PreviousSession};
 use bevy::prelude::*;
 
 pub struct SetupPlugin;
 
 impl Plugin for SetupPlugin {
     fn build(&self, app: &mut App) {
+        let cli_args = app.world().get_resource::<CliArgs>().cloned();
+
+        let mut global_config = GlobalConfig::load();
+        if let Some(cli_args) = &cli_args {
+            cli_args.apply_to_global(&mut global_config);
+        }
+        app.insert_resource(global_config);
         app.init_state::<AppState>();
         app.init_resource::<GridParameters>();
         app.init_resource::<ParticleTypesConfig>();
         app.init_resource::<SimulationParameters>();
         app.init_resource::<FoodParameters>();
-        app.init_resource::<BoundaryMode>();
+        app.insert_resource(
+            cli_args
+                .as_ref()
+                .and_then(|cli_args| cli_args.boundary)
+                .unwrap_or_default(),
+        );
+        app.init_resource::<PredationEnabled>();
+        app.init_resource::<PredationStrength>();
+        app.init_resource::<PhysicsState>();
+        app.init_resource::<PreviousSession>();
+        app.add_systems(Startup, detect_previous_session);
     }
 }
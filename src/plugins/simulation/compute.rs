@@ -5,15 +5,19 @@ use crate::components::entities::food::Food;
 use crate::components::entities::particle::{Particle, ParticleType, Velocity};
 use crate::components::entities::simulation::{Simulation, SimulationId};
 use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::TotalDistanceTravelled;
+use crate::resources::config::global::GlobalConfig;
 use crate::resources::config::simulation::{SimulationParameters, SimulationSpeed};
 use crate::resources::world::boundary::BoundaryMode;
 use crate::resources::world::grid::GridParameters;
+use crate::resources::world::physics::{DEFAULT_MAX_SUBSTEPS, PhysicsState};
 use crate::states::app::AppState;
+use crate::states::simulation::SimulationState;
 
 pub struct ParticleComputePlugin;
 
 /// Ressource pour activer/désactiver le compute shader
-#[derive(Resource, Default)]
+#[derive(Resource, Default, Debug)]
 pub struct ComputeEnabled(pub bool);
 
 impl Plugin for ParticleComputePlugin {
@@ -28,6 +32,7 @@ impl Plugin for ParticleComputePlugin {
                     apply_compute_results.after(run_compute_simulation),
                 )
                     .chain()
+                    .run_if(in_state(SimulationState::Running))
                     .run_if(in_state(AppState::Simulation))
                     .run_if(compute_enabled),
             );
@@ -48,12 +53,16 @@ struct ParticleComputeWorker;
 
 impl ComputeWorker for ParticleComputeWorker {
     fn build(world: &mut World) -> AppComputeWorker<Self> {
+        let global_config = world.get_resource::<GlobalConfig>().cloned().unwrap_or_default();
         let sim_params = world.resource::<SimulationParameters>();
         let grid_params = world.resource::<GridParameters>();
         let boundary_mode = world.resource::<BoundaryMode>();
 
         let num_particles = sim_params.particle_count as u32;
-        let dt = 1.0f32 / 60.0; // 60 FPS
+        // Mêmes constantes que la physique CPU
+        let dt = global_config.physics_timestep;
+        let force_scale_factor = global_config.force_scale_factor;
+        let max_velocity = global_config.max_velocity;
         let world_size = grid_params
             .width
             .max(grid_params.height)
@@ -61,7 +70,8 @@ impl ComputeWorker for ParticleComputeWorker {
         let num_types = sim_params.particle_types as u32;
         let max_force_range = sim_params.max_force_range;
         let boundary_mode_u32 = match boundary_mode {
-            BoundaryMode::Bounce => 0u32,
+            // Le shader ne gère pas les murs mous : rebond utilisé à la place
+            BoundaryMode::Bounce | BoundaryMode::SoftWall { .. } => 0u32,
             BoundaryMode::Teleport => 1u32,
         };
 
@@ -87,6 +97,8 @@ impl ComputeWorker for ParticleComputeWorker {
             .add_uniform("max_force_range", &max_force_range)
             .add_uniform("boundary_mode", &boundary_mode_u32)
             .add_uniform("food_count", &food_count)
+            .add_uniform("force_scale_factor", &force_scale_factor)
+            .add_uniform("max_velocity", &max_velocity)
             // Buffers de données
             .add_staging("positions", &positions)
             .add_staging("velocities", &velocities)
@@ -113,6 +125,8 @@ impl ComputeWorker for ParticleComputeWorker {
                     "food_positions",
                     "food_count",
                     "food_forces",
+                    "force_scale_factor",
+                    "max_velocity",
                 ],
             )
             .build()
@@ -164,8 +178,13 @@ fn update_compute_buffers(
 
     // Forces des simulations (peuvent changer entre époques)
     if let Some((_, genotype)) = simulations.iter().next() {
-        compute_worker.write_slice("force_matrix", &genotype.force_matrix);
-        compute_worker.write_slice("food_forces", &genotype.food_forces);
+        compute_worker.write_slice(
+            "force_matrix",
+            &genotype.expressed_force_matrix(sim_params.gene_expression_threshold),
+        );
+        // Le shader ne connaît qu'un type de nourriture : on envoie la première ligne
+        let food_forces_len = genotype.type_count.min(genotype.food_forces.len());
+        compute_worker.write_slice("food_forces", &genotype.food_forces[..food_forces_len]);
     } else {
         warn!("GPU: Aucune simulation trouvée!");
         return;
@@ -203,16 +222,19 @@ fn update_compute_buffers(
 fn run_compute_simulation(
     mut compute_worker: ResMut<AppComputeWorker<ParticleComputeWorker>>,
     sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
     time: Res<Time>,
+    mut physics_state: ResMut<PhysicsState>,
     mut timer: Local<Timer>,
 ) {
+    physics_state.last_substeps = 0;
     if !compute_worker.ready() {
         return;
     }
 
-    // Initialiser le timer pour 60 FPS
+    // Un tick par pas physique : le shader avance de dt = physics_timestep
     if timer.duration().is_zero() {
-        *timer = Timer::from_seconds(1.0 / 60.0, TimerMode::Repeating);
+        *timer = Timer::from_seconds(global_config.physics_timestep, TimerMode::Repeating);
     }
 
     timer.tick(time.delta());
@@ -227,7 +249,9 @@ fn run_compute_simulation(
         SimulationSpeed::Normal => 1,
         SimulationSpeed::Fast => 2,
         SimulationSpeed::VeryFast => 4,
+        SimulationSpeed::UltraFast => 8.min(DEFAULT_MAX_SUBSTEPS),
     };
+    physics_state.last_substeps = iterations;
 
     // Debug: afficher le nombre d'itérations
     if iterations > 0 {
@@ -250,7 +274,10 @@ fn run_compute_simulation(
 /// Applique les résultats du compute aux entités
 fn apply_compute_results(
     compute_worker: Res<AppComputeWorker<ParticleComputeWorker>>,
-    mut particles: Query<(Entity, &mut Transform, &mut Velocity), With<Particle>>,
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    mut particles: Query<(Entity, &mut Transform, &mut Velocity, &ChildOf), With<Particle>>,
+    mut distances: Query<&mut TotalDistanceTravelled, With<Simulation>>,
 ) {
     if !compute_worker.ready() {
         return;
@@ -265,13 +292,27 @@ fn apply_compute_results(
     }
 
     // Appliquer les résultats aux entités avec index sécurisé
-    for (i, (_, mut transform, mut velocity)) in particles.iter_mut().enumerate() {
+    let half_extents = Vec3::new(grid.width, grid.height, grid.depth) / 2.0;
+
+    for (i, (_, mut transform, mut velocity, parent)) in particles.iter_mut().enumerate() {
         if let (Some(pos), Some(vel)) = (new_positions.get(i), new_velocities.get(i)) {
-            let new_pos = Vec3::new(pos[0], pos[1], pos[2]);
-            let new_vel = Vec3::new(vel[0], vel[1], vel[2]);
+            let mut new_pos = Vec3::new(pos[0], pos[1], pos[2]);
+            let mut new_vel = Vec3::new(vel[0], vel[1], vel[2]);
+
+            // Mode 2D : le shader ignore la dimension, le plan XY est imposé ici
+            if sim_params.dimension.is_flat() {
+                new_pos.z = 0.0;
+                new_vel.z = 0.0;
+            }
 
             // Vérifier que les valeurs sont valides
             if new_pos.is_finite() && new_vel.is_finite() {
+                // Un saut de plus d'une demi-grille est une téléportation torique, pas un trajet
+                let displacement = new_pos - transform.translation;
+                let wrapped = displacement.abs().cmpgt(half_extents).any();
+                if let (false, Ok(mut total)) = (wrapped, distances.get_mut(parent.parent())) {
+                    total.0 += displacement.length();
+                }
                 transform.translation = new_pos;
                 velocity.0 = new_vel;
             }
@@ -1,3 +1,5 @@
 pub mod compute;
+pub mod sandbox;
 pub mod simulation;
+pub mod sweep;
 pub mod visualizer;
\ No newline at end of file
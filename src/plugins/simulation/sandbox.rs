@@ -0,0 +1,63 @@
+use crate::components::entities::simulation::Simulation;
+use crate::components::genetics::genotype::Genotype;
+use crate::states::app::AppState;
+use crate::systems::persistence::population_save::process_save_requests;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy_egui::EguiContexts;
+
+/// Bac à sable : une simulation unique sans algorithme génétique, génome éditable en direct
+pub struct SandboxPlugin;
+
+impl Plugin for SandboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(OnEnter(AppState::Sandbox), open_sandbox_ui)
+            .add_systems(
+                Update,
+                (sandbox_shortcuts, process_save_requests).run_if(in_state(AppState::Sandbox)),
+            );
+    }
+}
+
+/// Ouvre directement la matrice des forces de l'unique simulation
+fn open_sandbox_ui(mut ui_state: ResMut<ForceMatrixUI>) {
+    ui_state.selected_simulation = Some(0);
+    ui_state.show_matrix_window = true;
+    ui_state.selected_simulations.clear();
+    ui_state.selected_simulations.insert(0);
+
+    info!("🧪 Bac à sable: R = aléatoire, P = preset, Z = forces nulles");
+}
+
+/// Raccourcis clavier du bac à sable
+fn sandbox_shortcuts(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut simulations: Query<&mut Genotype, With<Simulation>>,
+    mut contexts: EguiContexts,
+) {
+    // Ne pas intercepter la saisie dans un champ de texte
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+
+    for mut genotype in simulations.iter_mut() {
+        if keyboard.just_pressed(KeyCode::KeyR) {
+            *genotype = Genotype::random(
+                genotype.type_count,
+                genotype.food_type_count,
+                &mut rand::rng(),
+            );
+            info!("🎲 Génome aléatoire");
+        } else if keyboard.just_pressed(KeyCode::KeyP) {
+            genotype.set_interesting_forces(&mut rand::rng());
+            info!("✨ Preset de forces appliqué");
+        } else if keyboard.just_pressed(KeyCode::KeyZ) {
+            genotype.force_matrix.fill(0.0);
+            genotype.food_forces.fill(0.0);
+            info!("⭕ Forces remises à zéro");
+        }
+    }
+}
@@ -1,15 +1,72 @@
 use crate::plugins::simulation::compute::ComputeEnabled;
 use crate::states::app::AppState;
 use crate::states::simulation::SimulationState;
-use crate::systems::lifecycle::{check_epoch_end, handle_pause_input};
+use crate::systems::audio::SoundEffect;
+use crate::systems::lifecycle::{
+    check_epoch_end, handle_max_epochs, handle_pause_input, keyboard_speed_control,
+};
 use crate::systems::persistence::population_save::{
     load_available_populations, process_save_requests, AvailablePopulations, PopulationSaveEvents,
 };
+use crate::systems::persistence::position_export::{
+    export_positions_at_epoch_end, export_requested_positions,
+};
+use crate::systems::persistence::session::{
+    autosave_session, delete_session_file, finish_session_resume,
+};
+use crate::systems::rendering::density_heatmap::{
+    draw_density_heatmap, update_density_heatmap, DensityHeatmap, DensityHeatmapGizmos,
+};
+use crate::systems::rendering::grid_gizmo::draw_grid_gizmo_system;
+use crate::systems::rendering::particle_palette::{
+    update_particle_colors_system, update_particle_materials,
+};
+use crate::systems::rendering::trails::{render_particle_trails, update_particle_trails};
+use crate::systems::rendering::velocity_arrows::{draw_velocity_arrows_system, VelocityArrowGizmos};
 use crate::systems::rendering::viewport_manager::ViewportCamera;
-use crate::systems::simulation::collision::detect_food_collision;
-use crate::systems::simulation::physics::physics_simulation_system;
-use crate::systems::simulation::reset::reset_for_new_epoch;
-use crate::systems::simulation::spawning::{spawn_food, spawn_simulations_with_particles, EntitiesSpawned};
+use crate::ui::dialogs::save_population::quick_save_best_input;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use crate::ui::panels::help::{toggle_help_input, HelpUI};
+use crate::systems::simulation::aging::age_particles_system;
+use crate::resources::config::particle_types::{ParticleTypeAssets, ParticleTypesConfig};
+use crate::resources::config::predation::PredationEnabled;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::systems::simulation::type_counts::{
+    ParticleTypeCounts, count_particles_by_type_system,
+};
+use crate::systems::simulation::obstacles::{despawn_obstacles, place_obstacle_on_click};
+use crate::systems::simulation::clustering::{detect_clusters_system, reset_cluster_scores};
+use crate::systems::simulation::collision::{
+    detect_food_collision, detect_particle_predation, reset_food_eaten_counts,
+};
+use crate::systems::simulation::energy::{energy_budget_system, reset_energy_budget};
+use crate::systems::simulation::exploration::{reset_exploration_scores, track_exploration_system};
+use crate::systems::simulation::hall_of_fame::HallOfFame;
+use crate::systems::simulation::lineage::LineageForest;
+use crate::systems::simulation::mutation::{
+    ForceMutateNow, apply_mutation_rate_change, force_mutation_system,
+};
+use crate::systems::simulation::novelty::{compute_novelty_scores, NoveltyArchive};
+use crate::systems::simulation::objectives::{
+    multi_objective_enabled, record_food_efficiency, update_secondary_scores,
+};
+use crate::systems::simulation::physics::{
+    physics_simulation_system, resolve_particle_collisions,
+};
+use crate::systems::simulation::plasticity::{
+    particle_type_migration_system, restore_particle_types,
+};
+use crate::systems::simulation::reset::{
+    reset_for_new_epoch, rolling_selection_system, update_convergence_estimator,
+    ConvergenceEstimator, EpochEndSummary, EpochHistoryResource, FitnessHistory,
+    PopulationDiversityHistory,
+};
+use crate::systems::simulation::session_stats::{
+    SessionStats, start_run_clock, update_session_stats,
+};
+use crate::systems::simulation::spawning::{
+    apply_food_pressure, spawn_food, spawn_simulations_with_particles, EntitiesSpawned,
+};
 use bevy::prelude::*;
 use crate::components::entities::food::Food;
 use crate::components::entities::simulation::Simulation;
@@ -22,19 +79,53 @@ impl Plugin for SimulationPlugin {
             .init_resource::<EntitiesSpawned>()
             .init_resource::<PopulationSaveEvents>()
             .init_resource::<AvailablePopulations>()
+            .init_resource::<EpochHistoryResource>()
+            .init_resource::<ConvergenceEstimator>()
+            .init_resource::<SessionStats>()
+            .init_resource::<LineageForest>()
+            .init_resource::<HallOfFame>()
+            .init_resource::<NoveltyArchive>()
+            .init_resource::<PopulationDiversityHistory>()
+            .init_resource::<FitnessHistory>()
+            .init_resource::<EpochEndSummary>()
+            // Écrit aussi en mode sans affichage, où personne ne le lit
+            .add_event::<SoundEffect>()
+            .add_event::<ForceMutateNow>()
+            .init_gizmo_group::<VelocityArrowGizmos>()
+            .init_gizmo_group::<DensityHeatmapGizmos>()
+            .init_resource::<DensityHeatmap>()
+            .init_resource::<ParticleTypeCounts>()
+            .init_resource::<ParticleTypeAssets>()
             .add_systems(Startup, load_available_populations)
             .add_systems(
                 OnEnter(AppState::Simulation),
-                |mut next_state: ResMut<NextState<SimulationState>>| {
-                    next_state.set(SimulationState::Starting);
-                },
+                (
+                    |mut next_state: ResMut<NextState<SimulationState>>| {
+                        next_state.set(SimulationState::Starting);
+                    },
+                    start_run_clock,
+                ),
             )
             .add_systems(
                 OnEnter(SimulationState::Starting),
                 (
+                    // Avant le repositionnement : positions finales de l'époque terminée
+                    export_positions_at_epoch_end,
+                    compute_novelty_scores,
+                    record_food_efficiency,
+                    apply_mutation_rate_change,
                     spawn_simulations_with_particles,
                     spawn_food,
+                    // Avant le repositionnement, qui ne replace que les particules du bon type
+                    restore_particle_types.run_if(type_migration_enabled),
                     reset_for_new_epoch,
+                    apply_food_pressure,
+                    reset_exploration_scores,
+                    reset_food_eaten_counts,
+                    reset_energy_budget,
+                    reset_cluster_scores,
+                    finish_session_resume,
+                    handle_max_epochs,
                 )
                     .chain(),
             )
@@ -46,7 +137,12 @@ impl Plugin for SimulationPlugin {
             )
             .add_systems(
                 Update,
-                physics_simulation_system
+                (
+                    physics_simulation_system,
+                    resolve_particle_collisions
+                        .after(physics_simulation_system)
+                        .run_if(hard_collision_enabled),
+                )
                     .run_if(in_state(SimulationState::Running))
                     .run_if(in_state(AppState::Simulation))
                     .run_if(compute_disabled),
@@ -57,24 +153,191 @@ impl Plugin for SimulationPlugin {
                 (
                     detect_food_collision,
                     check_epoch_end,
-                    process_save_requests,
+                    // Après check_epoch_end : les sauvegardes auto voient encore les génomes de l'époque
+                    process_save_requests.after(check_epoch_end),
+                    rolling_selection_system,
+                    track_exploration_system,
                 )
                     .run_if(in_state(SimulationState::Running))
                     .run_if(in_state(AppState::Simulation)),
             )
+            // Bouton « Save Best » de la fenêtre de fin de simulation
+            .add_systems(
+                Update,
+                process_save_requests
+                    .run_if(in_state(SimulationState::Finished))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Objectifs de sélection, lus en fin d'époque ou par la sélection glissante
+            .add_systems(
+                Update,
+                update_secondary_scores
+                    .before(check_epoch_end)
+                    .before(rolling_selection_system)
+                    .run_if(multi_objective_enabled)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            .add_systems(
+                Update,
+                detect_particle_predation
+                    .after(physics_simulation_system)
+                    .run_if(predation_enabled)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Détection des amas et bonus de cohésion
+            .add_systems(
+                Update,
+                detect_clusters_system
+                    .after(physics_simulation_system)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Vieillissement des particules
+            .add_systems(
+                Update,
+                age_particles_system
+                    .after(physics_simulation_system)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Bouton « Mutate Now » de la barre du haut
+            .add_systems(
+                Update,
+                force_mutation_system
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Plasticité : changements de type aléatoires
+            .add_systems(
+                Update,
+                particle_type_migration_system
+                    .after(physics_simulation_system)
+                    .run_if(type_migration_enabled)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Budget d'énergie : consommation, extinction et naissances
+            .add_systems(
+                Update,
+                energy_budget_system
+                    .after(physics_simulation_system)
+                    .after(detect_food_collision)
+                    .run_if(energy_budget_enabled)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Traînées des particules
+            .add_systems(
+                Update,
+                (update_particle_trails, render_particle_trails)
+                    .chain()
+                    .after(physics_simulation_system)
+                    .run_if(in_state(SimulationState::Running))
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Flèches de vitesse de la simulation sélectionnée
+            .add_systems(
+                Update,
+                draw_velocity_arrows_system
+                    .after(physics_simulation_system)
+                    .run_if(resource_exists::<ForceMatrixUI>)
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Carte de densité de la simulation sélectionnée
+            .add_systems(
+                Update,
+                (update_density_heatmap, draw_density_heatmap)
+                    .chain()
+                    .run_if(resource_exists::<ForceMatrixUI>)
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Tendance des meilleurs scores, affichée dans la barre du haut
+            .add_systems(
+                Update,
+                (update_convergence_estimator, update_session_stats)
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Comptage des particules par type (simulation, visualiseur, bac à sable)
+            .add_systems(Update, count_particles_by_type_system)
+            // Changement de palette : recoloration des particules déjà créées
+            .add_systems(
+                Update,
+                update_particle_materials.run_if(resource_changed::<ParticleTypesConfig>),
+            )
+            // Émission des particules selon leur vitesse
+            .add_systems(
+                Update,
+                update_particle_colors_system.after(update_particle_materials),
+            )
+            // Cadre de la grille
+            .add_systems(
+                Update,
+                draw_grid_gizmo_system
+                    .run_if(resource_exists::<ForceMatrixUI>)
+                    .run_if(in_state(AppState::Simulation)),
+            )
             // AJOUT DU SYSTÈME handle_pause_input
             .add_systems(
                 Update,
-                handle_pause_input.run_if(in_state(AppState::Simulation)),
+                (handle_pause_input, keyboard_speed_control)
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Export des positions demandé depuis la matrice de forces
+            .add_systems(
+                Update,
+                export_requested_positions.run_if(resource_exists::<ForceMatrixUI>),
+            )
+            // Ctrl+S : sauvegarde rapide de la meilleure simulation
+            .add_systems(
+                Update,
+                quick_save_best_input.run_if(in_state(AppState::Simulation)),
             )
-            .add_systems(OnExit(AppState::Simulation), cleanup_all);
+            // Aide des raccourcis clavier
+            .add_systems(
+                Update,
+                toggle_help_input
+                    .run_if(resource_exists::<HelpUI>)
+                    .run_if(in_state(AppState::Simulation)),
+            )
+            // Fichier de reprise en cas de crash
+            .add_systems(
+                Update,
+                autosave_session.run_if(in_state(AppState::Simulation)),
+            )
+            // Placement des obstacles au clic (panneau « Edit Obstacles »)
+            .add_systems(
+                Update,
+                place_obstacle_on_click.run_if(in_state(AppState::Simulation)),
+            )
+            .add_systems(
+                OnExit(AppState::Simulation),
+                (cleanup_all, despawn_obstacles, delete_session_file),
+            );
     }
 }
 
+fn hard_collision_enabled(sim_params: Res<SimulationParameters>) -> bool {
+    sim_params.enable_hard_collision
+}
+
+fn type_migration_enabled(sim_params: Res<SimulationParameters>) -> bool {
+    sim_params.type_migration_rate > 0.0
+}
+
+fn energy_budget_enabled(sim_params: Res<SimulationParameters>) -> bool {
+    sim_params.energy_budget.is_some()
+}
+
 fn compute_disabled(compute: Res<ComputeEnabled>) -> bool {
     !compute.0
 }
 
+pub fn predation_enabled(predation: Res<PredationEnabled>) -> bool {
+    predation.0
+}
+
 fn transition_to_running(
     mut next_state: ResMut<NextState<SimulationState>>,
     compute_enabled: Res<ComputeEnabled>,
@@ -92,6 +355,13 @@ fn cleanup_all(
     food: Query<Entity, With<Food>>,
     cameras: Query<Entity, With<ViewportCamera>>,
     mut entities_spawned: ResMut<EntitiesSpawned>,
+    mut epoch_history: ResMut<EpochHistoryResource>,
+    mut lineage_forest: ResMut<LineageForest>,
+    mut diversity_history: ResMut<PopulationDiversityHistory>,
+    mut fitness_history: ResMut<FitnessHistory>,
+    mut epoch_summary: ResMut<EpochEndSummary>,
+    mut hall_of_fame: ResMut<HallOfFame>,
+    mut novelty_archive: ResMut<NoveltyArchive>,
 ) {
     for entity in simulations.iter() {
         commands.entity(entity).despawn();
@@ -106,6 +376,13 @@ fn cleanup_all(
     }
 
     entities_spawned.0 = false;
+    epoch_history.clear();
+    lineage_forest.clear();
+    diversity_history.0.clear();
+    fitness_history.0.clear();
+    epoch_summary.dismiss();
+    hall_of_fame.clear();
+    novelty_archive.clear();
 
     info!("Nettoyage complet de la simulation");
 }
\ No newline at end of file
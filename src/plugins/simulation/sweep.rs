@@ -0,0 +1,25 @@
+use crate::states::app::AppState;
+use crate::states::simulation::SimulationState;
+use crate::systems::simulation::reset::reset_for_new_epoch;
+use crate::systems::sweep::{
+    ParameterSweep, cancel_interrupted_sweep, launch_next_combination, record_sweep_epoch,
+};
+use bevy::prelude::*;
+
+/// Balayage de paramètres : enchaîne les simulations de chaque point de la grille
+pub struct SweepPlugin;
+
+impl Plugin for SweepPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ParameterSweep>()
+            .add_systems(OnEnter(AppState::MainMenu), cancel_interrupted_sweep)
+            .add_systems(
+                Update,
+                launch_next_combination.run_if(in_state(AppState::MainMenu)),
+            )
+            .add_systems(
+                OnEnter(SimulationState::Starting),
+                record_sweep_epoch.after(reset_for_new_epoch),
+            );
+    }
+}
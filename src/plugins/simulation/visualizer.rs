@@ -1,17 +1,36 @@
 use crate::plugins::simulation::compute::ComputeEnabled;
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::ParticleTypesConfig;
 use crate::resources::config::simulation::SimulationParameters;
 use crate::resources::world::boundary::BoundaryMode;
 use crate::resources::world::grid::GridParameters;
+use crate::resources::world::physics::PhysicsState;
 use crate::states::app::AppState;
-use crate::systems::simulation::collision::detect_food_collision;
+use crate::systems::persistence::archive::{PopulationExportState, advance_population_export};
+use crate::systems::persistence::watcher::{
+    start_populations_watcher, watch_populations_directory, FileWatcher,
+};
+use crate::systems::rendering::grid_gizmo::draw_grid_gizmo_system;
+use crate::systems::rendering::trails::{render_particle_trails, update_particle_trails};
+use crate::systems::simulation::aging::age_particles_system;
+use crate::plugins::simulation::simulation::predation_enabled;
+use crate::systems::simulation::collision::{detect_food_collision, detect_particle_predation};
+use crate::systems::simulation::obstacles::spawn_saved_obstacles;
 use crate::systems::simulation::physics::physics_simulation_system;
+use crate::systems::simulation::replay::{
+    SimulationRecorder, apply_replay_frame, clear_recording, record_simulation_frame,
+    replay_inactive,
+};
 use crate::systems::simulation::spawning::spawn_food;
 use crate::systems::simulation::visualizer_spawning::spawn_visualizer_simulation;
 use bevy::prelude::*;
-use crate::components::entities::food::Food;
+use crate::components::entities::food::{Food, FoodType};
+use crate::components::entities::obstacle::Obstacle;
 use crate::components::entities::particle::{Particle, ParticleType, Velocity};
 use crate::components::entities::simulation::{Simulation, SimulationId};
 use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::TotalDistanceTravelled;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
 
 pub struct VisualizerPlugin;
 
@@ -19,6 +38,20 @@ impl Plugin for VisualizerPlugin {
     fn build(&self, app: &mut App) {
         app.add_systems(
             OnEnter(AppState::Visualization),
+            (spawn_visualizer_simulation, spawn_food, spawn_saved_obstacles).chain(),
+        )
+        // Rechargement à chaud du dossier populations/
+        .add_systems(Startup, start_populations_watcher)
+        // Export ZIP des populations cochées, une par frame
+        .init_resource::<PopulationExportState>()
+        .add_systems(Update, advance_population_export)
+        .add_systems(
+            Update,
+            watch_populations_directory.run_if(resource_exists::<FileWatcher>),
+        )
+        // Le bac à sable utilise la même simulation unique que le visualiseur
+        .add_systems(
+            OnEnter(AppState::Sandbox),
             (spawn_visualizer_simulation, spawn_food).chain(),
         )
         // Système CPU uniquement
@@ -28,20 +61,68 @@ impl Plugin for VisualizerPlugin {
                 visualizer_physics_system,
                 detect_food_collision.after(visualizer_physics_system),
             )
-                .run_if(in_state(AppState::Visualization))
-                .run_if(compute_disabled),
+                .run_if(single_simulation_active)
+                .run_if(compute_disabled)
+                .run_if(replay_inactive),
+        )
+        // Enregistrement et rejeu image par image
+        .init_resource::<SimulationRecorder>()
+        .add_systems(
+            Update,
+            (
+                record_simulation_frame.after(visualizer_physics_system),
+                apply_replay_frame,
+            )
+                .run_if(in_state(AppState::Visualization)),
         )
         // Système GPU (si activé)
         .add_systems(
             Update,
             detect_food_collision
-                .run_if(in_state(AppState::Visualization))
+                .run_if(single_simulation_active)
                 .run_if(compute_enabled),
         )
-        .add_systems(OnExit(AppState::Visualization), cleanup_visualization);
+        .add_systems(
+            Update,
+            detect_particle_predation
+                .after(visualizer_physics_system)
+                .run_if(single_simulation_active)
+                .run_if(predation_enabled)
+                .run_if(replay_inactive),
+        )
+        .add_systems(
+            Update,
+            age_particles_system
+                .after(visualizer_physics_system)
+                .run_if(single_simulation_active)
+                .run_if(replay_inactive),
+        )
+        .add_systems(
+            Update,
+            (update_particle_trails, render_particle_trails)
+                .chain()
+                .after(visualizer_physics_system)
+                .run_if(single_simulation_active),
+        )
+        .add_systems(
+            Update,
+            draw_grid_gizmo_system
+                .run_if(resource_exists::<ForceMatrixUI>)
+                .run_if(single_simulation_active),
+        )
+        .add_systems(
+            OnExit(AppState::Visualization),
+            (cleanup_visualization, clear_recording),
+        )
+        .add_systems(OnExit(AppState::Sandbox), cleanup_visualization);
     }
 }
 
+/// Visualisation d'une population sauvegardée ou bac à sable
+fn single_simulation_active(state: Res<State<AppState>>) -> bool {
+    matches!(state.get(), AppState::Visualization | AppState::Sandbox)
+}
+
 fn compute_enabled(compute: Res<ComputeEnabled>) -> bool {
     compute.0
 }
@@ -52,7 +133,11 @@ fn compute_disabled(compute: Res<ComputeEnabled>) -> bool {
 
 /// Wrapper pour le système physique du visualizer (évite les conflits de noms)
 fn visualizer_physics_system(
+    time: Res<Time>,
+    physics_state: ResMut<PhysicsState>,
     sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
+    particle_config: Res<ParticleTypesConfig>,
     grid: Res<GridParameters>,
     boundary_mode: Res<BoundaryMode>,
     simulations: Query<(&SimulationId, &Genotype), With<Simulation>>,
@@ -66,15 +151,23 @@ fn visualizer_physics_system(
         ),
         With<Particle>,
     >,
-    food_query: Query<(&Transform, &ViewVisibility), (With<Food>, Without<Particle>)>,
+    food_query: Query<(&Transform, &ViewVisibility, &FoodType), (With<Food>, Without<Particle>)>,
+    obstacle_query: Query<(&Transform, &Obstacle), Without<Particle>>,
+    distances: Query<&mut TotalDistanceTravelled, With<Simulation>>,
 ) {
     physics_simulation_system(
+        time,
+        physics_state,
         sim_params,
+        global_config,
+        particle_config,
         grid,
         boundary_mode,
         simulations,
         particles,
         food_query,
+        obstacle_query,
+        distances,
     );
 }
 
@@ -82,6 +175,7 @@ fn cleanup_visualization(
     mut commands: Commands,
     simulations: Query<Entity, With<Simulation>>,
     food: Query<Entity, With<Food>>,
+    obstacles: Query<Entity, With<Obstacle>>,
 ) {
     for entity in simulations.iter() {
         commands.entity(entity).despawn();
@@ -89,6 +183,9 @@ fn cleanup_visualization(
     for entity in food.iter() {
         commands.entity(entity).despawn();
     }
+    for entity in obstacles.iter() {
+        commands.entity(entity).despawn();
+    }
 
     info!("Nettoyage de la visualisation terminé");
 }
@@ -1,15 +1,28 @@
 use crate::states::app::AppState;
+use crate::states::simulation::SimulationState;
+use crate::systems::rendering::screenshot::{
+    CaptureScreenshot, ScreenshotState, capture_screenshot_system, screenshot_key_input,
+    update_screenshot_notice,
+};
 use crate::systems::rendering::viewport_manager::{
     UISpace, assign_render_layers, delayed_viewport_update, force_viewport_update_after_startup,
     update_viewports,
 };
-use crate::systems::rendering::viewport_overlay::draw_viewport_overlays;
+use crate::systems::rendering::viewport_overlay::{camera_click_system, draw_viewport_overlays};
 use crate::ui::dialogs::save_population::{
     SavePopulationUI, save_population_ui, simulations_list_ui,
 };
 use crate::ui::menus::main_menu::{MenuConfig, main_menu_ui};
 use crate::ui::menus::visualizer_menu::{VisualizerSelection, visualizer_ui};
+use crate::ui::panels::comparison::comparison_panel_ui;
+#[cfg(debug_assertions)]
+use crate::ui::panels::debug_resources::debug_resources_window;
+use crate::ui::panels::epoch_summary::epoch_summary_ui;
 use crate::ui::panels::force_matrix::{ForceMatrixUI, force_matrix_window, speed_control_ui};
+use crate::ui::panels::help::{HelpUI, help_window};
+use crate::ui::panels::obstacles::{ObstacleEditorUI, obstacle_editor_window};
+use crate::ui::panels::replay::replay_controls_ui;
+use crate::ui::panels::simulation_complete::simulation_complete_ui;
 use bevy::prelude::*;
 use bevy_egui::{EguiContextPass, EguiPlugin};
 
@@ -24,9 +37,25 @@ impl Plugin for UIPlugin {
         // Resources
         app.init_resource::<ForceMatrixUI>();
         app.init_resource::<UISpace>();
-        app.init_resource::<MenuConfig>();
+        let menu_config = MenuConfig::from_startup_world(app.world());
+        app.insert_resource(menu_config);
         app.init_resource::<SavePopulationUI>();
         app.init_resource::<VisualizerSelection>();
+        app.init_resource::<ScreenshotState>();
+        app.init_resource::<HelpUI>();
+        app.init_resource::<ObstacleEditorUI>();
+
+        // Captures d'écran
+        app.add_event::<CaptureScreenshot>();
+        app.add_systems(
+            Update,
+            (
+                screenshot_key_input,
+                capture_screenshot_system,
+                update_screenshot_notice,
+            )
+                .chain(),
+        );
 
         // Système pour forcer la mise à jour des viewports après le démarrage
         app.add_systems(Startup, force_viewport_update_after_startup);
@@ -52,7 +81,9 @@ impl Plugin for UIPlugin {
         // Systèmes UI du visualiseur
         app.add_systems(
             EguiContextPass,
-            visualizer_ui.run_if(in_state(AppState::Visualizer)),
+            (visualizer_ui, save_population_ui)
+                .chain()
+                .run_if(in_state(AppState::Visualizer)),
         );
 
         // Systèmes UI et viewport pour la simulation
@@ -65,13 +96,53 @@ impl Plugin for UIPlugin {
                     .after(simulations_list_ui)
                     .after(force_matrix_window),
                 draw_viewport_overlays.after(update_viewports),
+                camera_click_system.after(draw_viewport_overlays),
+                help_window,
+                obstacle_editor_window,
+                epoch_summary_ui,
+                comparison_panel_ui.after(simulations_list_ui),
             )
                 .run_if(in_state(AppState::Simulation)),
         );
 
+        // Fenêtre de résultats après la dernière époque
+        app.add_systems(
+            EguiContextPass,
+            simulation_complete_ui
+                .after(speed_control_ui)
+                .run_if(in_state(SimulationState::Finished))
+                .run_if(in_state(AppState::Simulation)),
+        );
+
+        app.add_systems(
+            EguiContextPass,
+            (
+                speed_control_ui,
+                replay_controls_ui.after(speed_control_ui),
+                draw_viewport_overlays,
+            )
+                .run_if(in_state(AppState::Visualization)),
+        );
+
+        // Bac à sable : matrice éditable et sauvegarde du génome
+        app.add_systems(
+            EguiContextPass,
+            (
+                speed_control_ui,
+                (simulations_list_ui, force_matrix_window, save_population_ui),
+            )
+                .run_if(in_state(AppState::Sandbox)),
+        );
+
+        // Valeurs des ressources globales, avec la barre du haut qui l'ouvre
+        #[cfg(debug_assertions)]
         app.add_systems(
             EguiContextPass,
-            (speed_control_ui, draw_viewport_overlays).run_if(in_state(AppState::Visualization)),
+            debug_resources_window.after(speed_control_ui).run_if(
+                in_state(AppState::Simulation)
+                    .or(in_state(AppState::Visualization))
+                    .or(in_state(AppState::Sandbox)),
+            ),
         );
     }
 }
\ No newline at end of file
@@ -0,0 +1,17 @@
+use bevy::prelude::*;
+
+/// Réglages des effets sonores (nourriture mangée, fin d'époque)
+#[derive(Resource, Clone, Copy)]
+pub struct AudioSettings {
+    pub audio_enabled: bool,
+    pub sfx_volume: f32, // 0.0..=1.0
+}
+
+impl Default for AudioSettings {
+    fn default() -> Self {
+        Self {
+            audio_enabled: true,
+            sfx_volume: 0.5,
+        }
+    }
+}
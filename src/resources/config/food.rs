@@ -1,12 +1,17 @@
 use bevy::prelude::*;
 use crate::globals::*;
 
-#[derive(Resource)]
+#[derive(Resource, Debug)]
 pub struct FoodParameters {
     pub food_count: usize,
     pub respawn_enabled: bool,
     pub respawn_cooldown: f32,
     pub food_value: f32,
+    pub food_type_count: usize,
+    // Pression environnementale : variation du nombre de nourritures à chaque époque
+    pub food_decay_per_epoch: f32,
+    pub food_growth_per_epoch: f32,
+    pub min_food_count: usize,
 }
 
 impl Default for FoodParameters {
@@ -16,6 +21,10 @@ impl Default for FoodParameters {
             respawn_enabled: true,
             respawn_cooldown: DEFAULT_FOOD_RESPAWN_TIME,
             food_value: DEFAULT_FOOD_VALUE,
+            food_type_count: DEFAULT_FOOD_TYPE_COUNT,
+            food_decay_per_epoch: 0.0,
+            food_growth_per_epoch: 0.0,
+            min_food_count: 1,
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,122 @@
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::sync::OnceLock;
+use crate::globals::*;
+
+/// Chemin du fichier de configuration utilisateur
+pub const CONFIG_FILE_PATH: &str = "config.toml";
+
+/// `config.toml` livré avec le dépôt, intégré à la compilation
+const BUNDLED_CONFIG: &str = include_str!("../../../config.toml");
+
+/// Valeurs par défaut chargées depuis `config.toml` (remplace les constantes de `globals.rs`)
+#[derive(Resource, Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct GlobalConfig {
+    // Paramètres de simulation
+    pub particle_count: usize,
+    pub particle_types: usize,
+    pub simulation_count: usize,
+    pub epoch_duration: f32,
+
+    // Paramètres de la grille
+    pub grid_width: f32,
+    pub grid_height: f32,
+    pub grid_depth: f32,
+
+    // Paramètres de la nourriture
+    pub food_count: usize,
+    pub food_respawn_time: f32,
+    pub food_value: f32,
+
+    // Paramètres physiques
+    pub physics_timestep: f32,
+    pub max_velocity: f32,
+    pub force_scale_factor: f32,
+    pub max_force_range: f32,
+
+    // Paramètres génétiques
+    pub elite_ratio: f32,
+    pub mutation_rate: f32,
+    pub crossover_rate: f32,
+    pub island_count: usize,
+    pub migration_interval: usize,
+}
+
+impl Default for GlobalConfig {
+    fn default() -> Self {
+        Self {
+            particle_count: DEFAULT_PARTICLE_COUNT,
+            particle_types: DEFAULT_PARTICLE_TYPES,
+            simulation_count: DEFAULT_SIMULATION_COUNT,
+            epoch_duration: DEFAULT_EPOCH_DURATION,
+
+            grid_width: DEFAULT_GRID_WIDTH,
+            grid_height: DEFAULT_GRID_HEIGHT,
+            grid_depth: DEFAULT_GRID_DEPTH,
+
+            food_count: DEFAULT_FOOD_COUNT,
+            food_respawn_time: DEFAULT_FOOD_RESPAWN_TIME,
+            food_value: DEFAULT_FOOD_VALUE,
+
+            physics_timestep: PHYSICS_TIMESTEP,
+            max_velocity: MAX_VELOCITY,
+            force_scale_factor: FORCE_SCALE_FACTOR,
+            max_force_range: DEFAULT_MAX_FORCE_RANGE,
+
+            elite_ratio: DEFAULT_ELITE_RATIO,
+            mutation_rate: DEFAULT_MUTATION_RATE,
+            crossover_rate: DEFAULT_CROSSOVER_RATE,
+            island_count: DEFAULT_ISLAND_COUNT,
+            migration_interval: DEFAULT_MIGRATION_INTERVAL,
+        }
+    }
+}
+
+impl GlobalConfig {
+    /// Configuration livrée, parsée une seule fois ; repli de `load`
+    pub fn bundled() -> Self {
+        static BUNDLED: OnceLock<GlobalConfig> = OnceLock::new();
+        BUNDLED
+            .get_or_init(|| {
+                toml::from_str(BUNDLED_CONFIG).expect("config.toml intégré invalide")
+            })
+            .clone()
+    }
+
+    /// Charge `config.toml`, ou y copie la configuration livrée s'il n'existe pas
+    pub fn load() -> Self {
+        let path = Path::new(CONFIG_FILE_PATH);
+
+        if !path.exists() {
+            if let Err(e) = fs::write(path, BUNDLED_CONFIG) {
+                warn!("Impossible d'écrire {:?}: {}", path, e);
+            } else {
+                info!("Fichier de configuration par défaut créé: {:?}", path);
+            }
+            return Self::bundled();
+        }
+
+        match fs::read_to_string(path) {
+            Ok(content) => match toml::from_str::<GlobalConfig>(&content) {
+                Ok(config) => {
+                    info!("Configuration chargée depuis {:?}", path);
+                    config
+                }
+                Err(e) => {
+                    warn!(
+                        "Erreur de lecture de {:?}, configuration livrée utilisée: {}",
+                        path, e
+                    );
+                    Self::bundled()
+                }
+            },
+            Err(e) => {
+                warn!("Impossible de lire {:?}: {}", path, e);
+                Self::bundled()
+            }
+        }
+    }
+}
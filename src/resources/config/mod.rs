@@ -1,3 +1,7 @@
+pub mod audio;
 pub mod food;
+pub mod global;
 pub mod particle_types;
-pub mod simulation;
\ No newline at end of file
+pub mod predation;
+pub mod random;
+pub mod simulation;
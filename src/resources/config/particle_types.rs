@@ -1,18 +1,94 @@
 use bevy::prelude::*;
 use crate::globals::*;
+use serde::{Deserialize, Serialize};
+
+/// Facteur d'émission des particules (assez fort pour déclencher le bloom)
+pub const EMISSIVE_MULTIPLIER: f32 = 2.0;
+
+/// Longueur maximale d'un nom de type (au-delà, la grille des forces se déforme)
+pub const MAX_TYPE_NAME_LENGTH: usize = 12;
+
+/// Palette des types de particules, dont des variantes adaptées aux daltoniens
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ColorPalette {
+    /// Teintes HSL régulièrement espacées
+    #[default]
+    AutoHSL,
+    /// Bleu, orange, violet, sarcelle : distinguables sans le vert
+    Deuteranopia,
+    /// Rouge, cyan, rose, vert foncé : distinguables sans le bleu
+    Tritanopia,
+    /// Blanc, jaune, cyan, magenta sur fond sombre
+    HighContrast,
+}
+
+impl ColorPalette {
+    pub const ALL: [ColorPalette; 4] = [
+        ColorPalette::AutoHSL,
+        ColorPalette::Deuteranopia,
+        ColorPalette::Tritanopia,
+        ColorPalette::HighContrast,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ColorPalette::AutoHSL => "Auto (HSL)",
+            ColorPalette::Deuteranopia => "Deuteranopia",
+            ColorPalette::Tritanopia => "Tritanopia",
+            ColorPalette::HighContrast => "High Contrast",
+        }
+    }
+
+    pub fn is_auto(&self) -> bool {
+        *self == ColorPalette::AutoHSL
+    }
+
+    /// Couleurs de base de la palette, `None` pour la palette HSL calculée
+    fn base_colors(self) -> Option<[Color; 4]> {
+        match self {
+            ColorPalette::AutoHSL => None,
+            ColorPalette::Deuteranopia => Some([
+                Color::srgb(0.0, 0.45, 0.70),
+                Color::srgb(0.90, 0.62, 0.0),
+                Color::srgb(0.60, 0.35, 0.80),
+                Color::srgb(0.0, 0.62, 0.58),
+            ]),
+            ColorPalette::Tritanopia => Some([
+                Color::srgb(0.89, 0.10, 0.11),
+                Color::srgb(0.0, 0.80, 0.85),
+                Color::srgb(0.97, 0.51, 0.75),
+                Color::srgb(0.0, 0.43, 0.35),
+            ]),
+            ColorPalette::HighContrast => Some([
+                Color::WHITE,
+                Color::srgb(1.0, 1.0, 0.0),
+                Color::srgb(0.0, 1.0, 1.0),
+                Color::srgb(1.0, 0.0, 1.0),
+            ]),
+        }
+    }
+}
+
+/// Mesh et matériau partagés de chaque type, créés avec les particules de la simulation
+#[derive(Resource, Default)]
+pub struct ParticleTypeAssets {
+    pub meshes: Vec<Handle<Mesh>>,
+    pub materials: Vec<Handle<StandardMaterial>>,
+}
 
 #[derive(Resource)]
 pub struct ParticleTypesConfig {
     pub type_count: usize,
     pub colors: Vec<(Color, LinearRgba)>, 
+    pub radii: Vec<f32>,
+    pub velocity_half_lives: Vec<f32>,
+    pub type_names: Vec<String>,
+    pub palette: ColorPalette,
 }
 
 impl Default for ParticleTypesConfig {
     fn default() -> Self {
-        Self {
-            type_count: DEFAULT_PARTICLE_TYPES,
-            colors: Self::generate_colors(DEFAULT_PARTICLE_TYPES),
-        }
+        Self::new(DEFAULT_PARTICLE_TYPES)
     }
 }
 
@@ -21,22 +97,126 @@ impl ParticleTypesConfig {
         Self {
             type_count,
             colors: Self::generate_colors(type_count),
+            radii: Self::generate_radii(type_count),
+            velocity_half_lives: Self::generate_velocity_half_lives(type_count),
+            type_names: Self::generate_type_names(type_count),
+            palette: ColorPalette::default(),
         }
     }
 
+    /// Recalcule les couleurs de tous les types avec une autre palette
+    pub fn set_palette(&mut self, palette: ColorPalette) {
+        self.palette = palette;
+        self.colors = Self::generate_palette_colors(self.type_count, palette);
+    }
+
+    /// Noms par défaut : « Type 0 », « Type 1 », ...
+    pub fn generate_type_names(count: usize) -> Vec<String> {
+        (0..count).map(Self::default_type_name).collect()
+    }
+
+    pub fn default_type_name(type_index: usize) -> String {
+        format!("Type {}", type_index)
+    }
+
+    /// Nom valide : non vide et d'au plus `MAX_TYPE_NAME_LENGTH` caractères
+    pub fn is_valid_type_name(name: &str) -> bool {
+        let length = name.trim().chars().count();
+        length > 0 && length <= MAX_TYPE_NAME_LENGTH
+    }
+
+    /// Remplace les noms invalides : vide → nom par défaut, trop long → tronqué
+    pub fn sanitize_type_names(names: &[String], count: usize) -> Vec<String> {
+        (0..count)
+            .map(|type_index| {
+                let name = names.get(type_index).map(|name| name.trim()).unwrap_or("");
+                if name.is_empty() {
+                    Self::default_type_name(type_index)
+                } else {
+                    name.chars().take(MAX_TYPE_NAME_LENGTH).collect()
+                }
+            })
+            .collect()
+    }
+
     /// Génère des couleurs distinctes pour chaque type avec émissive
-    fn generate_colors(count: usize) -> Vec<(Color, LinearRgba)> {
+    pub fn generate_colors(count: usize) -> Vec<(Color, LinearRgba)> {
         (0..count)
             .map(|i| {
                 let hue = (i as f32 / count as f32) * 360.0;
                 let base_color = Color::hsl(hue, 0.8, 0.6);
-                let emissive = base_color.to_linear() * 0.5; // Émission modérée
+                let emissive = base_color.to_linear() * EMISSIVE_MULTIPLIER;
+                (base_color, emissive)
+            })
+            .collect()
+    }
+
+    /// Couleurs d'une palette ; au-delà de ses couleurs de base, on les réutilise assombries
+    pub fn generate_palette_colors(
+        count: usize,
+        palette: ColorPalette,
+    ) -> Vec<(Color, LinearRgba)> {
+        let Some(base_colors) = palette.base_colors() else {
+            return Self::generate_colors(count);
+        };
+
+        (0..count)
+            .map(|i| {
+                let cycle = (i / base_colors.len()) as f32;
+                let base_color = base_colors[i % base_colors.len()].darker(0.2 * cycle);
+                let emissive = base_color.to_linear() * EMISSIVE_MULTIPLIER;
                 (base_color, emissive)
             })
             .collect()
     }
 
+    /// Remplace la palette automatique par des couleurs sRGBA choisies par l'utilisateur
+    pub fn apply_custom_colors(&mut self, custom_colors: &[[f32; 4]]) {
+        for (slot, [r, g, b, a]) in self.colors.iter_mut().zip(custom_colors) {
+            let base_color = Color::srgba(*r, *g, *b, *a);
+            let emissive = base_color.to_linear() * EMISSIVE_MULTIPLIER;
+            *slot = (base_color, emissive);
+        }
+    }
+
+    /// Rayons par défaut : identiques pour tous les types
+    pub fn generate_radii(count: usize) -> Vec<f32> {
+        vec![PARTICLE_RADIUS; count]
+    }
+
+    /// Demi-vies de la vitesse par défaut : même amortissement pour tous les types
+    pub fn generate_velocity_half_lives(count: usize) -> Vec<f32> {
+        vec![DEFAULT_VELOCITY_HALF_LIFE; count]
+    }
+
     pub fn get_color_for_type(&self, type_index: usize) -> (Color, LinearRgba) {
         self.colors[type_index % self.colors.len()]
     }
+
+    pub fn get_name_for_type(&self, type_index: usize) -> String {
+        self.type_names
+            .get(type_index)
+            .cloned()
+            .unwrap_or_else(|| Self::default_type_name(type_index))
+    }
+
+    /// Abréviation d'un type pour les affichages compacts : initiale du nom, ou indice par défaut
+    pub fn get_short_label_for_type(&self, type_index: usize) -> String {
+        let name = self.get_name_for_type(type_index);
+        if name == Self::default_type_name(type_index) {
+            return type_index.to_string();
+        }
+        name.chars().take(1).flat_map(char::to_uppercase).collect()
+    }
+
+    pub fn get_radius_for_type(&self, type_index: usize) -> f32 {
+        self.radii.get(type_index).copied().unwrap_or(PARTICLE_RADIUS)
+    }
+
+    pub fn get_velocity_half_life_for_type(&self, type_index: usize) -> f32 {
+        self.velocity_half_lives
+            .get(type_index)
+            .copied()
+            .unwrap_or(DEFAULT_VELOCITY_HALF_LIFE)
+    }
 }
\ No newline at end of file
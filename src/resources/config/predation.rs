@@ -0,0 +1,15 @@
+use bevy::prelude::*;
+
+/// Active la chaîne alimentaire : le type i mange le type (i+1) % type_count
+#[derive(Resource, Default, Clone, Copy)]
+pub struct PredationEnabled(pub bool);
+
+/// Fraction (0.0–1.0) de la distance de contact nécessaire pour qu'une prédation ait lieu
+#[derive(Resource, Clone, Copy)]
+pub struct PredationStrength(pub f32);
+
+impl Default for PredationStrength {
+    fn default() -> Self {
+        Self(1.0)
+    }
+}
@@ -0,0 +1,39 @@
+use bevy::prelude::*;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+
+/// Graine de la partie en cours (`--seed`, menu ou population chargée), None = non reproductible.
+/// Réinsérée à chaque lancement : les flux des systèmes repartent alors du début
+#[derive(Resource, Default, Clone, Copy, Debug)]
+pub struct RunSeed(pub Option<u64>);
+
+/// Générateur propre à un système, à garder en `Local`.
+/// Chaque système tire dans son propre flux : le résultat ne dépend pas de l'ordre
+/// d'exécution des systèmes parallèles
+#[derive(Default)]
+pub struct RngStream(Option<StdRng>);
+
+impl RngStream {
+    /// Flux nommé, dérivé de la graine ; recréé quand une nouvelle partie commence
+    pub fn get(&mut self, seed: &Res<RunSeed>, stream: &str) -> &mut StdRng {
+        if seed.is_changed() {
+            self.0 = None;
+        }
+        self.0.get_or_insert_with(|| stream_rng(seed.0, stream))
+    }
+}
+
+/// Générateur d'un flux nommé : dérivé de la graine si elle existe, sinon de l'entropie système
+pub fn stream_rng(seed: Option<u64>, stream: &str) -> StdRng {
+    match seed {
+        Some(seed) => StdRng::seed_from_u64(seed ^ stream_hash(stream)),
+        None => StdRng::from_os_rng(),
+    }
+}
+
+/// FNV-1a : décorrèle les flux tirés d'une même graine
+fn stream_hash(stream: &str) -> u64 {
+    stream.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| {
+        (hash ^ byte as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
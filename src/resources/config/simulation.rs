@@ -1,13 +1,15 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 use crate::globals::*;
 
-#[derive(Default, PartialEq, Eq, Clone)]
+#[derive(Default, PartialEq, Eq, Clone, Debug)]
 pub enum SimulationSpeed {
     Paused,
     #[default]
     Normal,
     Fast,
     VeryFast,
+    UltraFast,
 }
 
 impl SimulationSpeed {
@@ -17,15 +19,178 @@ impl SimulationSpeed {
             SimulationSpeed::Normal => 1.0,
             SimulationSpeed::Fast => 2.0,
             SimulationSpeed::VeryFast => 4.0,
+            // Plafonné par max_substeps
+            SimulationSpeed::UltraFast => 8.0,
         }
     }
 }
 
-#[derive(Resource, Clone)]
+/// Opérateur de crossover utilisé par l'algorithme génétique
+#[derive(Default, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum CrossoverOperator {
+    /// Chaque gène vient de l'un ou l'autre parent
+    #[default]
+    Uniform,
+    /// BLX-α : tirage dans l'intervalle des parents élargi de α
+    Blend(f32),
+    /// Évolution différentielle : essai a + f·(b − c), croisement binomial de probabilité cr
+    DifferentialEvolution { f: f32, cr: f32 },
+}
+
+/// Représentation des gènes : flottants libres ou grille régulière sur [-2, 2]
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GenomeEncoding {
+    #[default]
+    Float32,
+    /// Pas de 1 / 2^(bits − 1), de 2 à 8 bits par gène
+    Quantised(u8),
+}
+
+impl GenomeEncoding {
+    pub const BITS_RANGE: std::ops::RangeInclusive<u8> = 2..=8;
+
+    /// Pas de la grille, None en flottant
+    pub fn step(&self) -> Option<f32> {
+        match *self {
+            GenomeEncoding::Float32 => None,
+            GenomeEncoding::Quantised(bits) => {
+                let bits = bits.clamp(*Self::BITS_RANGE.start(), *Self::BITS_RANGE.end());
+                Some(1.0 / (1u32 << (bits - 1)) as f32)
+            }
+        }
+    }
+
+    /// Point de la grille le plus proche, valeur inchangée en flottant
+    pub fn snap(&self, value: f32) -> f32 {
+        match self.step() {
+            Some(step) => (value / step).round() * step,
+            None => value,
+        }
+    }
+}
+
+/// Déroulement de l'évolution
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulationMode {
+    /// Époques fixes : toutes les simulations sont réinitialisées à chaque époque
+    #[default]
+    EpochBased,
+    /// Sélection glissante : seuls les moins bons génomes sont remplacés, sans reset global
+    Continuous,
+}
+
+/// Dimension de l'espace simulé
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SimulationDimension {
+    /// Plan XY : positions et vitesses en Z maintenues à zéro
+    TwoD,
+    #[default]
+    ThreeD,
+}
+
+impl SimulationDimension {
+    pub fn is_flat(&self) -> bool {
+        *self == SimulationDimension::TwoD
+    }
+
+    pub fn is_three_d(&self) -> bool {
+        *self == SimulationDimension::ThreeD
+    }
+}
+
+/// Comportement une fois `max_epochs` atteint
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OnMaxEpochs {
+    /// Physique arrêtée et fenêtre de résultats (sauvegarde, prolongation ou menu)
+    #[default]
+    ShowResults,
+    /// Sauvegarde du meilleur génome puis retour au menu principal
+    Stop,
+    /// Reprise à l'époque 0 en gardant les génomes
+    Loop,
+    /// Reprise à l'époque 0 avec des génomes aléatoires
+    LoopWithReset,
+}
+
+/// Forme du planning de la demi-vie des vitesses au fil des époques (recuit simulé)
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HalfLifeDecay {
+    /// Interpolation linéaire entre début et fin
+    #[default]
+    Linear,
+    /// Interpolation géométrique : même rapport d'une époque à l'autre
+    Exponential,
+    /// Paliers constants, `HALF_LIFE_DECAY_STEPS` niveaux
+    Step,
+}
+
+impl HalfLifeDecay {
+    pub const ALL: [HalfLifeDecay; 3] =
+        [HalfLifeDecay::Linear, HalfLifeDecay::Exponential, HalfLifeDecay::Step];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            HalfLifeDecay::Linear => "Linéaire",
+            HalfLifeDecay::Exponential => "Exponentielle",
+            HalfLifeDecay::Step => "Paliers",
+        }
+    }
+
+    /// Valeur entre `start` et `end` pour une progression `t` dans [0, 1]
+    pub fn interpolate(&self, start: f32, end: f32, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            HalfLifeDecay::Linear => start + (end - start) * t,
+            HalfLifeDecay::Exponential if start > 0.0 && end > 0.0 => {
+                start * (end / start).powf(t)
+            }
+            HalfLifeDecay::Exponential => start + (end - start) * t,
+            HalfLifeDecay::Step => {
+                let steps = HALF_LIFE_DECAY_STEPS.max(2) as f32;
+                let level = (t * steps).floor().min(steps - 1.0) / (steps - 1.0);
+                start + (end - start) * level
+            }
+        }
+    }
+}
+
+/// Objectif de sélection ; plusieurs objectifs activent le tri par rangs de Pareto
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ObjectiveFn {
+    /// Nourritures mangées pendant l'époque
+    FoodEaten,
+    /// Volume de la boîte englobante des positions visitées
+    ExplorationVolume,
+    /// Taille moyenne du plus grand amas
+    ClusterSize,
+    /// Score par unité de distance parcourue
+    FoodEfficiency,
+}
+
+impl ObjectiveFn {
+    pub const ALL: [ObjectiveFn; 4] = [
+        ObjectiveFn::FoodEaten,
+        ObjectiveFn::ExplorationVolume,
+        ObjectiveFn::ClusterSize,
+        ObjectiveFn::FoodEfficiency,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ObjectiveFn::FoodEaten => "Nourriture",
+            ObjectiveFn::ExplorationVolume => "Exploration",
+            ObjectiveFn::ClusterSize => "Taille d'amas",
+            ObjectiveFn::FoodEfficiency => "Efficacité",
+        }
+    }
+}
+
+#[derive(Resource, Clone, Debug)]
 pub struct SimulationParameters {
     // Paramètres d'époque
     pub current_epoch: usize,
     pub max_epochs: usize,
+    pub on_max_epochs: OnMaxEpochs,
     pub epoch_duration: f32,
     pub epoch_timer: Timer,
 
@@ -34,15 +199,82 @@ pub struct SimulationParameters {
     pub particle_count: usize,
     pub particle_types: usize,
     pub simulation_speed: SimulationSpeed,
+    pub auto_save_interval: Option<usize>,
+    pub simulation_mode: SimulationMode,
+    pub rolling_selection_interval: f32,
+    // Graine effective de la simulation (None = générateur non initialisé)
+    pub rng_seed: Option<u64>,
+
+    pub dimension: SimulationDimension,
 
     // Paramètres des forces
     pub max_force_range: f32,
     pub velocity_half_life: f32,
+    // Planning (début, fin) de la demi-vie au fil des époques (None = demi-vie fixe)
+    pub velocity_half_life_schedule: Option<(f32, f32)>,
+    pub decay_curve: HalfLifeDecay,
+    // Cellules par axe de la grille spatiale des voisins
+    pub spatial_grid_resolution: usize,
+    // Tore : images des particules proches des murs comme sources supplémentaires
+    pub use_ghost_particles: bool,
+    // Expérimental : les particules des autres simulations agissent, atténuées par le facteur
+    pub inter_simulation_interaction: bool,
+    pub inter_sim_force_scale: f32,
+
+    // Probabilité par pas qu'une particule change de type (plasticité phénotypique)
+    pub type_migration_rate: f32,
+
+    // Durée de vie des particules (None = immortelles)
+    pub particle_lifespan: Option<f32>,
+
+    // Budget d'énergie : seuil de naissance en énergie par particule (None = désactivé)
+    pub energy_budget: Option<f32>,
+
+    // Champ de gravité uniforme (None = désactivé)
+    pub gravity: Option<Vec3>,
+
+    // Chocs entre particules (sphères dures), physique CPU uniquement
+    pub enable_hard_collision: bool,
+
+    // Modulation sinusoïdale des forces au cours de l'époque
+    pub oscillating_forces: bool,
+    pub force_oscillation_period: f32,
 
     // Paramètres génétiques
     pub elite_ratio: f32,
+    pub tournament_size: usize,
     pub mutation_rate: f32,
     pub crossover_rate: f32,
+    pub crossover_operator: CrossoverOperator,
+    pub genome_encoding: GenomeEncoding,
+    // Gènes particule-particule de valeur absolue inférieure : forces nulles
+    pub gene_expression_threshold: f32,
+    // Interactions réciproques imposées après chaque mutation et crossover
+    pub symmetric_forces: bool,
+
+    // Objectifs de sélection (plus d'un : rangs de Pareto puis distance de crowding)
+    pub objectives: Vec<ObjectiveFn>,
+
+    // Spéciation (partage de fitness)
+    pub use_speciation: bool,
+    pub niche_radius: f32,
+    pub use_novelty_search: bool,
+    pub novelty_weight: f32,
+
+    // Modèle en îles
+    pub island_count: usize,
+    pub migration_interval: usize,
+
+    // Détection de stagnation (meilleur score quasi constant sur la fenêtre)
+    pub stagnation_window: usize,
+    pub stagnation_threshold: f32,
+
+    // Bonus de score pour le plus grand amas de particules
+    pub cluster_bonus_multiplier: f32,
+
+    // Paramètres de rendu
+    pub trail_length: usize,
+    pub speed_coloring: bool, // Émission des particules proportionnelle à leur vitesse
 }
 
 impl Default for SimulationParameters {
@@ -50,6 +282,7 @@ impl Default for SimulationParameters {
         Self {
             current_epoch: 0,
             max_epochs: 100,
+            on_max_epochs: OnMaxEpochs::default(),
             epoch_duration: DEFAULT_EPOCH_DURATION,
             epoch_timer: Timer::from_seconds(DEFAULT_EPOCH_DURATION, TimerMode::Once),
 
@@ -57,13 +290,58 @@ impl Default for SimulationParameters {
             particle_count: DEFAULT_PARTICLE_COUNT,
             particle_types: DEFAULT_PARTICLE_TYPES,
             simulation_speed: SimulationSpeed::default(),
+            auto_save_interval: Some(DEFAULT_AUTO_SAVE_INTERVAL),
+            simulation_mode: SimulationMode::default(),
+            rolling_selection_interval: DEFAULT_ROLLING_SELECTION_INTERVAL,
+            rng_seed: None,
+            dimension: SimulationDimension::default(),
 
             max_force_range: DEFAULT_MAX_FORCE_RANGE,
-            velocity_half_life: 0.043,
+            velocity_half_life: DEFAULT_VELOCITY_HALF_LIFE,
+            velocity_half_life_schedule: None,
+            decay_curve: HalfLifeDecay::default(),
+            spatial_grid_resolution: Self::default_spatial_grid_resolution(
+                DEFAULT_GRID_WIDTH,
+                DEFAULT_MAX_FORCE_RANGE,
+            ),
+            use_ghost_particles: false,
+            inter_simulation_interaction: false,
+            inter_sim_force_scale: DEFAULT_INTER_SIM_FORCE_SCALE,
+
+            type_migration_rate: 0.0,
+            particle_lifespan: None,
+            energy_budget: None,
+            gravity: None,
+            enable_hard_collision: false,
+            oscillating_forces: false,
+            force_oscillation_period: DEFAULT_FORCE_OSCILLATION_PERIOD,
 
             elite_ratio: DEFAULT_ELITE_RATIO,
+            tournament_size: DEFAULT_TOURNAMENT_SIZE,
             mutation_rate: DEFAULT_MUTATION_RATE,
             crossover_rate: DEFAULT_CROSSOVER_RATE,
+            crossover_operator: CrossoverOperator::default(),
+            genome_encoding: GenomeEncoding::default(),
+            gene_expression_threshold: 0.0,
+            symmetric_forces: false,
+
+            objectives: vec![ObjectiveFn::FoodEaten],
+
+            use_speciation: false,
+            niche_radius: DEFAULT_NICHE_RADIUS,
+            use_novelty_search: false,
+            novelty_weight: DEFAULT_NOVELTY_WEIGHT,
+
+            island_count: DEFAULT_ISLAND_COUNT,
+            migration_interval: DEFAULT_MIGRATION_INTERVAL,
+
+            stagnation_window: DEFAULT_STAGNATION_WINDOW,
+            stagnation_threshold: DEFAULT_STAGNATION_THRESHOLD,
+
+            cluster_bonus_multiplier: DEFAULT_CLUSTER_BONUS_MULTIPLIER,
+
+            trail_length: 0,
+            speed_coloring: false,
         }
     }
 }
@@ -82,6 +360,73 @@ impl SimulationParameters {
         self.epoch_timer.finished()
     }
 
+    /// Résolution proposée : une cellule par portée de force sur la largeur
+    pub fn default_spatial_grid_resolution(grid_width: f32, max_force_range: f32) -> usize {
+        ((grid_width / max_force_range.max(1.0)).ceil() as usize)
+            .clamp(MIN_SPATIAL_GRID_RESOLUTION, MAX_SPATIAL_GRID_RESOLUTION)
+    }
+
+    /// Borne la résolution de la grille spatiale, avertit au-delà d'un million de cellules
+    pub fn clamp_spatial_grid_resolution(resolution: usize) -> usize {
+        let clamped = resolution.clamp(MIN_SPATIAL_GRID_RESOLUTION, MAX_SPATIAL_GRID_RESOLUTION);
+        if clamped.pow(3) > SPATIAL_GRID_CELL_WARNING {
+            warn!(
+                "⚠️ Grille spatiale de {}³ cellules : plus de {} cellules, recherche des voisins coûteuse",
+                clamped, SPATIAL_GRID_CELL_WARNING
+            );
+        }
+        clamped
+    }
+
+    /// Sélection par rangs de Pareto dès que plusieurs objectifs sont choisis
+    pub fn is_multi_objective(&self) -> bool {
+        self.objectives.len() > 1
+    }
+
+    /// Change la durée d'époque en cours de route en conservant la progression relative
+    pub fn set_epoch_duration(&mut self, new_duration: f32) {
+        let new_duration = new_duration.max(MIN_EPOCH_DURATION);
+        let old_fraction = self.epoch_timer.fraction();
+
+        let mut new_timer = Timer::from_seconds(new_duration, TimerMode::Once);
+        new_timer.tick(std::time::Duration::from_secs_f32(new_duration * old_fraction));
+
+        self.epoch_duration = new_duration;
+        self.epoch_timer = new_timer;
+    }
+
+    /// Phase de l'oscillation des forces (radians), repart de zéro à chaque époque
+    pub fn force_oscillation_phase(&self) -> f32 {
+        let period = self.force_oscillation_period.max(f32::EPSILON);
+        (self.epoch_timer.elapsed_secs() * std::f32::consts::TAU / period) % std::f32::consts::TAU
+    }
+
+    /// Multiplicateur des forces particule-particule, 1 si l'oscillation est désactivée
+    pub fn force_oscillation_factor(&self) -> f32 {
+        if !self.oscillating_forces {
+            return 1.0;
+        }
+        1.0 + FORCE_OSCILLATION_AMPLITUDE * self.force_oscillation_phase().sin()
+    }
+
+    /// Demi-vie planifiée de l'époque courante : `start` à l'époque 0, `end` à `max_epochs`
+    pub fn update_velocity_half_life(&mut self) {
+        if let Some((start, end)) = self.velocity_half_life_schedule {
+            let progress = self.current_epoch as f32 / self.max_epochs.max(1) as f32;
+            self.velocity_half_life = self.decay_curve.interpolate(start, end, progress);
+        }
+    }
+
+    /// Facteur appliqué aux demi-vies par type, 1 tant que la demi-vie reste celle par défaut
+    pub fn velocity_half_life_scale(&self) -> f32 {
+        self.velocity_half_life / DEFAULT_VELOCITY_HALF_LIFE
+    }
+
+    /// Vrai une fois la dernière époque terminée (mode par époques uniquement)
+    pub fn reached_max_epochs(&self) -> bool {
+        self.simulation_mode == SimulationMode::EpochBased && self.current_epoch >= self.max_epochs
+    }
+
     /// Démarre une nouvelle époque
     pub fn start_new_epoch(&mut self) {
         self.current_epoch += 1;
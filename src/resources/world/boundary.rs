@@ -1,8 +1,11 @@
 use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
 
-#[derive(Resource, Default, Clone, Copy, PartialEq, Eq)]
+#[derive(Resource, Default, Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 pub enum BoundaryMode {
     #[default]
     Bounce,
     Teleport,
+    /// Champ de force répulsif qui décroît exponentiellement avec la distance au mur
+    SoftWall { stiffness: f32, decay: f32 },
 }
\ No newline at end of file
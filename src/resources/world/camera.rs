@@ -1,24 +1,83 @@
+use bevy::core_pipeline::bloom::{Bloom, BloomPrefilter};
 use bevy::prelude::*;
 use std::{f32::consts::FRAC_PI_2, ops::Range};
 
+/// Mode de contrôle de la caméra
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum CameraMode {
+    #[default]
+    Orbit,
+    FreeFly,
+}
+
+impl CameraMode {
+    pub fn label(&self) -> &'static str {
+        match self {
+            CameraMode::Orbit => "Orbite",
+            CameraMode::FreeFly => "Vol libre",
+        }
+    }
+}
+
 #[derive(Debug, Resource)]
 pub struct CameraSettings {
+    pub mode: CameraMode,
     pub orbit_distance: f32,
     pub pitch_speed: f32,
     pub pitch_range: Range<f32>,
     pub roll_speed: f32,
     pub yaw_speed: f32,
+    pub zoom_speed: f32,
+    pub fly_speed: f32,
+    pub transition_duration: f32, // Secondes de transition quand une vue change de cible
 }
 
 impl Default for CameraSettings {
     fn default() -> Self {
         let pitch_limit = FRAC_PI_2 - 0.01;
         Self {
+            mode: CameraMode::Orbit,
             orbit_distance: 800.0, 
             pitch_speed: 0.003,
             pitch_range: -pitch_limit..pitch_limit,
             roll_speed: 1.0,
             yaw_speed: 0.003,
+            zoom_speed: 10.0,
+            fly_speed: 300.0,
+            transition_duration: 0.3,
         }
     }
-}
\ No newline at end of file
+}
+/// Réglages du bloom des caméras 3D (intensité nulle = désactivé)
+#[derive(Debug, Resource, Clone, Copy)]
+pub struct BloomConfig {
+    pub intensity: f32,
+    pub threshold: f32,
+}
+
+impl Default for BloomConfig {
+    fn default() -> Self {
+        Self {
+            intensity: 0.15,
+            threshold: 0.8,
+        }
+    }
+}
+
+impl BloomConfig {
+    pub fn enabled(&self) -> bool {
+        self.intensity > 0.0
+    }
+
+    /// Composant à ajouter aux caméras, `None` si le bloom est désactivé
+    pub fn bloom(&self) -> Option<Bloom> {
+        self.enabled().then(|| Bloom {
+            intensity: self.intensity,
+            prefilter: BloomPrefilter {
+                threshold: self.threshold,
+                threshold_softness: 0.2,
+            },
+            ..Bloom::NATURAL
+        })
+    }
+}
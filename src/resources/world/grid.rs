@@ -2,7 +2,7 @@ use crate::globals::*;
 use crate::resources::world::boundary::BoundaryMode;
 use bevy::prelude::*;
 
-#[derive(Resource)]
+#[derive(Resource, Debug)]
 pub struct GridParameters {
     pub width: f32,
     pub height: f32,
@@ -36,9 +36,68 @@ impl GridParameters {
         match mode {
             BoundaryMode::Bounce => self.apply_bounce_bounds(position, velocity),
             BoundaryMode::Teleport => self.apply_teleport_bounds(position),
+            // Pas de contrainte de position : la force des murs est appliquée dans la physique
+            BoundaryMode::SoftWall { .. } => {}
         }
     }
 
+    /// Force de répulsion des murs mous, non nulle à moins de `decay` unités d'un mur
+    pub fn soft_wall_force(&self, position: Vec3, stiffness: f32, decay: f32) -> Vec3 {
+        if decay <= 0.0 {
+            return Vec3::ZERO;
+        }
+
+        let half_extents = Vec3::new(self.width, self.height, self.depth) / 2.0;
+        let mut force = Vec3::ZERO;
+
+        for axis in 0..3 {
+            // Distance au mur positif et au mur négatif (négative si la particule est sortie)
+            let to_positive = (half_extents[axis] - position[axis]).max(-decay);
+            let to_negative = (half_extents[axis] + position[axis]).max(-decay);
+
+            if to_positive < decay {
+                force[axis] -= stiffness * (-to_positive / decay).exp();
+            }
+            if to_negative < decay {
+                force[axis] += stiffness * (-to_negative / decay).exp();
+            }
+        }
+
+        force
+    }
+
+    /// Images d'une position à moins de `margin` d'un mur, décalées d'une période vers le mur
+    /// opposé : jusqu'à 7 images (faces, arêtes et coin) selon le nombre de murs proches
+    pub fn ghost_images(&self, position: Vec3, margin: f32) -> Vec<Vec3> {
+        let size = Vec3::new(self.width, self.height, self.depth);
+        let half_extents = size / 2.0;
+
+        let mut shifts = Vec3::ZERO;
+        for axis in 0..3 {
+            // Axe plus mince que deux marges (mode 2D) : aucune image
+            if size[axis] <= 2.0 * margin {
+                continue;
+            }
+            if position[axis] > half_extents[axis] - margin {
+                shifts[axis] = -size[axis];
+            } else if position[axis] < -half_extents[axis] + margin {
+                shifts[axis] = size[axis];
+            }
+        }
+
+        // Chaque sous-ensemble non vide des axes décalés donne une image
+        (1..8u8)
+            .filter(|mask| (0..3).all(|axis| mask & (1 << axis) == 0 || shifts[axis] != 0.0))
+            .map(|mask| {
+                let mut image = position;
+                for axis in (0..3).filter(|axis| mask & (1 << axis) != 0) {
+                    image[axis] += shifts[axis];
+                }
+                image
+            })
+            .collect()
+    }
+
     /// Applique les rebonds sur les murs
     fn apply_bounce_bounds(&self, position: &mut Vec3, velocity: &mut Vec3) {
         let half_width = self.width / 2.0;
@@ -64,6 +123,23 @@ impl GridParameters {
         }
     }
 
+    /// Rebond sur les seuls murs vers lesquels la gravité attire (le sol)
+    pub fn apply_floor_bounce(&self, position: &mut Vec3, velocity: &mut Vec3, gravity: Vec3) {
+        let half_extents = Vec3::new(self.width, self.height, self.depth) / 2.0;
+
+        for axis in 0..3 {
+            if gravity[axis] == 0.0 {
+                continue;
+            }
+
+            let floor = gravity[axis].signum() * (half_extents[axis] - PARTICLE_RADIUS);
+            if position[axis] * gravity[axis].signum() > floor.abs() {
+                position[axis] = floor;
+                velocity[axis] *= -COLLISION_DAMPING;
+            }
+        }
+    }
+
     /// Téléporte les particules de l'autre côté
     fn apply_teleport_bounds(&self, position: &mut Vec3) {
         let half_width = self.width / 2.0;
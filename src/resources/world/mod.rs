@@ -1,3 +1,4 @@
 pub mod boundary;
 pub mod camera;
-pub mod grid;
\ No newline at end of file
+pub mod grid;
+pub mod physics;
\ No newline at end of file
@@ -0,0 +1,44 @@
+use bevy::prelude::*;
+
+/// Nombre maximal de pas physiques par frame (évite la spirale de la mort)
+pub const DEFAULT_MAX_SUBSTEPS: usize = 8;
+
+/// Accumulateur de temps pour un pas physique fixe indépendant du framerate
+#[derive(Resource)]
+pub struct PhysicsState {
+    pub physics_accumulator: f32,
+    pub max_substeps: usize,
+    // Pas effectivement simulés à la dernière frame (énergie, plasticité…)
+    pub last_substeps: usize,
+}
+
+impl Default for PhysicsState {
+    fn default() -> Self {
+        Self {
+            physics_accumulator: 0.0,
+            max_substeps: DEFAULT_MAX_SUBSTEPS,
+            last_substeps: 0,
+        }
+    }
+}
+
+impl PhysicsState {
+    /// Ajoute le temps écoulé et renvoie le nombre de pas à simuler
+    pub fn accumulate(&mut self, elapsed: f32, timestep: f32) -> usize {
+        self.physics_accumulator += elapsed;
+
+        let mut steps = 0;
+        while self.physics_accumulator >= timestep && steps < self.max_substeps {
+            self.physics_accumulator -= timestep;
+            steps += 1;
+        }
+
+        // Retard trop important : on abandonne le temps restant plutôt que de rattraper
+        if steps == self.max_substeps {
+            self.physics_accumulator = self.physics_accumulator.min(timestep);
+        }
+
+        self.last_substeps = steps;
+        steps
+    }
+}
@@ -8,4 +8,5 @@ pub enum AppState {
     Simulation,
     Visualizer,
     Visualization,  
+    Sandbox,
 }
\ No newline at end of file
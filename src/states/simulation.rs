@@ -7,4 +7,6 @@ pub enum SimulationState {
     Running,
     Paused,
     GeneticSelection,
+    // Dernière époque terminée : fenêtre de résultats, physique arrêtée
+    Finished,
 }
\ No newline at end of file
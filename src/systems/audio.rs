@@ -0,0 +1,62 @@
+use crate::resources::config::audio::AudioSettings;
+use bevy::audio::Volume;
+use bevy::prelude::*;
+
+const FOOD_EAT_SOUND: &str = "sounds/food_eat.wav";
+const EPOCH_END_SOUND: &str = "sounds/epoch_end.wav";
+
+/// Le carillon de nourriture reste discret par rapport à la fin d'époque
+const FOOD_EAT_GAIN: f32 = 0.4;
+
+/// Effet sonore à jouer
+#[derive(Event, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SoundEffect {
+    FoodEaten,
+    EpochEnd,
+}
+
+/// Sons chargés au démarrage
+#[derive(Resource)]
+pub struct SoundAssets {
+    pub food_eat: Handle<AudioSource>,
+    pub epoch_end: Handle<AudioSource>,
+}
+
+pub fn load_sound_assets(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(SoundAssets {
+        food_eat: asset_server.load(FOOD_EAT_SOUND),
+        epoch_end: asset_server.load(EPOCH_END_SOUND),
+    });
+}
+
+pub fn play_sound_effects(
+    mut commands: Commands,
+    mut sound_events: EventReader<SoundEffect>,
+    settings: Res<AudioSettings>,
+    sounds: Res<SoundAssets>,
+) {
+    if !settings.audio_enabled || settings.sfx_volume <= 0.0 {
+        sound_events.clear();
+        return;
+    }
+
+    // Un même effet n'est joué qu'une fois par frame
+    let mut played: Vec<SoundEffect> = Vec::new();
+
+    for effect in sound_events.read() {
+        if played.contains(effect) {
+            continue;
+        }
+        played.push(*effect);
+
+        let (source, gain) = match effect {
+            SoundEffect::FoodEaten => (&sounds.food_eat, FOOD_EAT_GAIN),
+            SoundEffect::EpochEnd => (&sounds.epoch_end, 1.0),
+        };
+
+        commands.spawn((
+            AudioPlayer::new(source.clone()),
+            PlaybackSettings::DESPAWN.with_volume(Volume::Linear(settings.sfx_volume * gain)),
+        ));
+    }
+}
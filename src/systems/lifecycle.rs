@@ -1,18 +1,164 @@
 use bevy::prelude::*;
-use crate::resources::config::simulation::SimulationParameters;
+use bevy_egui::EguiContexts;
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::genetics::score::Score;
+use crate::components::genetics::genotype::Genotype;
+use crate::resources::config::random::{RngStream, RunSeed};
+use crate::resources::config::simulation::{
+    OnMaxEpochs, SimulationMode, SimulationParameters, SimulationSpeed,
+};
+use crate::states::app::AppState;
 use crate::states::simulation::SimulationState;
+use crate::systems::audio::SoundEffect;
+use crate::systems::persistence::population_save::{PopulationSaveEvents, PopulationSaveRequest};
 
 pub fn check_epoch_end(
     mut sim_params: ResMut<SimulationParameters>,
     mut next_state: ResMut<NextState<SimulationState>>,
+    mut save_events: ResMut<PopulationSaveEvents>,
+    simulations: Query<(&SimulationId, &Score), With<Simulation>>,
+    mut sound_events: EventWriter<SoundEffect>,
     time: Res<Time>,
 ) {
+    // En mode continu, la sélection glissante remplace les fins d'époque
+    if sim_params.simulation_mode == SimulationMode::Continuous {
+        return;
+    }
+
     sim_params.tick(time.delta());
 
     if sim_params.is_epoch_finished() {
         info!("Époque {} terminée!", sim_params.current_epoch);
         sim_params.start_new_epoch();
-        next_state.set(SimulationState::Starting);
+        queue_auto_save(&sim_params, &mut save_events, &simulations);
+        if sim_params.reached_max_epochs() && sim_params.on_max_epochs == OnMaxEpochs::Stop {
+            queue_final_save(&sim_params, &mut save_events, &simulations);
+        }
+        sound_events.write(SoundEffect::EpochEnd);
+
+        // Résultats : sélection différée, scores et génomes de la dernière époque conservés
+        if sim_params.reached_max_epochs()
+            && sim_params.on_max_epochs == OnMaxEpochs::ShowResults
+        {
+            info!("🏁 {} époques atteintes, simulation terminée", sim_params.max_epochs);
+            next_state.set(SimulationState::Finished);
+        } else {
+            next_state.set(SimulationState::Starting);
+        }
+    }
+}
+
+/// Demande la sauvegarde de la meilleure simulation toutes les N époques
+fn queue_auto_save(
+    sim_params: &SimulationParameters,
+    save_events: &mut PopulationSaveEvents,
+    simulations: &Query<(&SimulationId, &Score), With<Simulation>>,
+) {
+    let Some(interval) = sim_params.auto_save_interval else {
+        return;
+    };
+
+    if interval == 0 || sim_params.current_epoch % interval != 0 {
+        return;
+    }
+
+    let Some((best_id, best_score)) = simulations
+        .iter()
+        .max_by(|a, b| a.1.get().partial_cmp(&b.1.get()).unwrap())
+    else {
+        return;
+    };
+
+    info!(
+        "💾 Sauvegarde automatique de la simulation #{} (score {:.1})",
+        best_id.0 + 1,
+        best_score.get()
+    );
+
+    save_events.save_requests.push(PopulationSaveRequest {
+        simulation_id: best_id.0,
+        name: format!("auto_epoch_{}", sim_params.current_epoch),
+        description: Some(format!(
+            "Sauvegarde automatique - époque {} - score {:.1}",
+            sim_params.current_epoch,
+            best_score.get()
+        )),
+    });
+}
+
+/// Sauvegarde du meilleur génome avant l'arrêt sur `max_epochs`
+pub fn queue_final_save(
+    sim_params: &SimulationParameters,
+    save_events: &mut PopulationSaveEvents,
+    simulations: &Query<(&SimulationId, &Score), With<Simulation>>,
+) {
+    let Some((best_id, best_score)) = simulations
+        .iter()
+        .max_by(|a, b| a.1.get().partial_cmp(&b.1.get()).unwrap())
+    else {
+        return;
+    };
+
+    info!(
+        "💾 Sauvegarde finale de la simulation #{} (score {:.1})",
+        best_id.0 + 1,
+        best_score.get()
+    );
+
+    save_events.save_requests.push(PopulationSaveRequest {
+        simulation_id: best_id.0,
+        name: format!("final_epoch_{}", sim_params.current_epoch),
+        description: Some(format!(
+            "Meilleur génome après {} époques - score {:.1}",
+            sim_params.current_epoch,
+            best_score.get()
+        )),
+    });
+}
+
+/// Après la dernière époque : retour au menu ou reprise à l'époque 0.
+/// Tourne après `reset_for_new_epoch` pour que la dernière époque soit enregistrée.
+pub fn handle_max_epochs(
+    mut sim_params: ResMut<SimulationParameters>,
+    mut simulations: Query<&mut Genotype, With<Simulation>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    if !sim_params.reached_max_epochs() {
+        return;
+    }
+
+    match sim_params.on_max_epochs {
+        // Déjà traité par check_epoch_end, avant la sélection
+        OnMaxEpochs::ShowResults => {}
+        OnMaxEpochs::Stop => {
+            info!(
+                "🏁 {} époques atteintes, retour au menu principal",
+                sim_params.max_epochs
+            );
+            next_app_state.set(AppState::MainMenu);
+        }
+        OnMaxEpochs::Loop => {
+            info!("🔁 {} époques atteintes, reprise à l'époque 0", sim_params.max_epochs);
+            sim_params.current_epoch = 0;
+        }
+        OnMaxEpochs::LoopWithReset => {
+            info!(
+                "🔁 {} époques atteintes, reprise à l'époque 0 avec des génomes aléatoires",
+                sim_params.max_epochs
+            );
+            let rng = rng_stream.get(&run_seed, "loop_with_reset");
+            for mut genotype in simulations.iter_mut() {
+                *genotype = Genotype::random_encoded(
+                    genotype.type_count,
+                    genotype.food_type_count,
+                    sim_params.genome_encoding,
+                    rng,
+                );
+            }
+            sim_params.current_epoch = 0;
+        }
     }
 }
 
@@ -34,4 +180,34 @@ pub fn handle_pause_input(
             _ => {}
         }
     }
+}
+
+/// Vitesse au clavier : 0 pause, 1 normal, 2 rapide, 4 très rapide, 8 ultra
+pub fn keyboard_speed_control(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut sim_params: ResMut<SimulationParameters>,
+    mut contexts: EguiContexts,
+) {
+    // Ne pas intercepter la saisie dans un champ numérique
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+
+    let shortcuts = [
+        ([KeyCode::Digit0, KeyCode::Numpad0], SimulationSpeed::Paused),
+        ([KeyCode::Digit1, KeyCode::Numpad1], SimulationSpeed::Normal),
+        ([KeyCode::Digit2, KeyCode::Numpad2], SimulationSpeed::Fast),
+        ([KeyCode::Digit4, KeyCode::Numpad4], SimulationSpeed::VeryFast),
+        ([KeyCode::Digit8, KeyCode::Numpad8], SimulationSpeed::UltraFast),
+    ];
+
+    for (keys, speed) in shortcuts {
+        if keyboard.any_just_pressed(keys) && sim_params.simulation_speed != speed {
+            info!("Vitesse: x{}", speed.multiplier());
+            sim_params.simulation_speed = speed;
+        }
+    }
 }
\ No newline at end of file
@@ -1,4 +1,6 @@
+pub mod audio;
 pub mod lifecycle;
 pub mod persistence;
 pub mod rendering;
-pub mod simulation;
\ No newline at end of file
+pub mod simulation;
+pub mod sweep;
\ No newline at end of file
@@ -0,0 +1,119 @@
+use crate::systems::persistence::population_save::{SavedPopulation, population_file_path};
+use bevy::prelude::*;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Write;
+use std::path::PathBuf;
+use zip::ZipWriter;
+use zip::write::SimpleFileOptions;
+
+/// Archive ZIP en cours d'écriture : une population par frame pour animer la progression
+pub struct PopulationExport {
+    writer: ZipWriter<File>,
+    pending: VecDeque<SavedPopulation>,
+    readme: String,
+    total: usize,
+    path: PathBuf,
+}
+
+/// Export multiple du visualiseur : tâche en cours et résultat du dernier export
+#[derive(Resource, Default)]
+pub struct PopulationExportState {
+    pub job: Option<PopulationExport>,
+    pub result: Option<Result<PathBuf, String>>,
+}
+
+impl PopulationExport {
+    /// Crée `populations_export_<timestamp>.zip` dans le dossier courant
+    pub fn start(populations: Vec<SavedPopulation>) -> Result<Self, Box<dyn std::error::Error>> {
+        let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+        let path = PathBuf::from(format!("populations_export_{}.zip", timestamp));
+        let writer = ZipWriter::new(File::create(&path)?);
+
+        let readme = format!(
+            "Export de {} population(s) - {}\n\n",
+            populations.len(),
+            timestamp
+        );
+
+        Ok(Self {
+            writer,
+            total: populations.len(),
+            pending: populations.into(),
+            readme,
+            path,
+        })
+    }
+
+    /// Fraction des populations déjà écrites
+    pub fn progress(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.total - self.pending.len()) as f32 / self.total as f32
+    }
+
+    pub fn written(&self) -> usize {
+        self.total - self.pending.len()
+    }
+
+    pub fn total(&self) -> usize {
+        self.total
+    }
+
+    /// Écrit la population suivante ; `false` quand il n'en reste plus
+    pub fn write_next(&mut self) -> Result<bool, Box<dyn std::error::Error>> {
+        let Some(population) = self.pending.pop_front() else {
+            return Ok(false);
+        };
+
+        let file_name = population_file_path(&population)
+            .file_name()
+            .map(|name| name.to_string_lossy().into_owned())
+            .unwrap_or_else(|| format!("{}.json", population.timestamp));
+
+        self.writer.start_file(file_name.as_str(), SimpleFileOptions::default())?;
+        self.writer.write_all(serde_json::to_string_pretty(&population)?.as_bytes())?;
+
+        self.readme.push_str(&format!(
+            "- {} | score {:.1} | {} | {}\n",
+            population.name, population.score, population.timestamp, file_name
+        ));
+
+        Ok(true)
+    }
+
+    /// Ajoute le README et ferme l'archive
+    pub fn finish(mut self) -> Result<PathBuf, Box<dyn std::error::Error>> {
+        self.writer.start_file("README.txt", SimpleFileOptions::default())?;
+        self.writer.write_all(self.readme.as_bytes())?;
+        self.writer.finish()?;
+        Ok(self.path)
+    }
+}
+
+/// Fait avancer l'export en cours d'une population par frame
+pub fn advance_population_export(mut export_state: ResMut<PopulationExportState>) {
+    let Some(job) = export_state.job.as_mut() else {
+        return;
+    };
+
+    let outcome = match job.write_next() {
+        Ok(true) => return,
+        Ok(false) => export_state
+            .job
+            .take()
+            .map(|job| job.finish().map_err(|e| e.to_string())),
+        Err(e) => {
+            export_state.job = None;
+            Some(Err(e.to_string()))
+        }
+    };
+
+    match &outcome {
+        Some(Ok(path)) => info!("📦 Populations exportées dans {:?}", path),
+        Some(Err(e)) => error!("Erreur lors de l'export des populations: {}", e),
+        None => {}
+    }
+    export_state.result = outcome;
+}
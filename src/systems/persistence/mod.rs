@@ -1 +1,6 @@
-pub mod population_save;
\ No newline at end of file
+pub mod archive;
+pub mod population_save;
+pub mod position_export;
+pub mod session;
+pub mod stats_export;
+pub mod watcher;
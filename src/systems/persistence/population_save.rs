@@ -1,21 +1,39 @@
 use bevy::prelude::*;
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::hash::{DefaultHasher, Hasher};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
 
 use crate::components::{
     entities::simulation::*,
     entities::particle::*,
     entities::food::*,
+    entities::obstacle::*,
     genetics::genotype::*,
     genetics::score::*,
 };
 
+use crate::globals::{
+    DEFAULT_FORCE_OSCILLATION_PERIOD, DEFAULT_INTER_SIM_FORCE_SCALE, DEFAULT_MUTATION_RATE,
+    DEFAULT_NICHE_RADIUS, DEFAULT_ROLLING_SELECTION_INTERVAL, DEFAULT_CLUSTER_BONUS_MULTIPLIER,
+    DEFAULT_STAGNATION_THRESHOLD, DEFAULT_STAGNATION_WINDOW, DEFAULT_TOURNAMENT_SIZE,
+    PARTICLE_RADIUS,
+};
 use crate::resources::config::food::FoodParameters;
-use crate::resources::config::particle_types::ParticleTypesConfig;
-use crate::resources::config::simulation::{SimulationParameters, SimulationSpeed};
+use crate::resources::config::particle_types::{
+    ColorPalette, EMISSIVE_MULTIPLIER, ParticleTypesConfig,
+};
+use crate::resources::config::simulation::{
+    CrossoverOperator, GenomeEncoding, HalfLifeDecay, ObjectiveFn, OnMaxEpochs,
+    SimulationDimension, SimulationMode, SimulationParameters, SimulationSpeed,
+};
 use crate::resources::world::boundary::BoundaryMode;
 use crate::resources::world::grid::GridParameters;
+use crate::systems::simulation::lineage::LineageForest;
+use crate::systems::simulation::obstacles::{ObstacleLayout, collect_obstacles};
+use crate::systems::simulation::reset::{FitnessHistory, improved_crossover};
+use crate::systems::simulation::session_stats::SessionStats;
 
 /// Structure pour sauvegarder une population complète avec ses paramètres
 #[derive(Serialize, Deserialize, Clone)]
@@ -24,12 +42,29 @@ pub struct SavedPopulation {
     pub timestamp: String,
     pub genotype: SavedGenotype,
     pub score: f32,
+    #[serde(default)]
+    pub exploration_score: f32,
+    #[serde(default)]
+    pub epoch_scores: Vec<f32>, // Score de la simulation à chaque époque
     pub simulation_params: SavedSimulationParams,
     pub grid_params: SavedGridParams,
     pub food_params: SavedFoodParams,
     pub particle_types_config: SavedParticleTypesConfig,
     pub boundary_mode: SavedBoundaryMode,
+    // Omis si vide : le checksum des anciennes sauvegardes reste valide
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub obstacle_positions: Vec<(Vec3, ObstacleShape)>,
     pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub starred: bool, // Favori : toujours en tête de liste dans le visualiseur
+    #[serde(default)]
+    pub checksum: Option<u64>, // Empreinte des autres champs, absente des anciennes sauvegardes
+    #[serde(skip)]
+    pub is_corrupted: bool, // Checksum incorrect au chargement
+    #[serde(skip)]
+    pub source_path: Option<PathBuf>, // Fichier JSON lu au chargement, None si jamais écrit
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -37,6 +72,10 @@ pub struct SavedGenotype {
     pub force_matrix: Vec<f32>,
     pub food_forces: Vec<f32>,
     pub type_count: usize,
+    #[serde(default = "default_food_type_count")]
+    pub food_type_count: usize,
+    #[serde(default = "default_meta_mutation_rate")]
+    pub meta_mutation_rate: f32,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -46,6 +85,19 @@ pub struct SavedSimulationParams {
     pub max_force_range: f32,
     pub velocity_half_life: f32,
     pub epoch_duration: f32,
+    #[serde(default)]
+    pub particle_lifespan: Option<f32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gravity: Option<[f32; 3]>,
+    /// Période de l'oscillation des forces, None = forces constantes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub force_oscillation_period: Option<f32>,
+    /// Omise en 3D pour ne pas invalider les sommes de contrôle existantes
+    #[serde(default, skip_serializing_if = "SimulationDimension::is_three_d")]
+    pub dimension: SimulationDimension,
+    /// Graine de la session d'origine, absente des anciennes sauvegardes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rng_seed: Option<u64>,
 }
 
 #[derive(Serialize, Deserialize, Clone)]
@@ -61,18 +113,39 @@ pub struct SavedFoodParams {
     pub respawn_enabled: bool,
     pub respawn_cooldown: f32,
     pub food_value: f32,
+    #[serde(default = "default_food_type_count")]
+    pub food_type_count: usize,
+}
+
+/// Les anciennes sauvegardes n'ont qu'un seul type de nourriture
+fn default_food_type_count() -> usize {
+    1
+}
+
+fn default_meta_mutation_rate() -> f32 {
+    DEFAULT_MUTATION_RATE
 }
 
 #[derive(Serialize, Deserialize, Clone)]
 pub struct SavedParticleTypesConfig {
     pub type_count: usize,
     pub colors: Vec<(f32, f32, f32, f32)>, // RGBA values
+    #[serde(default)]
+    pub radii: Vec<f32>, // Absent des anciennes sauvegardes
+    #[serde(default)]
+    pub velocity_half_lives: Vec<f32>, // Absent des anciennes sauvegardes
+    // Omis si vide : le checksum des anciennes sauvegardes reste valide
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_names: Vec<String>,
+    #[serde(default, skip_serializing_if = "ColorPalette::is_auto")]
+    pub palette: ColorPalette,
 }
 
 #[derive(Serialize, Deserialize, Clone, Copy)]
 pub enum SavedBoundaryMode {
     Bounce,
     Teleport,
+    SoftWall { stiffness: f32, decay: f32 },
 }
 
 #[derive(Resource, Default)]
@@ -93,6 +166,29 @@ pub struct AvailablePopulations {
     pub loaded: bool,
 }
 
+impl SavedGenotype {
+    pub fn from_genotype(genotype: &Genotype) -> Self {
+        Self {
+            force_matrix: genotype.force_matrix.clone(),
+            food_forces: genotype.food_forces.clone(),
+            type_count: genotype.type_count,
+            food_type_count: genotype.food_type_count,
+            meta_mutation_rate: genotype.meta_mutation_rate,
+        }
+    }
+
+    pub fn to_genotype(&self) -> Genotype {
+        Genotype {
+            force_matrix: self.force_matrix.clone(),
+            food_forces: self.food_forces.clone(),
+            type_count: self.type_count,
+            food_type_count: self.food_type_count,
+            lineage: None,
+            meta_mutation_rate: self.meta_mutation_rate,
+        }
+    }
+}
+
 impl SavedPopulation {
     pub fn from_current_state(
         simulation_id: usize,
@@ -100,29 +196,37 @@ impl SavedPopulation {
         description: Option<String>,
         genotype: &Genotype,
         score: f32,
+        exploration_score: f32,
+        epoch_scores: Vec<f32>,
         sim_params: &SimulationParameters,
         grid_params: &GridParameters,
         food_params: &FoodParameters,
         particle_config: &ParticleTypesConfig,
         boundary_mode: &BoundaryMode,
+        obstacles: Vec<(Vec3, ObstacleShape)>,
     ) -> Self {
         let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string();
 
         Self {
             name,
             timestamp,
-            genotype: SavedGenotype {
-                force_matrix: genotype.force_matrix.clone(),
-                food_forces: genotype.food_forces.clone(),
-                type_count: genotype.type_count,
-            },
+            genotype: SavedGenotype::from_genotype(genotype),
             score,
+            exploration_score,
+            epoch_scores,
             simulation_params: SavedSimulationParams {
                 particle_count: sim_params.particle_count,
                 particle_types: sim_params.particle_types,
                 max_force_range: sim_params.max_force_range,
                 velocity_half_life: sim_params.velocity_half_life,
                 epoch_duration: sim_params.epoch_duration,
+                particle_lifespan: sim_params.particle_lifespan,
+                gravity: sim_params.gravity.map(|gravity| gravity.to_array()),
+                force_oscillation_period: sim_params
+                    .oscillating_forces
+                    .then_some(sim_params.force_oscillation_period),
+                dimension: sim_params.dimension,
+                rng_seed: sim_params.rng_seed,
             },
             grid_params: SavedGridParams {
                 width: grid_params.width,
@@ -134,6 +238,7 @@ impl SavedPopulation {
                 respawn_enabled: food_params.respawn_enabled,
                 respawn_cooldown: food_params.respawn_cooldown,
                 food_value: food_params.food_value,
+                food_type_count: food_params.food_type_count,
             },
             particle_types_config: SavedParticleTypesConfig {
                 type_count: particle_config.type_count,
@@ -145,12 +250,84 @@ impl SavedPopulation {
                         (srgba.red, srgba.green, srgba.blue, srgba.alpha)
                     })
                     .collect(),
+                radii: particle_config.radii.clone(),
+                velocity_half_lives: particle_config.velocity_half_lives.clone(),
+                type_names: particle_config.type_names.clone(),
+                palette: particle_config.palette,
             },
             boundary_mode: match boundary_mode {
                 BoundaryMode::Bounce => SavedBoundaryMode::Bounce,
                 BoundaryMode::Teleport => SavedBoundaryMode::Teleport,
+                BoundaryMode::SoftWall { stiffness, decay } => {
+                    SavedBoundaryMode::SoftWall { stiffness: *stiffness, decay: *decay }
+                }
             },
+            obstacle_positions: obstacles,
             description,
+            tags: Vec::new(),
+            starred: false,
+            checksum: None,
+            is_corrupted: false,
+            source_path: None,
+        }
+    }
+
+    /// Empreinte du JSON sérialisé de tous les champs sauf le checksum lui-même
+    pub fn compute_checksum(&self) -> Option<u64> {
+        let unsealed = SavedPopulation {
+            checksum: None,
+            ..self.clone()
+        };
+        let json = serde_json::to_vec(&unsealed).ok()?;
+
+        let mut hasher = DefaultHasher::new();
+        hasher.write(&json);
+        Some(hasher.finish())
+    }
+
+    /// Vrai si le checksum enregistré correspond au contenu (ou s'il est absent)
+    pub fn has_valid_checksum(&self) -> bool {
+        self.checksum.is_none() || self.checksum == self.compute_checksum()
+    }
+
+    /// Vrai si une étiquette contient `search` (insensible à la casse)
+    pub fn has_tag_matching(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+        self.tags.iter().any(|tag| tag.to_lowercase().contains(&search))
+    }
+
+    /// Deux populations ne peuvent être fusionnées que si leurs génomes ont la même forme
+    pub fn is_mergeable_with(&self, other: &SavedPopulation) -> bool {
+        self.genotype.type_count == other.genotype.type_count
+            && self.genotype.food_type_count == other.genotype.food_type_count
+            && self.genotype.force_matrix.len() == other.genotype.force_matrix.len()
+            && self.genotype.food_forces.len() == other.genotype.food_forces.len()
+    }
+
+    /// Population issue de `a` et `b` : paramètres repris de `a`, scores pondérés par `blend`
+    fn merged_with(
+        a: &SavedPopulation,
+        b: &SavedPopulation,
+        genotype: &Genotype,
+        blend: f32,
+        description: String,
+    ) -> SavedPopulation {
+        let weighted = |score_a: f32, score_b: f32| score_a * (1.0 - blend) + score_b * blend;
+
+        SavedPopulation {
+            name: format!("Merge_{}_{}", a.name, b.name),
+            timestamp: chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string(),
+            genotype: SavedGenotype::from_genotype(genotype),
+            score: weighted(a.score, b.score),
+            exploration_score: weighted(a.exploration_score, b.exploration_score),
+            epoch_scores: Vec::new(),
+            description: Some(description),
+            tags: Vec::new(),
+            starred: false,
+            checksum: None,
+            is_corrupted: false,
+            source_path: None,
+            ..a.clone()
         }
     }
 
@@ -163,16 +340,14 @@ impl SavedPopulation {
         FoodParameters,
         ParticleTypesConfig,
         BoundaryMode,
+        ObstacleLayout,
     ) {
-        let genotype = Genotype {
-            force_matrix: self.genotype.force_matrix.clone(),
-            food_forces: self.genotype.food_forces.clone(),
-            type_count: self.genotype.type_count,
-        };
+        let genotype = self.genotype.to_genotype();
 
         let sim_params = SimulationParameters {
             current_epoch: 0,
             max_epochs: 100,
+            on_max_epochs: OnMaxEpochs::default(),
             epoch_duration: self.simulation_params.epoch_duration,
             epoch_timer: Timer::from_seconds(
                 self.simulation_params.epoch_duration,
@@ -182,11 +357,52 @@ impl SavedPopulation {
             particle_count: self.simulation_params.particle_count,
             particle_types: self.simulation_params.particle_types,
             simulation_speed: SimulationSpeed::Normal,
+            auto_save_interval: None,
+            simulation_mode: SimulationMode::EpochBased,
+            dimension: self.simulation_params.dimension,
+            rolling_selection_interval: DEFAULT_ROLLING_SELECTION_INTERVAL,
+            rng_seed: self.simulation_params.rng_seed,
             max_force_range: self.simulation_params.max_force_range,
             velocity_half_life: self.simulation_params.velocity_half_life,
+            velocity_half_life_schedule: None,
+            decay_curve: HalfLifeDecay::default(),
+            spatial_grid_resolution: SimulationParameters::default_spatial_grid_resolution(
+                self.grid_params.width,
+                self.simulation_params.max_force_range,
+            ),
+            use_ghost_particles: false,
+            inter_simulation_interaction: false,
+            inter_sim_force_scale: DEFAULT_INTER_SIM_FORCE_SCALE,
+            type_migration_rate: 0.0,
+            particle_lifespan: self.simulation_params.particle_lifespan,
+            energy_budget: None,
+            gravity: self.simulation_params.gravity.map(Vec3::from_array),
+            enable_hard_collision: false,
+            oscillating_forces: self.simulation_params.force_oscillation_period.is_some(),
+            force_oscillation_period: self
+                .simulation_params
+                .force_oscillation_period
+                .unwrap_or(DEFAULT_FORCE_OSCILLATION_PERIOD),
             elite_ratio: 0.1,
+            tournament_size: DEFAULT_TOURNAMENT_SIZE,
             mutation_rate: 0.1,
             crossover_rate: 0.7,
+            crossover_operator: CrossoverOperator::default(),
+            genome_encoding: GenomeEncoding::default(),
+            gene_expression_threshold: 0.0,
+            symmetric_forces: false,
+            objectives: vec![ObjectiveFn::FoodEaten],
+            use_speciation: false,
+            niche_radius: DEFAULT_NICHE_RADIUS,
+            use_novelty_search: false,
+            novelty_weight: 0.0,
+            island_count: 1,
+            migration_interval: 0,
+            stagnation_window: DEFAULT_STAGNATION_WINDOW,
+            stagnation_threshold: DEFAULT_STAGNATION_THRESHOLD,
+            cluster_bonus_multiplier: DEFAULT_CLUSTER_BONUS_MULTIPLIER,
+            trail_length: 0,
+            speed_coloring: false,
         };
 
         let grid_params = GridParameters {
@@ -200,6 +416,8 @@ impl SavedPopulation {
             respawn_enabled: self.food_params.respawn_enabled,
             respawn_cooldown: self.food_params.respawn_cooldown,
             food_value: self.food_params.food_value,
+            food_type_count: self.food_params.food_type_count,
+            ..default()
         };
 
         let colors = self
@@ -208,19 +426,42 @@ impl SavedPopulation {
             .iter()
             .map(|(r, g, b, a)| {
                 let base_color = Color::srgba(*r, *g, *b, *a);
-                let emissive = base_color.to_linear() * 0.5;
+                let emissive = base_color.to_linear() * EMISSIVE_MULTIPLIER;
                 (base_color, emissive)
             })
             .collect();
 
+        // Migration des anciennes sauvegardes sans rayons
+        let mut radii = self.particle_types_config.radii.clone();
+        radii.resize(self.particle_types_config.type_count, PARTICLE_RADIUS);
+        // Les anciennes sauvegardes n'ont qu'une demi-vie globale
+        let mut velocity_half_lives = self.particle_types_config.velocity_half_lives.clone();
+        velocity_half_lives.resize(
+            self.particle_types_config.type_count,
+            self.simulation_params.velocity_half_life,
+        );
+
+        // Les anciennes sauvegardes n'ont pas de noms de types
+        let type_names = ParticleTypesConfig::sanitize_type_names(
+            &self.particle_types_config.type_names,
+            self.particle_types_config.type_count,
+        );
+
         let particle_config = ParticleTypesConfig {
             type_count: self.particle_types_config.type_count,
             colors,
+            radii,
+            velocity_half_lives,
+            type_names,
+            palette: self.particle_types_config.palette,
         };
 
         let boundary_mode = match self.boundary_mode {
             SavedBoundaryMode::Bounce => BoundaryMode::Bounce,
             SavedBoundaryMode::Teleport => BoundaryMode::Teleport,
+            SavedBoundaryMode::SoftWall { stiffness, decay } => {
+                BoundaryMode::SoftWall { stiffness, decay }
+            }
         };
 
         (
@@ -230,23 +471,54 @@ impl SavedPopulation {
             food_params,
             particle_config,
             boundary_mode,
+            ObstacleLayout(self.obstacle_positions.clone()),
         )
     }
 }
 
+/// Fusion par interpolation : chaque gène vaut `a·(1 − blend) + b·blend`
+pub fn merge_populations(a: &SavedPopulation, b: &SavedPopulation, blend: f32) -> SavedPopulation {
+    let blend = blend.clamp(0.0, 1.0);
+    let genotype =
+        Genotype::interpolate(&a.genotype.to_genotype(), &b.genotype.to_genotype(), blend);
+    let description = format!(
+        "Fusion de « {} » ({:.0} %) et « {} » ({:.0} %)",
+        a.name,
+        (1.0 - blend) * 100.0,
+        b.name,
+        blend * 100.0
+    );
+    SavedPopulation::merged_with(a, b, &genotype, blend, description)
+}
+
+/// Fusion par crossover uniforme : chaque gène vient de l'un ou l'autre génome
+pub fn crossover_populations(a: &SavedPopulation, b: &SavedPopulation) -> SavedPopulation {
+    let genotype = improved_crossover(
+        &a.genotype.to_genotype(),
+        &b.genotype.to_genotype(),
+        &mut rand::rng(),
+    );
+    let description = format!("Crossover de « {} » et « {} »", a.name, b.name);
+    SavedPopulation::merged_with(a, b, &genotype, 0.5, description)
+}
+
 pub fn process_save_requests(
     mut save_events: ResMut<PopulationSaveEvents>,
-    simulations: Query<(&SimulationId, &Genotype, &Score), With<Simulation>>,
+    simulations: Query<(&SimulationId, &Genotype, &Score, &ExplorationScore), With<Simulation>>,
     sim_params: Res<SimulationParameters>,
     grid_params: Res<GridParameters>,
     food_params: Res<FoodParameters>,
     particle_config: Res<ParticleTypesConfig>,
     boundary_mode: Res<BoundaryMode>,
+    lineage_forest: Res<LineageForest>,
+    fitness_history: Res<FitnessHistory>,
+    obstacles: Query<(&Transform, &Obstacle), Without<Particle>>,
+    mut session_stats: ResMut<SessionStats>,
 ) {
     for request in save_events.save_requests.drain(..) {
-        if let Some((_, genotype, score)) = simulations
+        if let Some((_, genotype, score, exploration)) = simulations
             .iter()
-            .find(|(sim_id, _, _)| sim_id.0 == request.simulation_id)
+            .find(|(sim_id, _, _, _)| sim_id.0 == request.simulation_id)
         {
             let saved_population = SavedPopulation::from_current_state(
                 request.simulation_id,
@@ -254,17 +526,27 @@ pub fn process_save_requests(
                 request.description.clone(),
                 genotype,
                 score.get(),
+                exploration.get(),
+                fitness_history.scores(request.simulation_id).to_vec(),
                 &sim_params,
                 &grid_params,
                 &food_params,
                 &particle_config,
                 &boundary_mode,
+                collect_obstacles(&obstacles),
             );
 
             if let Err(e) = save_population_to_file(&saved_population) {
                 error!("Erreur lors de la sauvegarde: {}", e);
             } else {
                 info!("Population '{}' sauvegardée avec succès", request.name);
+                session_stats.record_save();
+
+                if !lineage_forest.is_empty() {
+                    if let Err(e) = save_lineage_to_file(&lineage_forest, &saved_population) {
+                        warn!("Impossible d'exporter la lignée: {}", e);
+                    }
+                }
             }
         }
     }
@@ -278,9 +560,204 @@ pub fn save_population_to_file(
         fs::create_dir_all(populations_dir)?;
     }
 
-    let safe_name = population
-        .name
-        .chars()
+    let file_path = population_file_path(population);
+
+    let sealed = SavedPopulation {
+        checksum: population.compute_checksum(),
+        ..population.clone()
+    };
+
+    // Écriture atomique : fichier temporaire vérifié puis renommé
+    let json: Vec<u8> = serde_json::to_vec_pretty(&sealed)?;
+    let tmp_path = file_path.with_extension("json.tmp");
+    fs::write(&tmp_path, &json)?;
+
+    if !verify_population_file(&tmp_path) {
+        let _ = fs::remove_file(&tmp_path);
+        error!("Fichier de sauvegarde invalide, écriture annulée: {:?}", file_path);
+        return Err(format!("vérification échouée pour {:?}", file_path).into());
+    }
+
+    fs::rename(&tmp_path, &file_path)?;
+
+    // Copie binaire pour un chargement rapide ; le JSON reste la référence lisible
+    if let Err(e) = save_population_to_msgpack(&sealed) {
+        warn!("Impossible d'écrire la copie binaire de '{}': {}", population.name, e);
+    }
+
+    Ok(())
+}
+
+/// Écrit la population au format MessagePack (`.bin`, checksum compris) à côté du JSON
+pub fn save_population_to_msgpack(
+    population: &SavedPopulation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let file_path = population_file_path(population).with_extension("bin");
+
+    let bytes = rmp_serde::to_vec_named(population)?;
+    let tmp_path = file_path.with_extension("bin.tmp");
+    fs::write(&tmp_path, &bytes)?;
+    fs::rename(&tmp_path, &file_path)?;
+
+    Ok(())
+}
+
+pub fn load_from_msgpack(path: &Path) -> Result<SavedPopulation, Box<dyn std::error::Error>> {
+    let bytes = fs::read(path)?;
+    Ok(rmp_serde::from_slice(&bytes)?)
+}
+
+fn load_from_json(path: &Path) -> Result<SavedPopulation, Box<dyn std::error::Error>> {
+    let content = fs::read_to_string(path)?;
+    Ok(serde_json::from_str(&content)?)
+}
+
+/// Charge un seul fichier JSON de population, checksum vérifié
+pub fn load_population_file(path: &Path) -> Result<SavedPopulation, Box<dyn std::error::Error>> {
+    let mut population = load_from_json(path)?;
+    population.source_path = Some(path.to_path_buf());
+    flag_if_corrupted(&mut population);
+    Ok(population)
+}
+
+fn flag_if_corrupted(population: &mut SavedPopulation) {
+    if !population.has_valid_checksum() {
+        warn!(
+            "Population '{}' peut-être corrompue (checksum incorrect)",
+            population.name
+        );
+        population.is_corrupted = true;
+    }
+}
+
+/// Étiquettes saisies séparées par des virgules, sans doublons ni entrées vides
+pub fn parse_tags(input: &str) -> Vec<String> {
+    let mut tags: Vec<String> = Vec::new();
+    for tag in input.split(',').map(str::trim).filter(|tag| !tag.is_empty()) {
+        if !tags.iter().any(|existing| existing == tag) {
+            tags.push(tag.to_string());
+        }
+    }
+    tags
+}
+
+/// Réécrit le fichier après modification des métadonnées (étiquettes, favori)
+pub fn update_population_metadata(
+    population: &mut SavedPopulation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    save_population_to_file(population)?;
+    population.checksum = population.compute_checksum();
+    Ok(())
+}
+
+/// Réécrit le fichier d'une population avec un checksum recalculé
+pub fn repair_population_checksum(
+    population: &mut SavedPopulation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    save_population_to_file(population)?;
+    population.checksum = population.compute_checksum();
+    population.is_corrupted = false;
+    info!("Checksum de la population '{}' réparé", population.name);
+    Ok(())
+}
+
+/// Distance euclidienne entre deux matrices de forces. Si les nombres de types diffèrent,
+/// les interactions absentes d'un des génomes comptent pour 0.
+pub fn genotype_distance(a: &SavedGenotype, b: &SavedGenotype) -> f32 {
+    let force = |genotype: &SavedGenotype, i: usize, j: usize| {
+        if i < genotype.type_count && j < genotype.type_count {
+            genotype
+                .force_matrix
+                .get(i * genotype.type_count + j)
+                .copied()
+                .unwrap_or(0.0)
+        } else {
+            0.0
+        }
+    };
+
+    let type_count = a.type_count.max(b.type_count);
+    let mut sum = 0.0;
+    for i in 0..type_count {
+        for j in 0..type_count {
+            sum += (force(a, i, j) - force(b, i, j)).powi(2);
+        }
+    }
+    sum.sqrt()
+}
+
+/// Chemin du fichier d'une population : celui d'où elle a été chargée,
+/// sinon dérivé du nom et de l'horodatage
+pub fn population_file_path(population: &SavedPopulation) -> PathBuf {
+    if let Some(path) = &population.source_path {
+        return path.clone();
+    }
+
+    let filename = format!(
+        "{}_{}.json",
+        sanitize_file_name(&population.name),
+        population.timestamp
+    );
+    Path::new("populations").join(filename)
+}
+
+/// Relit un fichier de population et vérifie qu'il se désérialise correctement
+pub fn verify_population_file(path: &Path) -> bool {
+    fs::read_to_string(path)
+        .ok()
+        .is_some_and(|content| serde_json::from_str::<SavedPopulation>(&content).is_ok())
+}
+
+/// Binaire présent et au moins aussi récent que son JSON : un JSON modifié à la main
+/// (ou rechargé à chaud) prime sur une copie binaire périmée
+fn is_binary_up_to_date(json_path: &Path, binary_path: &Path) -> bool {
+    let modified = |path: &Path| fs::metadata(path).and_then(|metadata| metadata.modified());
+    match (modified(json_path), modified(binary_path)) {
+        (Ok(json_time), Ok(binary_time)) => binary_time >= json_time,
+        _ => binary_path.exists(),
+    }
+}
+
+/// Supprime les fichiers `.tmp` laissés par une sauvegarde interrompue
+fn remove_orphaned_temp_files(populations_dir: &Path) {
+    let Ok(entries) = fs::read_dir(populations_dir) else {
+        return;
+    };
+
+    for path in entries.flatten().map(|entry| entry.path()) {
+        if path.extension().and_then(|s| s.to_str()) == Some("tmp") {
+            match fs::remove_file(&path) {
+                Ok(()) => warn!("Fichier temporaire orphelin supprimé: {:?}", path),
+                Err(e) => warn!("Impossible de supprimer {:?}: {}", path, e),
+            }
+        }
+    }
+}
+
+/// Exporte la lignée dans `populations/lineage/` (hors du dossier scanné au chargement)
+pub fn save_lineage_to_file(
+    lineage: &LineageForest,
+    population: &SavedPopulation,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let lineage_dir = Path::new("populations").join("lineage");
+    if !lineage_dir.exists() {
+        fs::create_dir_all(&lineage_dir)?;
+    }
+
+    let filename = format!(
+        "{}_{}.json",
+        sanitize_file_name(&population.name),
+        population.timestamp
+    );
+
+    let json = serde_json::to_string_pretty(lineage)?;
+    fs::write(lineage_dir.join(filename), json)?;
+
+    Ok(())
+}
+
+fn sanitize_file_name(name: &str) -> String {
+    name.chars()
         .map(|c| {
             if c.is_alphanumeric() || c == '_' || c == '-' {
                 c
@@ -288,15 +765,7 @@ pub fn save_population_to_file(
                 '_'
             }
         })
-        .collect::<String>();
-
-    let filename = format!("{}_{}.json", safe_name, population.timestamp);
-    let file_path = populations_dir.join(filename);
-
-    let json = serde_json::to_string_pretty(population)?;
-    fs::write(file_path, json)?;
-
-    Ok(())
+        .collect()
 }
 
 pub fn load_all_populations() -> Result<Vec<SavedPopulation>, Box<dyn std::error::Error>> {
@@ -305,25 +774,72 @@ pub fn load_all_populations() -> Result<Vec<SavedPopulation>, Box<dyn std::error
         return Ok(Vec::new());
     }
 
+    remove_orphaned_temp_files(populations_dir);
+
+    let started_at = Instant::now();
     let mut populations = Vec::new();
+    let mut binary_count = 0;
+    let mut migrated_count = 0;
 
     for entry in fs::read_dir(populations_dir)? {
         let entry = entry?;
         let path = entry.path();
 
-        if path.extension().and_then(|s| s.to_str()) == Some("json") {
-            match fs::read_to_string(&path) {
-                Ok(content) => match serde_json::from_str::<SavedPopulation>(&content) {
-                    Ok(population) => populations.push(population),
-                    Err(e) => warn!("Erreur lors du chargement de {:?}: {}", path, e),
-                },
-                Err(e) => warn!("Impossible de lire {:?}: {}", path, e),
-            }
+        if path.extension().and_then(|s| s.to_str()) != Some("json") {
+            continue;
         }
+
+        // Le binaire est préféré s'il est à jour, le JSON sert de repli
+        let binary_path = path.with_extension("bin");
+        let from_binary = if is_binary_up_to_date(&path, &binary_path) {
+            match load_from_msgpack(&binary_path) {
+                Ok(population) => Some(population),
+                Err(e) => {
+                    warn!("Binaire illisible {:?}, repli sur le JSON: {}", binary_path, e);
+                    None
+                }
+            }
+        } else {
+            None
+        };
+
+        let mut population = match from_binary {
+            Some(population) => {
+                binary_count += 1;
+                population
+            }
+            None => match load_from_json(&path) {
+                Ok(mut population) => {
+                    // Migration : le binaire prend le nom du JSON lu, pas celui dérivé du contenu
+                    population.source_path = Some(path.clone());
+                    match save_population_to_msgpack(&population) {
+                        Ok(()) => migrated_count += 1,
+                        Err(e) => warn!("Impossible de convertir {:?} en binaire: {}", path, e),
+                    }
+                    population
+                }
+                Err(e) => {
+                    warn!("Erreur lors du chargement de {:?}: {}", path, e);
+                    continue;
+                }
+            },
+        };
+
+        population.source_path = Some(path);
+        flag_if_corrupted(&mut population);
+        populations.push(population);
     }
 
     populations.sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
 
+    info!(
+        "⏱ {} population(s) chargée(s) en {:.1} ms ({} binaire(s), {} JSON converti(s))",
+        populations.len(),
+        started_at.elapsed().as_secs_f64() * 1000.0,
+        binary_count,
+        migrated_count
+    );
+
     Ok(populations)
 }
 
@@ -0,0 +1,127 @@
+use bevy::diagnostic::FrameCount;
+use bevy::prelude::*;
+use std::fs::{self, File};
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use crate::components::entities::particle::{Particle, ParticleType, Velocity};
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::resources::config::simulation::SimulationParameters;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+
+/// Ligne exportée : (id, type, position, vitesse)
+type PositionRow = (u32, usize, Vec3, Vec3);
+
+type ParticleRowQuery<'w, 's> = Query<
+    'w,
+    's,
+    (Entity, &'static ParticleType, &'static Transform, &'static Velocity, &'static ChildOf),
+    With<Particle>,
+>;
+
+/// Positions et vitesses des particules d'une simulation, triées par identifiant
+fn collect_rows(
+    simulation_id: usize,
+    particles: &ParticleRowQuery,
+    simulations: &Query<&SimulationId, With<Simulation>>,
+) -> Vec<PositionRow> {
+    let mut rows: Vec<PositionRow> = particles
+        .iter()
+        .filter(|(_, _, _, _, parent)| {
+            simulations
+                .get(parent.parent())
+                .is_ok_and(|sim_id| sim_id.0 == simulation_id)
+        })
+        .map(|(entity, particle_type, transform, velocity, _)| {
+            (entity.index(), particle_type.0, transform.translation, velocity.0)
+        })
+        .collect();
+    rows.sort_by_key(|(id, ..)| *id);
+    rows
+}
+
+fn write_positions_csv(path: &Path, rows: &[PositionRow]) -> std::io::Result<()> {
+    let mut writer = BufWriter::new(File::create(path)?);
+    writeln!(writer, "particle_id,type,x,y,z,vx,vy,vz")?;
+    for (id, particle_type, position, velocity) in rows {
+        writeln!(
+            writer,
+            "{},{},{:.4},{:.4},{:.4},{:.4},{:.4},{:.4}",
+            id,
+            particle_type,
+            position.x,
+            position.y,
+            position.z,
+            velocity.x,
+            velocity.y,
+            velocity.z
+        )?;
+    }
+    writer.flush()
+}
+
+/// Écrit le CSV dans `positions/` sur un thread séparé pour ne pas bloquer la frame
+fn spawn_positions_export(simulation_id: usize, epoch: usize, frame: u32, rows: Vec<PositionRow>) {
+    let file_path = PathBuf::from("positions").join(format!(
+        "{}_epoch_{}_frame_{}.csv",
+        simulation_id + 1,
+        epoch,
+        frame
+    ));
+
+    std::thread::spawn(move || {
+        let result = fs::create_dir_all("positions")
+            .and_then(|_| write_positions_csv(&file_path, &rows));
+        match result {
+            Ok(()) => info!("📍 {} particules exportées: {}", rows.len(), file_path.display()),
+            Err(e) => error!("Erreur d'export des positions: {}", e),
+        }
+    });
+}
+
+/// Bouton « Export Positions » de la matrice de forces
+pub fn export_requested_positions(
+    mut ui_state: ResMut<ForceMatrixUI>,
+    sim_params: Res<SimulationParameters>,
+    frames: Res<FrameCount>,
+    particles: ParticleRowQuery,
+    simulations: Query<&SimulationId, With<Simulation>>,
+) {
+    if !ui_state.export_positions_requested {
+        return;
+    }
+    ui_state.export_positions_requested = false;
+
+    let Some(simulation_id) = ui_state.selected_simulation else {
+        return;
+    };
+
+    let rows = collect_rows(simulation_id, &particles, &simulations);
+    spawn_positions_export(simulation_id, sim_params.current_epoch, frames.0, rows);
+}
+
+/// Export automatique en fin d'époque, avant le repositionnement des particules
+pub fn export_positions_at_epoch_end(
+    ui_state: Option<Res<ForceMatrixUI>>,
+    sim_params: Res<SimulationParameters>,
+    frames: Res<FrameCount>,
+    particles: ParticleRowQuery,
+    simulations: Query<&SimulationId, With<Simulation>>,
+) {
+    let Some(ui_state) = ui_state else {
+        return;
+    };
+    if !ui_state.export_positions_every_epoch {
+        return;
+    }
+    let Some(simulation_id) = ui_state.selected_simulation else {
+        return;
+    };
+
+    // Premier passage : aucune particule n'existe encore
+    let rows = collect_rows(simulation_id, &particles, &simulations);
+    if rows.is_empty() {
+        return;
+    }
+    spawn_positions_export(simulation_id, sim_params.current_epoch, frames.0, rows);
+}
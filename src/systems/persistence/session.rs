@@ -0,0 +1,181 @@
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::Score;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::systems::persistence::population_save::SavedGenotype;
+use crate::systems::simulation::hall_of_fame::HallOfFame;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Fichier de reprise écrit pendant la simulation
+pub const SESSION_PATH: &str = "session.json";
+
+/// Intervalle entre deux sauvegardes de session (secondes)
+const SESSION_AUTOSAVE_INTERVAL: f32 = 60.0;
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SessionGenome {
+    pub simulation_id: usize,
+    pub genotype: SavedGenotype,
+    pub score: f32,
+}
+
+/// Entrée du panthéon sauvegardée avec la session
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SessionHallOfFameEntry {
+    pub epoch: usize,
+    pub genotype: SavedGenotype,
+    pub score: f32,
+}
+
+/// État minimal pour reprendre une simulation interrompue
+#[derive(Serialize, Deserialize, Clone)]
+pub struct SavedSession {
+    pub epoch: usize,
+    pub genomes: Vec<SessionGenome>,
+    #[serde(default)]
+    pub hall_of_fame: Vec<SessionHallOfFameEntry>,
+}
+
+impl SavedSession {
+    pub fn load() -> Option<Self> {
+        let path = Path::new(SESSION_PATH);
+        if !path.exists() {
+            return None;
+        }
+
+        match fs::read_to_string(path).map(|content| serde_json::from_str::<SavedSession>(&content)) {
+            Ok(Ok(session)) => Some(session),
+            Ok(Err(e)) => {
+                warn!("Fichier de session invalide {:?}: {}", path, e);
+                None
+            }
+            Err(e) => {
+                warn!("Impossible de lire {:?}: {}", path, e);
+                None
+            }
+        }
+    }
+
+    /// Génome sauvegardé d'une simulation, s'il a la forme attendue
+    pub fn genotype_for(
+        &self,
+        simulation_id: usize,
+        type_count: usize,
+        food_type_count: usize,
+    ) -> Option<Genotype> {
+        self.genomes
+            .iter()
+            .find(|genome| genome.simulation_id == simulation_id)
+            .map(|genome| genome.genotype.to_genotype())
+            .filter(|genotype| {
+                genotype.type_count == type_count && genotype.food_type_count == food_type_count
+            })
+    }
+}
+
+/// Session trouvée au démarrage, proposée à la reprise dans le menu principal
+#[derive(Resource, Default)]
+pub struct PreviousSession(pub Option<SavedSession>);
+
+/// Session acceptée : ses génomes remplacent la première génération aléatoire
+#[derive(Resource)]
+pub struct ResumeSession(pub SavedSession);
+
+pub fn detect_previous_session(mut previous_session: ResMut<PreviousSession>) {
+    if let Some(session) = SavedSession::load().filter(|session| session.epoch > 0) {
+        info!(
+            "Session précédente trouvée (époque {}, {} simulations)",
+            session.epoch,
+            session.genomes.len()
+        );
+        previous_session.0 = Some(session);
+    }
+}
+
+/// Écrit `session.json` toutes les 60 secondes
+pub fn autosave_session(
+    time: Res<Time>,
+    sim_params: Res<SimulationParameters>,
+    simulations: Query<(&SimulationId, &Genotype, &Score), With<Simulation>>,
+    hall_of_fame: Res<HallOfFame>,
+    mut timer: Local<Option<Timer>>,
+) {
+    let timer = timer.get_or_insert_with(|| {
+        Timer::from_seconds(SESSION_AUTOSAVE_INTERVAL, TimerMode::Repeating)
+    });
+
+    if !timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    let mut genomes: Vec<SessionGenome> = simulations
+        .iter()
+        .map(|(sim_id, genotype, score)| SessionGenome {
+            simulation_id: sim_id.0,
+            genotype: SavedGenotype::from_genotype(genotype),
+            score: score.get(),
+        })
+        .collect();
+    genomes.sort_by_key(|genome| genome.simulation_id);
+
+    let session = SavedSession {
+        epoch: sim_params.current_epoch,
+        genomes,
+        hall_of_fame: hall_of_fame
+            .entries
+            .iter()
+            .map(|(epoch, (genotype, score))| SessionHallOfFameEntry {
+                epoch: *epoch,
+                genotype: SavedGenotype::from_genotype(genotype),
+                score: *score,
+            })
+            .collect(),
+    };
+
+    // Écriture atomique : un crash pendant l'écriture ne corrompt pas la session précédente
+    let tmp_path = format!("{}.tmp", SESSION_PATH);
+    let result = serde_json::to_vec(&session)
+        .map_err(|e| e.to_string())
+        .and_then(|json| fs::write(&tmp_path, json).map_err(|e| e.to_string()))
+        .and_then(|()| fs::rename(&tmp_path, SESSION_PATH).map_err(|e| e.to_string()));
+
+    match result {
+        Ok(()) => info!("💾 Session sauvegardée (époque {})", session.epoch),
+        Err(e) => error!("Erreur lors de la sauvegarde de session: {}", e),
+    }
+}
+
+/// Restaure l'époque de la session reprise, une fois les génomes en place
+pub fn finish_session_resume(
+    mut commands: Commands,
+    resume: Option<Res<ResumeSession>>,
+    mut sim_params: ResMut<SimulationParameters>,
+    mut hall_of_fame: ResMut<HallOfFame>,
+) {
+    let Some(resume) = resume else {
+        return;
+    };
+
+    sim_params.current_epoch = resume.0.epoch;
+    hall_of_fame.restore(
+        resume
+            .0
+            .hall_of_fame
+            .iter()
+            .map(|entry| (entry.epoch, entry.genotype.to_genotype(), entry.score)),
+    );
+    commands.remove_resource::<ResumeSession>();
+    info!("Session reprise à l'époque {}", resume.0.epoch);
+}
+
+/// Sortie propre : la session n'a plus besoin d'être reprise
+pub fn delete_session_file() {
+    match fs::remove_file(SESSION_PATH) {
+        Ok(()) => info!("Fichier de session supprimé"),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) => warn!("Impossible de supprimer {}: {}", SESSION_PATH, e),
+    }
+}
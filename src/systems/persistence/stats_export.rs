@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::systems::simulation::reset::EpochHistoryResource;
+
+/// Exporte l'historique des époques au format CSV dans `populations/`
+pub fn export_epoch_history_csv(
+    history: &EpochHistoryResource,
+) -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let populations_dir = Path::new("populations");
+    if !populations_dir.exists() {
+        fs::create_dir_all(populations_dir)?;
+    }
+
+    let mut csv = String::from("epoch,best,average,worst,std_dev,entropy\n");
+    for stats in &history.stats {
+        csv.push_str(&format!(
+            "{},{:.4},{:.4},{:.4},{:.4},{:.4}\n",
+            stats.epoch,
+            stats.best_score,
+            stats.average_score,
+            stats.worst_score,
+            stats.std_deviation,
+            stats.average_entropy
+        ));
+    }
+
+    let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    let file_path = populations_dir.join(format!("stats_{}.csv", timestamp));
+    fs::write(&file_path, csv)?;
+
+    Ok(file_path)
+}
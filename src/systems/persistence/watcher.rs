@@ -0,0 +1,123 @@
+use crate::systems::persistence::population_save::{
+    AvailablePopulations, load_population_file, population_file_path,
+};
+use bevy::prelude::*;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+/// Dossier surveillé
+const POPULATIONS_DIR: &str = "populations";
+
+/// Délai de calme avant de traiter un fichier (une sauvegarde produit plusieurs événements)
+const DEBOUNCE_DELAY: Duration = Duration::from_millis(200);
+
+/// Surveillance de `populations/` : les événements arrivent par un canal, sans bloquer
+#[derive(Resource)]
+pub struct FileWatcher {
+    _watcher: RecommendedWatcher, // Gardé en vie tant que la ressource existe
+    events: flume::Receiver<notify::Result<notify::Event>>,
+    pending: HashMap<PathBuf, Instant>,
+}
+
+pub fn start_populations_watcher(mut commands: Commands) {
+    let populations_dir = Path::new(POPULATIONS_DIR);
+    if let Err(e) = fs::create_dir_all(populations_dir) {
+        warn!("Impossible de créer {:?}, pas de surveillance: {}", populations_dir, e);
+        return;
+    }
+
+    let (sender, events) = flume::unbounded();
+    let watcher = notify::recommended_watcher(move |event: notify::Result<notify::Event>| {
+        let _ = sender.send(event);
+    })
+    .and_then(|mut watcher| {
+        watcher.watch(populations_dir, RecursiveMode::NonRecursive)?;
+        Ok(watcher)
+    });
+
+    match watcher {
+        Ok(watcher) => {
+            info!("👁 Surveillance de {:?} active", populations_dir);
+            commands.insert_resource(FileWatcher {
+                _watcher: watcher,
+                events,
+                pending: HashMap::new(),
+            });
+        }
+        Err(e) => warn!("Impossible de surveiller {:?}: {}", populations_dir, e),
+    }
+}
+
+/// Met à jour `AvailablePopulations` avec les fichiers créés, modifiés ou supprimés
+pub fn watch_populations_directory(
+    mut watcher: ResMut<FileWatcher>,
+    mut available: ResMut<AvailablePopulations>,
+) {
+    let now = Instant::now();
+
+    let received: Vec<_> = watcher.events.try_iter().collect();
+    for event in received {
+        match event {
+            Ok(event) => {
+                for path in event.paths {
+                    // Seuls les JSON comptent : les .tmp et .bin accompagnent chaque sauvegarde
+                    if path.extension().and_then(|s| s.to_str()) == Some("json") {
+                        watcher.pending.insert(path, now);
+                    }
+                }
+            }
+            Err(e) => warn!("Erreur de surveillance des populations: {}", e),
+        }
+    }
+
+    // Un fichier n'est relu qu'après 200 ms sans nouvel événement
+    let ready: Vec<PathBuf> = watcher
+        .pending
+        .iter()
+        .filter(|(_, last_event)| now.duration_since(**last_event) >= DEBOUNCE_DELAY)
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    if ready.is_empty() || !available.loaded {
+        return;
+    }
+
+    for path in ready {
+        watcher.pending.remove(&path);
+        refresh_population(&mut available, &path);
+    }
+
+    available
+        .populations
+        .sort_by(|a, b| b.timestamp.cmp(&a.timestamp));
+}
+
+fn refresh_population(available: &mut AvailablePopulations, path: &Path) {
+    let file_name = path.file_name();
+    let position = available
+        .populations
+        .iter()
+        .position(|population| population_file_path(population).file_name() == file_name);
+
+    if !path.exists() {
+        if let Some(index) = position {
+            let removed = available.populations.remove(index);
+            info!("Population '{}' retirée (fichier supprimé)", removed.name);
+        }
+        return;
+    }
+
+    match load_population_file(path) {
+        Ok(population) => {
+            info!("Population '{}' rechargée depuis {:?}", population.name, path);
+            match position {
+                Some(index) => available.populations[index] = population,
+                None => available.populations.push(population),
+            }
+        }
+        Err(e) => warn!("Impossible de relire {:?}: {}", path, e),
+    }
+}
@@ -1,22 +1,127 @@
+use bevy::core_pipeline::bloom::Bloom;
+use bevy::core_pipeline::core_3d::Camera3d;
 use bevy::input::ButtonInput;
-use bevy::input::mouse::AccumulatedMouseMotion;
+use bevy::input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll, MouseScrollUnit};
 use bevy::math::{EulerRot, Quat, Vec2, Vec3};
-use bevy::prelude::{Camera, MouseButton, Query, Res, Transform, With};
-use crate::resources::world::camera::CameraSettings;
+use bevy::prelude::{
+    info, Camera, Commands, Component, DetectChanges, Entity, KeyCode, Local, MouseButton,
+    Projection, Query, Res, ResMut, Single, Time, Transform, With,
+};
+use bevy::window::{CursorGrabMode, PrimaryWindow, Window};
+use bevy_egui::EguiContexts;
+use crate::resources::world::camera::{BloomConfig, CameraMode, CameraSettings};
+use crate::systems::rendering::viewport_manager::{ViewportCamera, ViewportCameraState};
 
+/// Bornes de la distance d'orbite
+const MIN_ORBIT_DISTANCE: f32 = 50.0;
+const MAX_ORBIT_DISTANCE: f32 = 5000.0;
+
+/// Nombre de pixels de défilement équivalents à une ligne (pavés tactiles)
+const PIXELS_PER_SCROLL_LINE: f32 = 16.0;
+
+/// Transition d'une caméra vers une nouvelle position, au lieu d'un saut instantané
+#[derive(Component, Debug)]
+pub struct CameraTransition {
+    pub start: Transform,
+    pub end: Transform,
+    pub t: f32,
+    pub duration: f32,
+}
+
+impl CameraTransition {
+    pub fn new(start: Transform, end: Transform, duration: f32) -> Self {
+        Self {
+            start,
+            end,
+            t: 0.0,
+            duration,
+        }
+    }
+}
+
+/// Caméra active dont la zone d'affichage contient le curseur (la plus haute en cas de chevauchement)
+pub(crate) fn camera_under_cursor<'a>(
+    cameras: impl Iterator<Item = (Entity, &'a Camera)>,
+    cursor: Vec2,
+) -> Option<Entity> {
+    cameras
+        .filter(|(_, camera)| camera.is_active)
+        .filter(|(_, camera)| {
+            // Sans viewport, la caméra couvre toute la fenêtre
+            camera.viewport.as_ref().is_none_or(|viewport| {
+                let min = viewport.physical_position.as_vec2();
+                let max = min + viewport.physical_size.as_vec2();
+                cursor.cmpge(min).all() && cursor.cmplt(max).all()
+            })
+        })
+        .max_by_key(|(_, camera)| camera.order)
+        .map(|(entity, _)| entity)
+}
+
+/// Orbite de la vue survolée : chaque viewport garde son propre angle et sa distance
 pub fn orbit(
-    mut camera: Query<&mut Transform, With<Camera>>,
+    mut cameras: Query<(Entity, &Camera, &mut Transform, Option<&mut ViewportCamera>)>,
     camera_settings: Res<CameraSettings>,
     mouse_buttons: Res<ButtonInput<MouseButton>>,
     mouse_motion: Res<AccumulatedMouseMotion>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut contexts: EguiContexts,
+    mut dragged_camera: Local<Option<Entity>>,
 ) {
+    if camera_settings.mode != CameraMode::Orbit {
+        *dragged_camera = None;
+        return;
+    }
+
+    // La vue est choisie au clic puis conservée pendant tout le glisser
+    if mouse_buttons.just_pressed(MouseButton::Left) {
+        let over_egui = contexts
+            .try_ctx_mut()
+            .is_some_and(|ctx| ctx.is_pointer_over_area());
+
+        *dragged_camera = match window.physical_cursor_position() {
+            Some(cursor) if !over_egui => camera_under_cursor(
+                cameras.iter().map(|(entity, camera, _, _)| (entity, camera)),
+                cursor,
+            ),
+            _ => None,
+        };
+    }
+
+    if !mouse_buttons.pressed(MouseButton::Left) {
+        *dragged_camera = None;
+        return;
+    }
+
     let delta = mouse_motion.delta;
+    if delta == Vec2::ZERO {
+        return;
+    }
+
+    let Some((_, _, mut transform, viewport_camera)) =
+        dragged_camera.and_then(|entity| cameras.get_mut(entity).ok())
+    else {
+        return;
+    };
 
-    if mouse_buttons.pressed(MouseButton::Left) && delta != Vec2::ZERO {
-        for mut transform in camera.iter_mut() {
-            let delta_pitch = delta.y * camera_settings.pitch_speed;
-            let delta_yaw = delta.x * camera_settings.yaw_speed;
+    let delta_pitch = delta.y * camera_settings.pitch_speed;
+    let delta_yaw = delta.x * camera_settings.yaw_speed;
 
+    match viewport_camera {
+        Some(mut viewport_camera) => {
+            let state = &mut viewport_camera.state;
+            // La vue 2D reste face au plan
+            if state.flat {
+                return;
+            }
+            state.pitch = (state.pitch + delta_pitch).clamp(
+                camera_settings.pitch_range.start,
+                camera_settings.pitch_range.end,
+            );
+            state.yaw += delta_yaw;
+            *transform = state.transform();
+        }
+        None => {
             let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
 
             let pitch = (pitch + delta_pitch).clamp(
@@ -33,4 +138,257 @@ pub fn orbit(
             transform.translation = target - transform.forward() * orbit_distance;
         }
     }
-}
\ No newline at end of file
+}
+
+pub fn scroll_zoom(
+    mut cameras: Query<(
+        Entity,
+        &Camera,
+        &mut Transform,
+        Option<&mut ViewportCamera>,
+        Option<&mut Projection>,
+    )>,
+    mut camera_settings: ResMut<CameraSettings>,
+    mouse_scroll: Res<AccumulatedMouseScroll>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    mut contexts: EguiContexts,
+) {
+    if camera_settings.mode != CameraMode::Orbit || mouse_scroll.delta.y == 0.0 {
+        return;
+    }
+
+    // Le défilement au-dessus d'un panneau egui ne doit pas zoomer
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.is_pointer_over_area())
+    {
+        return;
+    }
+
+    let scroll_lines = match mouse_scroll.unit {
+        MouseScrollUnit::Line => mouse_scroll.delta.y,
+        MouseScrollUnit::Pixel => mouse_scroll.delta.y / PIXELS_PER_SCROLL_LINE,
+    };
+    let zoom = scroll_lines * camera_settings.zoom_speed;
+
+    // Seule la vue survolée zoome
+    let Some((_, _, mut transform, viewport_camera, projection)) = window
+        .physical_cursor_position()
+        .and_then(|cursor| {
+            camera_under_cursor(
+                cameras.iter().map(|(entity, camera, ..)| (entity, camera)),
+                cursor,
+            )
+        })
+        .and_then(|entity| cameras.get_mut(entity).ok())
+    else {
+        return;
+    };
+
+    match viewport_camera {
+        Some(mut viewport_camera) => {
+            let state = &mut viewport_camera.state;
+            state.distance =
+                (state.distance - zoom).clamp(MIN_ORBIT_DISTANCE, MAX_ORBIT_DISTANCE);
+            *transform = state.transform();
+            // En orthographique, seule la hauteur visible change le zoom
+            if let (true, Some(mut projection)) = (state.flat, projection) {
+                *projection = state.projection();
+            }
+        }
+        None => {
+            camera_settings.orbit_distance = (camera_settings.orbit_distance - zoom)
+                .clamp(MIN_ORBIT_DISTANCE, MAX_ORBIT_DISTANCE);
+
+            let direction = transform.translation.try_normalize().unwrap_or(Vec3::Z);
+
+            *transform = Transform::from_translation(direction * camera_settings.orbit_distance)
+                .looking_at(Vec3::ZERO, Vec3::Y);
+        }
+    }
+}
+
+/// Bascule entre l'orbite et le vol libre avec la touche F
+pub fn toggle_camera_mode(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut camera_settings: ResMut<CameraSettings>,
+    mut cameras: Query<(&Transform, Option<&mut ViewportCamera>), With<Camera>>,
+    mut window: Single<&mut Window, With<PrimaryWindow>>,
+    mut contexts: EguiContexts,
+) {
+    // Ne pas intercepter la saisie dans un champ de texte
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+
+    if !keyboard.just_pressed(KeyCode::KeyF) {
+        return;
+    }
+
+    camera_settings.mode = match camera_settings.mode {
+        CameraMode::Orbit => {
+            window.cursor_options.grab_mode = CursorGrabMode::Locked;
+            window.cursor_options.visible = false;
+            CameraMode::FreeFly
+        }
+        CameraMode::FreeFly => {
+            window.cursor_options.grab_mode = CursorGrabMode::None;
+            window.cursor_options.visible = true;
+
+            // Reprendre l'orbite depuis la position courante de chaque caméra
+            for (transform, viewport_camera) in cameras.iter_mut() {
+                let distance = transform
+                    .translation
+                    .length()
+                    .clamp(MIN_ORBIT_DISTANCE, MAX_ORBIT_DISTANCE);
+
+                match viewport_camera {
+                    Some(mut viewport_camera) => {
+                        let flat = viewport_camera.state.flat;
+                        viewport_camera.state =
+                            ViewportCameraState::from_transform(transform, distance);
+                        viewport_camera.state.flat = flat;
+                    }
+                    None => camera_settings.orbit_distance = distance,
+                }
+            }
+            CameraMode::Orbit
+        }
+    };
+
+    info!("🎥 Mode caméra: {}", camera_settings.mode.label());
+}
+
+pub fn free_fly(
+    mut camera: Query<&mut Transform, With<Camera>>,
+    camera_settings: Res<CameraSettings>,
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    mouse_motion: Res<AccumulatedMouseMotion>,
+    time: Res<Time>,
+    mut contexts: EguiContexts,
+) {
+    if camera_settings.mode != CameraMode::FreeFly {
+        return;
+    }
+
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+
+    // Axes locaux : x = droite, y = haut, z = avant
+    let mut axis = Vec3::ZERO;
+    if keyboard.pressed(KeyCode::KeyW) {
+        axis.z += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyS) {
+        axis.z -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyD) {
+        axis.x += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyA) {
+        axis.x -= 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyE) {
+        axis.y += 1.0;
+    }
+    if keyboard.pressed(KeyCode::KeyQ) {
+        axis.y -= 1.0;
+    }
+
+    let step = camera_settings.fly_speed * time.delta_secs();
+    let delta = mouse_motion.delta;
+    let rotating = mouse_buttons.pressed(MouseButton::Left) && delta != Vec2::ZERO;
+
+    for mut transform in camera.iter_mut() {
+        if rotating {
+            let (yaw, pitch, roll) = transform.rotation.to_euler(EulerRot::YXZ);
+
+            let pitch = (pitch - delta.y * camera_settings.pitch_speed).clamp(
+                camera_settings.pitch_range.start,
+                camera_settings.pitch_range.end,
+            );
+            let yaw = yaw - delta.x * camera_settings.yaw_speed;
+            transform.rotation = Quat::from_euler(EulerRot::YXZ, yaw, pitch, roll);
+        }
+
+        if axis != Vec3::ZERO {
+            let movement = transform.right() * axis.x
+                + transform.up() * axis.y
+                + transform.forward() * axis.z;
+            transform.translation += movement.normalize_or_zero() * step;
+        }
+    }
+}
+
+/// Fait avancer les transitions en cours (ease-out), puis les retire une fois terminées
+pub fn camera_transition_system(
+    mut commands: Commands,
+    time: Res<Time>,
+    camera_settings: Res<CameraSettings>,
+    mut cameras: Query<(
+        Entity,
+        &mut Transform,
+        &mut CameraTransition,
+        Option<&ViewportCamera>,
+    )>,
+) {
+    for (entity, mut transform, mut transition, viewport_camera) in cameras.iter_mut() {
+        // En vol libre, la caméra appartient à l'utilisateur
+        if camera_settings.mode == CameraMode::FreeFly {
+            commands.entity(entity).remove::<CameraTransition>();
+            continue;
+        }
+
+        // La cible suit l'orbite de la vue si l'utilisateur la modifie pendant la transition
+        if let Some(viewport_camera) = viewport_camera {
+            transition.end = viewport_camera.state.transform();
+        }
+
+        transition.t += time.delta_secs() / transition.duration.max(f32::EPSILON);
+        if transition.t >= 1.0 {
+            *transform = transition.end;
+            commands.entity(entity).remove::<CameraTransition>();
+            continue;
+        }
+
+        let eased = transition.t.powf(0.5);
+        transform.translation = transition
+            .start
+            .translation
+            .lerp(transition.end.translation, eased);
+        transform.rotation = transition.start.rotation.slerp(transition.end.rotation, eased);
+        transform.scale = transition.start.scale.lerp(transition.end.scale, eased);
+    }
+}
+
+/// Applique les réglages du bloom aux caméras existantes lorsqu'ils changent
+pub fn sync_bloom_settings(
+    mut commands: Commands,
+    bloom_config: Res<BloomConfig>,
+    mut cameras: Query<(Entity, &mut Camera), With<Camera3d>>,
+) {
+    if !bloom_config.is_changed() {
+        return;
+    }
+
+    for (entity, mut camera) in cameras.iter_mut() {
+        camera.hdr = bloom_config.enabled();
+
+        match bloom_config.bloom() {
+            Some(bloom) => {
+                commands.entity(entity).insert(bloom);
+            }
+            None => {
+                commands.entity(entity).remove::<Bloom>();
+            }
+        }
+    }
+}
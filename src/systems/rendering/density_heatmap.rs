@@ -0,0 +1,148 @@
+use crate::components::entities::particle::Particle;
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::resources::world::grid::GridParameters;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use std::f32::consts::FRAC_PI_2;
+
+/// Résolution par défaut de la carte de densité (cellules par côté)
+pub const DEFAULT_HEATMAP_RESOLUTION: u32 = 20;
+
+/// Nombre de frames entre deux recalculs de la densité
+const HEATMAP_UPDATE_INTERVAL: u32 = 10;
+
+/// Groupe de gizmos de la carte de densité (affiché uniquement sur le layer de la simulation)
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct DensityHeatmapGizmos;
+
+/// Densité normalisée des particules projetées sur le plan XZ
+#[derive(Resource)]
+pub struct DensityHeatmap {
+    pub resolution: u32,
+    pub densities: Vec<f32>,
+}
+
+impl Default for DensityHeatmap {
+    fn default() -> Self {
+        Self::new(DEFAULT_HEATMAP_RESOLUTION)
+    }
+}
+
+impl DensityHeatmap {
+    pub fn new(resolution: u32) -> Self {
+        Self {
+            resolution,
+            densities: Vec::new(),
+        }
+    }
+}
+
+/// Regroupe les particules de la simulation sélectionnée par cellule, toutes les 10 frames
+pub fn update_density_heatmap(
+    ui_state: Res<ForceMatrixUI>,
+    grid: Res<GridParameters>,
+    mut heatmap: ResMut<DensityHeatmap>,
+    simulations: Query<(Entity, &SimulationId), With<Simulation>>,
+    particles: Query<(&Transform, &ChildOf), With<Particle>>,
+    mut frame_counter: Local<u32>,
+) {
+    if !ui_state.show_density_heatmap {
+        return;
+    }
+
+    *frame_counter += 1;
+    if *frame_counter < HEATMAP_UPDATE_INTERVAL && !heatmap.densities.is_empty() {
+        return;
+    }
+    *frame_counter = 0;
+
+    let Some(selected_sim) = ui_state.selected_simulation else {
+        heatmap.densities.clear();
+        return;
+    };
+
+    let Some((simulation_entity, _)) = simulations
+        .iter()
+        .find(|(_, sim_id)| sim_id.0 == selected_sim)
+    else {
+        heatmap.densities.clear();
+        return;
+    };
+
+    let resolution = heatmap.resolution.max(1) as usize;
+    let mut counts = vec![0u32; resolution * resolution];
+
+    for (transform, parent) in particles.iter() {
+        if parent.parent() != simulation_entity {
+            continue;
+        }
+
+        let position = transform.translation;
+        let u = (position.x / grid.width + 0.5).clamp(0.0, 0.999);
+        let v = (position.z / grid.depth + 0.5).clamp(0.0, 0.999);
+        let cell = (v * resolution as f32) as usize * resolution + (u * resolution as f32) as usize;
+        counts[cell] += 1;
+    }
+
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1) as f32;
+    heatmap.densities = counts
+        .into_iter()
+        .map(|count| count as f32 / max_count)
+        .collect();
+}
+
+/// Dessine une cellule par case occupée sur le plancher de la grille, du noir (vide) au rouge (dense)
+pub fn draw_density_heatmap(
+    ui_state: Res<ForceMatrixUI>,
+    grid: Res<GridParameters>,
+    heatmap: Res<DensityHeatmap>,
+    mut config_store: ResMut<GizmoConfigStore>,
+    mut gizmos: Gizmos<DensityHeatmapGizmos>,
+) {
+    if !ui_state.show_density_heatmap {
+        return;
+    }
+
+    let Some(selected_sim) = ui_state.selected_simulation else {
+        return;
+    };
+
+    let resolution = heatmap.resolution.max(1) as usize;
+    if heatmap.densities.len() != resolution * resolution {
+        return;
+    }
+
+    let (config, _) = config_store.config_mut::<DensityHeatmapGizmos>();
+    let render_layers = RenderLayers::layer(selected_sim + 1);
+    if config.render_layers != render_layers {
+        config.render_layers = render_layers;
+    }
+
+    let cell_size = Vec2::new(
+        grid.width / resolution as f32,
+        grid.depth / resolution as f32,
+    );
+    let floor_y = -grid.height / 2.0;
+    let rotation = Quat::from_rotation_x(FRAC_PI_2);
+
+    for (cell, &density) in heatmap.densities.iter().enumerate() {
+        if density <= 0.0 {
+            continue;
+        }
+
+        let column = cell % resolution;
+        let row = cell / resolution;
+        let center = Vec3::new(
+            -grid.width / 2.0 + (column as f32 + 0.5) * cell_size.x,
+            floor_y,
+            -grid.depth / 2.0 + (row as f32 + 0.5) * cell_size.y,
+        );
+
+        gizmos.rect(
+            Isometry3d::new(center, rotation),
+            cell_size * 0.9,
+            Color::srgba(density, 0.0, 0.0, 0.2 + 0.6 * density),
+        );
+    }
+}
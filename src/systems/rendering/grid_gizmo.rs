@@ -0,0 +1,36 @@
+use crate::resources::world::boundary::BoundaryMode;
+use crate::resources::world::grid::GridParameters;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+
+/// Opacité de base du cadre de la grille
+const GRID_BOUNDS_ALPHA: f32 = 0.5;
+/// Vitesse de pulsation en mode téléportation (rad/s)
+const TELEPORT_PULSE_SPEED: f32 = 3.0;
+
+/// Dessine la boîte englobante de la grille (layer 0 : visible dans tous les viewports)
+pub fn draw_grid_gizmo_system(
+    ui_state: Res<ForceMatrixUI>,
+    grid: Res<GridParameters>,
+    boundary_mode: Res<BoundaryMode>,
+    time: Res<Time>,
+    mut gizmos: Gizmos,
+) {
+    if !ui_state.show_grid_bounds {
+        return;
+    }
+
+    // En téléportation, l'opacité oscille pour signaler le bouclage des bords
+    let alpha = match *boundary_mode {
+        BoundaryMode::Teleport => {
+            let pulse = (time.elapsed_secs() * TELEPORT_PULSE_SPEED).sin() * 0.5 + 0.5;
+            GRID_BOUNDS_ALPHA * (0.3 + 0.7 * pulse)
+        }
+        _ => GRID_BOUNDS_ALPHA,
+    };
+
+    gizmos.cuboid(
+        Transform::from_scale(Vec3::new(grid.width, grid.height, grid.depth)),
+        Color::srgba(0.3, 0.3, 0.3, alpha),
+    );
+}
@@ -1,3 +1,9 @@
 pub mod camera;
+pub mod density_heatmap;
+pub mod grid_gizmo;
+pub mod particle_palette;
+pub mod screenshot;
+pub mod trails;
+pub mod velocity_arrows;
 pub mod viewport_overlay;
 pub mod viewport_manager;
\ No newline at end of file
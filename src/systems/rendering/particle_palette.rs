@@ -0,0 +1,117 @@
+use crate::components::entities::particle::{Particle, ParticleType, Velocity};
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::SimulationParameters;
+use bevy::prelude::*;
+use std::collections::{HashMap, HashSet};
+
+/// Intensité d'émission à vitesse nulle et à la vitesse maximale configurée
+const SPEED_EMISSIVE_RANGE: (f32, f32) = (0.1, 3.0);
+/// Écart de vitesse en dessous duquel le matériau n'est pas mis à jour
+const SPEED_UPDATE_THRESHOLD: f32 = 5.0;
+
+/// La particule a son propre matériau (coloration par vitesse)
+#[derive(Component)]
+pub struct OwnMaterial;
+
+/// Recolore les matériaux des particules existantes quand la palette des types change
+pub fn update_particle_materials(
+    particle_config: Res<ParticleTypesConfig>,
+    particles: Query<(&ParticleType, &MeshMaterial3d<StandardMaterial>), With<Particle>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    // Les particules d'un même type partagent leur matériau : un seul passage par matériau
+    let mut updated = HashSet::new();
+
+    for (particle_type, material_handle) in particles.iter() {
+        if !updated.insert(material_handle.id()) {
+            continue;
+        }
+
+        let Some(material) = materials.get_mut(material_handle.id()) else {
+            continue;
+        };
+
+        let (base_color, emissive) = particle_config.get_color_for_type(particle_type.0);
+        material.base_color = base_color;
+        material.emissive = emissive;
+    }
+}
+
+/// Émission de chaque particule proportionnelle à sa vitesse.
+/// Les matériaux étant partagés par type, chaque particule reçoit d'abord sa propre copie.
+pub fn update_particle_colors_system(
+    mut commands: Commands,
+    sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
+    particle_config: Res<ParticleTypesConfig>,
+    particles: Query<
+        (
+            Entity,
+            &Velocity,
+            &ParticleType,
+            &MeshMaterial3d<StandardMaterial>,
+            Has<OwnMaterial>,
+        ),
+        With<Particle>,
+    >,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut last_speeds: Local<HashMap<Entity, f32>>,
+) {
+    if !sim_params.speed_coloring {
+        // Retour à l'émission du type une fois la coloration désactivée
+        for (_, _, particle_type, material_handle, _) in particles
+            .iter()
+            .filter(|(entity, ..)| last_speeds.contains_key(entity))
+        {
+            if let Some(material) = materials.get_mut(material_handle.id()) {
+                material.emissive = particle_config.get_color_for_type(particle_type.0).1;
+            }
+        }
+        last_speeds.clear();
+        return;
+    }
+
+    // Palette modifiée : toutes les émissions sont à recalculer
+    if particle_config.is_changed() {
+        last_speeds.clear();
+    }
+
+    // Particules disparues (époque suivante, prédation, vieillissement)
+    last_speeds.retain(|entity, _| particles.contains(*entity));
+
+    for (entity, velocity, particle_type, material_handle, has_own_material) in particles.iter() {
+        if !has_own_material {
+            let Some(shared) = materials.get(material_handle.id()).cloned() else {
+                continue;
+            };
+            commands
+                .entity(entity)
+                .insert((MeshMaterial3d(materials.add(shared)), OwnMaterial));
+            continue;
+        }
+
+        let speed = velocity.0.length();
+        if last_speeds
+            .get(&entity)
+            .is_some_and(|last| (speed - last).abs() <= SPEED_UPDATE_THRESHOLD)
+        {
+            continue;
+        }
+        last_speeds.insert(entity, speed);
+
+        let Some(material) = materials.get_mut(material_handle.id()) else {
+            continue;
+        };
+
+        let (min, max) = SPEED_EMISSIVE_RANGE;
+        let speed_ratio = (speed / global_config.max_velocity.max(f32::EPSILON)).clamp(0.0, 1.0);
+        let intensity = min + (max - min) * speed_ratio;
+        let emissive = particle_config.get_color_for_type(particle_type.0).1;
+        material.emissive = LinearRgba::rgb(
+            emissive.red * intensity,
+            emissive.green * intensity,
+            emissive.blue * intensity,
+        );
+    }
+}
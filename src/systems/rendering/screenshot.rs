@@ -0,0 +1,84 @@
+use bevy::prelude::*;
+use bevy::render::view::screenshot::{save_to_disk, Screenshot, ScreenshotCaptured};
+use std::fs;
+use std::path::Path;
+
+/// Dossier de destination des captures d'écran
+pub const SCREENSHOTS_DIR: &str = "screenshots";
+
+/// Durée d'affichage de la notification après une capture
+const NOTICE_DURATION: f32 = 2.0;
+
+/// Demande une capture de la fenêtre principale
+#[derive(Event)]
+pub struct CaptureScreenshot;
+
+/// État des captures : capture en cours et notification transitoire
+#[derive(Resource, Default)]
+pub struct ScreenshotState {
+    pub pending: bool,
+    pub notice: Option<(String, Timer)>,
+}
+
+impl ScreenshotState {
+    pub fn notice_message(&self) -> Option<&str> {
+        self.notice.as_ref().map(|(message, _)| message.as_str())
+    }
+}
+
+pub fn screenshot_key_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    mut capture_events: EventWriter<CaptureScreenshot>,
+) {
+    if keyboard.just_pressed(KeyCode::PrintScreen) {
+        capture_events.write(CaptureScreenshot);
+    }
+}
+
+pub fn capture_screenshot_system(
+    mut commands: Commands,
+    mut capture_events: EventReader<CaptureScreenshot>,
+    mut state: ResMut<ScreenshotState>,
+) {
+    // Plusieurs demandes dans la même frame ne produisent qu'une capture
+    if capture_events.read().count() == 0 || state.pending {
+        return;
+    }
+
+    let screenshots_dir = Path::new(SCREENSHOTS_DIR);
+    if let Err(e) = fs::create_dir_all(screenshots_dir) {
+        error!("Impossible de créer {:?}: {}", screenshots_dir, e);
+        return;
+    }
+
+    let timestamp = chrono::Utc::now().format("%Y-%m-%d_%H-%M-%S").to_string();
+    let file_path = screenshots_dir.join(format!("capture_{}.png", timestamp));
+    let display_path = file_path.display().to_string();
+
+    state.pending = true;
+
+    commands
+        .spawn(Screenshot::primary_window())
+        .observe(save_to_disk(file_path))
+        .observe(
+            move |_trigger: Trigger<ScreenshotCaptured>, mut state: ResMut<ScreenshotState>| {
+                info!("📷 Capture d'écran enregistrée: {}", display_path);
+                state.pending = false;
+                state.notice = Some((
+                    "📷 Capture enregistrée".to_string(),
+                    Timer::from_seconds(NOTICE_DURATION, TimerMode::Once),
+                ));
+            },
+        );
+}
+
+pub fn update_screenshot_notice(mut state: ResMut<ScreenshotState>, time: Res<Time>) {
+    let expired = match state.notice.as_mut() {
+        Some((_, timer)) => timer.tick(time.delta()).finished(),
+        None => false,
+    };
+
+    if expired {
+        state.notice = None;
+    }
+}
@@ -0,0 +1,74 @@
+use crate::components::entities::particle::{Particle, ParticleType};
+use crate::components::entities::trail::{ParticleTrails, TrailHistory};
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::grid::GridParameters;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Enregistre la position courante de chaque particule dans son historique
+pub fn update_particle_trails(
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    mut particles: Query<(&Transform, &mut TrailHistory), With<Particle>>,
+) {
+    if sim_params.trail_length == 0 {
+        return;
+    }
+
+    // Un saut plus grand que la moitié de la grille = téléportation ou nouvelle époque
+    let max_jump = grid.width.min(grid.height).min(grid.depth) / 2.0;
+
+    for (transform, mut trail) in particles.iter_mut() {
+        let position = transform.translation;
+
+        if let Some(last) = trail.positions.back() {
+            if last.distance(position) > max_jump {
+                trail.clear();
+            }
+        }
+
+        trail.push(position);
+    }
+}
+
+/// Reconstruit les gizmos de traînées de chaque simulation (alpha décroissant vers l'ancien)
+pub fn render_particle_trails(
+    sim_params: Res<SimulationParameters>,
+    particle_config: Res<ParticleTypesConfig>,
+    particles: Query<(&TrailHistory, &ParticleType, &ChildOf), With<Particle>>,
+    trail_gizmos: Query<(&Gizmo, &ChildOf), With<ParticleTrails>>,
+    mut gizmo_assets: ResMut<Assets<GizmoAsset>>,
+) {
+    if sim_params.trail_length == 0 {
+        return;
+    }
+
+    let mut assets_by_simulation: HashMap<Entity, GizmoAsset> = HashMap::new();
+
+    for (trail, particle_type, parent) in particles.iter() {
+        if trail.positions.len() < 2 {
+            continue;
+        }
+
+        let (base_color, _) = particle_config.get_color_for_type(particle_type.0);
+        let count = trail.positions.len() as f32;
+
+        let asset = assets_by_simulation
+            .entry(parent.parent())
+            .or_insert_with(GizmoAsset::default);
+
+        asset.linestrip_gradient(trail.positions.iter().enumerate().map(|(i, position)| {
+            let alpha = (i as f32 + 1.0) / count;
+            (*position, base_color.with_alpha(alpha))
+        }));
+    }
+
+    for (gizmo, parent) in trail_gizmos.iter() {
+        if let Some(asset) = gizmo_assets.get_mut(&gizmo.handle) {
+            *asset = assets_by_simulation
+                .remove(&parent.parent())
+                .unwrap_or_default();
+        }
+    }
+}
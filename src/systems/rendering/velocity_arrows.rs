@@ -0,0 +1,64 @@
+use crate::components::entities::particle::{Particle, ParticleType, Velocity};
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::globals::PARTICLE_RADIUS;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+
+/// Groupe de gizmos des flèches de vitesse (affiché uniquement sur le layer de la simulation)
+#[derive(Default, Reflect, GizmoConfigGroup)]
+pub struct VelocityArrowGizmos;
+
+/// Facteur entre la norme de la vitesse et la longueur de la flèche
+const ARROW_LENGTH_FACTOR: f32 = 0.1;
+
+/// Dessine une flèche de vitesse pour chaque particule de la simulation sélectionnée
+pub fn draw_velocity_arrows_system(
+    ui_state: Res<ForceMatrixUI>,
+    particle_config: Res<ParticleTypesConfig>,
+    mut config_store: ResMut<GizmoConfigStore>,
+    simulations: Query<(Entity, &SimulationId), With<Simulation>>,
+    particles: Query<(&Transform, &Velocity, &ParticleType, &ChildOf), With<Particle>>,
+    mut gizmos: Gizmos<VelocityArrowGizmos>,
+) {
+    if !ui_state.show_velocity_arrows {
+        return;
+    }
+
+    let Some(selected_sim) = ui_state.selected_simulation else {
+        return;
+    };
+
+    let Some((simulation_entity, _)) = simulations
+        .iter()
+        .find(|(_, sim_id)| sim_id.0 == selected_sim)
+    else {
+        return;
+    };
+
+    let (config, _) = config_store.config_mut::<VelocityArrowGizmos>();
+    let render_layers = RenderLayers::layer(selected_sim + 1);
+    if config.render_layers != render_layers {
+        config.render_layers = render_layers;
+    }
+
+    let max_length = PARTICLE_RADIUS * 5.0;
+
+    for (transform, velocity, particle_type, parent) in particles.iter() {
+        if parent.parent() != simulation_entity {
+            continue;
+        }
+
+        let length = (velocity.0.length() * ARROW_LENGTH_FACTOR).min(max_length);
+        if length <= f32::EPSILON {
+            continue;
+        }
+
+        let start = transform.translation;
+        let end = start + velocity.0.normalize() * length;
+        let (color, _) = particle_config.get_color_for_type(particle_type.0);
+
+        gizmos.arrow(start, end, color);
+    }
+}
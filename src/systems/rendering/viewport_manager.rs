@@ -1,16 +1,88 @@
+use crate::resources::world::camera::{BloomConfig, CameraSettings};
 use crate::resources::world::grid::GridParameters;
-use crate::ui::panels::force_matrix::ForceMatrixUI;
+use crate::ui::panels::force_matrix::{ForceMatrixUI, ViewportLayout};
 use bevy::prelude::*;
-use bevy::render::camera::{ClearColorConfig};
+use bevy::render::camera::{ClearColorConfig, ScalingMode};
 use bevy::render::view::RenderLayers;
 use bevy::window::WindowResized;
 use crate::components::entities::particle::Particle;
 use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::resources::config::simulation::SimulationParameters;
+use crate::systems::rendering::camera::CameraTransition;
 
 /// Marqueur pour les caméras des viewports
 #[derive(Component)]
 pub struct ViewportCamera {
     pub simulation_id: usize,
+    pub state: ViewportCameraState,
+}
+
+/// Orbite propre à chaque viewport, autour du centre de la grille
+#[derive(Clone, Copy, Debug)]
+pub struct ViewportCameraState {
+    pub yaw: f32,
+    pub pitch: f32,
+    pub distance: f32,
+    pub flat: bool, // Mode 2D : vue de face orthographique, sans orbite
+}
+
+impl ViewportCameraState {
+    /// Vue initiale en diagonale au-dessus de la grille
+    pub fn new(distance: f32) -> Self {
+        let position = Vec3::new(distance * 0.7, distance * 0.8, distance * 0.7);
+        Self::from_transform(
+            &Transform::from_translation(position).looking_at(Vec3::ZERO, Vec3::Y),
+            distance,
+        )
+    }
+
+    /// Vue de face du plan XY ; la distance sert de hauteur visible
+    pub fn top_down(distance: f32) -> Self {
+        Self {
+            yaw: 0.0,
+            pitch: 0.0,
+            distance,
+            flat: true,
+        }
+    }
+
+    pub fn for_dimension(distance: f32, flat: bool) -> Self {
+        if flat {
+            Self::top_down(distance)
+        } else {
+            Self::new(distance)
+        }
+    }
+
+    pub fn from_transform(transform: &Transform, distance: f32) -> Self {
+        let (yaw, pitch, _) = transform.rotation.to_euler(EulerRot::YXZ);
+        Self {
+            yaw,
+            pitch,
+            distance,
+            flat: false,
+        }
+    }
+
+    /// Perspective en 3D, orthographique en 2D
+    pub fn projection(&self) -> Projection {
+        if self.flat {
+            Projection::Orthographic(OrthographicProjection {
+                scaling_mode: ScalingMode::FixedVertical {
+                    viewport_height: self.distance,
+                },
+                far: self.distance * 2.0,
+                ..OrthographicProjection::default_3d()
+            })
+        } else {
+            Projection::default()
+        }
+    }
+
+    pub fn transform(&self) -> Transform {
+        let rotation = Quat::from_euler(EulerRot::YXZ, self.yaw, self.pitch, 0.0);
+        Transform::from_translation(rotation * Vec3::Z * self.distance).with_rotation(rotation)
+    }
 }
 
 /// Ressource pour stocker les dimensions de l'UI
@@ -74,13 +146,17 @@ pub fn update_viewports(
     ui_state: Res<ForceMatrixUI>,
     ui_space: Res<UISpace>,
     grid_params: Res<GridParameters>,
+    bloom_config: Res<BloomConfig>,
+    camera_settings: Res<CameraSettings>,
+    sim_params: Res<SimulationParameters>,
     windows: Query<&Window>,
     mut existing_cameras: Query<(
         Entity,
         &mut Camera,
-        &mut Transform,
+        &Transform,
         &mut RenderLayers,
         &mut ViewportCamera,
+        &mut Projection,
     )>,
     force_update: Option<Res<ForceViewportUpdate>>,
     mut resize_events: EventReader<WindowResized>,
@@ -123,10 +199,10 @@ pub fn update_viewports(
     selected_sims.sort();
 
     let mut cameras_to_reuse: Vec<Entity> =
-        existing_cameras.iter().map(|(e, _, _, _, _)| e).collect();
+        existing_cameras.iter().map(|(e, ..)| e).collect();
 
     if selected_sims.is_empty() {
-        for (_, mut camera, _, _, _) in existing_cameras.iter_mut() {
+        for (_, mut camera, ..) in existing_cameras.iter_mut() {
             camera.is_active = false;
         }
         return;
@@ -134,11 +210,13 @@ pub fn update_viewports(
 
     let viewport_count = selected_sims.len();
     let camera_distance = calculate_adaptive_camera_distance(&grid_params, viewport_count);
+    let flat = sim_params.dimension.is_flat();
 
     for (idx, &sim_id) in selected_sims.iter().enumerate() {
         let (x, y, w, h) = calculate_viewport_rect(
             idx,
             viewport_count,
+            ui_state.viewport_layout,
             available_width,
             available_height,
             ui_top_physical,
@@ -150,14 +228,20 @@ pub fn update_viewports(
         }
 
         if let Some(camera_entity) = cameras_to_reuse.pop() {
-            if let Ok((_, mut camera, mut transform, mut render_layers, mut viewport_camera)) =
-                existing_cameras.get_mut(camera_entity)
+            if let Ok((
+                _,
+                mut camera,
+                transform,
+                mut render_layers,
+                mut viewport_camera,
+                mut projection,
+            )) = existing_cameras.get_mut(camera_entity)
             {
                 update_camera_viewport(
                     &mut camera,
-                    &mut transform,
                     &mut render_layers,
                     &mut viewport_camera,
+                    &mut projection,
                     x,
                     y,
                     w,
@@ -165,15 +249,39 @@ pub fn update_viewports(
                     idx,
                     sim_id,
                     camera_distance,
+                    flat,
                 );
+
+                // Glisser vers la nouvelle cible plutôt que sauter
+                let target = viewport_camera.state.transform();
+                if !transform.translation.abs_diff_eq(target.translation, 0.01)
+                    || !transform.rotation.abs_diff_eq(target.rotation, 0.0001)
+                {
+                    commands.entity(camera_entity).insert(CameraTransition::new(
+                        *transform,
+                        target,
+                        camera_settings.transition_duration,
+                    ));
+                }
             }
         } else {
-            spawn_viewport_camera(&mut commands, x, y, w, h, idx, sim_id, camera_distance);
+            spawn_viewport_camera(
+                &mut commands,
+                x,
+                y,
+                w,
+                h,
+                idx,
+                sim_id,
+                camera_distance,
+                flat,
+                &bloom_config,
+            );
         }
     }
 
     for camera_entity in cameras_to_reuse {
-        if let Ok((_, mut camera, _, _, _)) = existing_cameras.get_mut(camera_entity) {
+        if let Ok((_, mut camera, ..)) = existing_cameras.get_mut(camera_entity) {
             camera.is_active = false;
         }
     }
@@ -183,6 +291,7 @@ pub fn update_viewports(
 fn calculate_viewport_rect(
     idx: usize,
     total: usize,
+    layout: ViewportLayout,
     available_width: f32,
     available_height: f32,
     ui_top: f32,
@@ -190,6 +299,25 @@ fn calculate_viewport_rect(
 ) -> (u32, u32, u32, u32) {
     let margin = 8.0; // Marge plus grande pour éviter les chevauchements
 
+    // Grille imposée par l'utilisateur : le nombre de viewports est ignoré
+    if let ViewportLayout::Grid(cols, rows) = layout {
+        let cols = cols.max(1) as usize;
+        let rows = rows.max(1) as usize;
+
+        // Simulation hors de la grille : pas de viewport
+        if idx >= cols * rows {
+            return (0, 0, 0, 0);
+        }
+
+        let width = (available_width - (cols + 1) as f32 * margin) / cols as f32;
+        let height = (available_height - (rows + 1) as f32 * margin) / rows as f32;
+        let x = margin + ((idx % cols) as f32 * (width + margin));
+        let y_from_top = margin + ((idx / cols) as f32 * (height + margin));
+
+        let bevy_y = window_height - ui_top - y_from_top - height;
+        return (x as u32, bevy_y as u32, width as u32, height as u32);
+    }
+
     let (x, y_from_top, w, h) = match total {
         1 => (
             margin,
@@ -250,9 +378,9 @@ fn calculate_viewport_rect(
 /// Met à jour une caméra existante
 fn update_camera_viewport(
     camera: &mut Camera,
-    transform: &mut Transform,
     render_layers: &mut RenderLayers,
     viewport_camera: &mut ViewportCamera,
+    projection: &mut Projection,
     x: u32,
     y: u32,
     w: u32,
@@ -260,6 +388,7 @@ fn update_camera_viewport(
     order: usize,
     sim_id: usize,
     distance: f32,
+    flat: bool,
 ) {
     camera.is_active = true;
     camera.viewport = Some(bevy::render::camera::Viewport {
@@ -270,12 +399,14 @@ fn update_camera_viewport(
     camera.order = order as isize;
     camera.clear_color = ClearColorConfig::Custom(Color::srgb(0.02, 0.02, 0.02));
 
-    let camera_pos = Vec3::new(distance * 0.7, distance * 0.8, distance * 0.7);
-
-    *transform = Transform::from_translation(camera_pos).looking_at(Vec3::ZERO, Vec3::Y);
+    // L'orbite de la vue est conservée tant qu'elle affiche la même simulation
+    if viewport_camera.simulation_id != sim_id || viewport_camera.state.flat != flat {
+        viewport_camera.simulation_id = sim_id;
+        viewport_camera.state = ViewportCameraState::for_dimension(distance, flat);
+        *projection = viewport_camera.state.projection();
+    }
 
     *render_layers = RenderLayers::from_layers(&[0, sim_id + 1]);
-    viewport_camera.simulation_id = sim_id;
 }
 
 /// Crée une nouvelle caméra de viewport
@@ -288,12 +419,15 @@ fn spawn_viewport_camera(
     order: usize,
     sim_id: usize,
     distance: f32,
+    flat: bool,
+    bloom_config: &BloomConfig,
 ) {
-    let camera_pos = Vec3::new(distance * 0.7, distance * 0.8, distance * 0.7);
+    let state = ViewportCameraState::for_dimension(distance, flat);
 
-    commands.spawn((
+    let mut camera = commands.spawn((
         Camera {
             is_active: true,
+            hdr: bloom_config.enabled(),
             viewport: Some(bevy::render::camera::Viewport {
                 physical_position: UVec2::new(x, y),
                 physical_size: UVec2::new(w, h),
@@ -304,12 +438,18 @@ fn spawn_viewport_camera(
             ..default()
         },
         Camera3d::default(),
-        Transform::from_translation(camera_pos).looking_at(Vec3::ZERO, Vec3::Y),
+        state.projection(),
+        state.transform(),
         ViewportCamera {
             simulation_id: sim_id,
+            state,
         },
         RenderLayers::from_layers(&[0, sim_id + 1]),
     ));
+
+    if let Some(bloom) = bloom_config.bloom() {
+        camera.insert(bloom);
+    }
 }
 
 /// Assigne les RenderLayers aux simulations et particules
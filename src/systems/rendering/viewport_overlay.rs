@@ -1,7 +1,64 @@
 use bevy::prelude::*;
+use bevy::window::PrimaryWindow;
 use bevy_egui::{egui, EguiContexts};
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::systems::rendering::camera::camera_under_cursor;
 use crate::systems::rendering::viewport_manager::{ViewportCamera, UISpace};
+use crate::systems::simulation::type_counts::ParticleTypeCounts;
 use crate::ui::panels::force_matrix::ForceMatrixUI;
+use crate::ui::panels::obstacles::ObstacleEditorUI;
+
+/// Déplacement maximal (pixels physiques) entre appui et relâchement pour un clic
+const CLICK_MAX_DRAG: f32 = 4.0;
+
+/// Un clic simple dans un viewport ouvre la matrice de forces de sa simulation ;
+/// un glisser reste réservé à l'orbite de la caméra
+pub fn camera_click_system(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<ForceMatrixUI>,
+    obstacle_editor: Res<ObstacleEditorUI>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &ViewportCamera)>,
+    mut press_position: Local<Option<Vec2>>,
+) {
+    if obstacle_editor.placing {
+        *press_position = None;
+        return;
+    }
+
+    let over_egui = contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.is_pointer_over_area());
+    let cursor = window.physical_cursor_position();
+
+    if mouse_buttons.just_pressed(MouseButton::Left) {
+        *press_position = if over_egui { None } else { cursor };
+    }
+
+    if !mouse_buttons.just_released(MouseButton::Left) {
+        return;
+    }
+
+    let (Some(pressed), Some(released)) = (press_position.take(), cursor) else {
+        return;
+    };
+    if over_egui || pressed.distance(released) > CLICK_MAX_DRAG {
+        return;
+    }
+
+    let Some(clicked) = camera_under_cursor(
+        cameras.iter().map(|(entity, camera, _)| (entity, camera)),
+        released,
+    ) else {
+        return;
+    };
+
+    if let Ok((_, _, viewport_camera)) = cameras.get(clicked) {
+        ui_state.selected_simulation = Some(viewport_camera.simulation_id);
+        ui_state.show_matrix_window = true;
+    }
+}
 
 /// Système pour dessiner les overlays des numéros de simulation sur chaque viewport
 pub fn draw_viewport_overlays(
@@ -10,6 +67,8 @@ pub fn draw_viewport_overlays(
     ui_space: Res<UISpace>,
     windows: Query<&Window>,
     cameras: Query<(&Camera, &ViewportCamera)>,
+    type_counts: Res<ParticleTypeCounts>,
+    particle_config: Res<ParticleTypesConfig>,
 ) {
     let Ok(window) = windows.single() else {
         return;
@@ -64,7 +123,7 @@ pub fn draw_viewport_overlays(
                 .movable(false)
                 .collapsible(false)
                 .fixed_pos(egui::pos2(logical_x + 10.0, egui_y + 10.0))
-                .fixed_size(egui::vec2(100.0, 40.0))
+                .fixed_size(egui::vec2(140.0, 40.0))
                 .frame(egui::Frame::NONE)
                 .show(ctx, |ui| {
                     // Style du texte avec fond semi-transparent
@@ -84,6 +143,27 @@ pub fn draw_viewport_overlays(
                                 .size(14.0)
                                 .strong()
                         );
+
+                        // Effectifs vivants par type, dans la couleur du type
+                        ui.horizontal_wrapped(|ui| {
+                            ui.spacing_mut().item_spacing.x = 4.0;
+                            for type_index in 0..particle_config.type_count {
+                                let [r, g, b, _] = particle_config
+                                    .get_color_for_type(type_index)
+                                    .0
+                                    .to_srgba()
+                                    .to_u8_array();
+                                ui.label(
+                                    egui::RichText::new(format!(
+                                        "{}:{}",
+                                        particle_config.get_short_label_for_type(type_index),
+                                        type_counts.count(sim_id, type_index)
+                                    ))
+                                    .color(egui::Color32::from_rgb(r, g, b))
+                                    .size(11.0),
+                                );
+                            }
+                        });
                     });
                 });
         }
@@ -0,0 +1,39 @@
+use crate::components::entities::particle::{Age, Particle, Velocity};
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::grid::GridParameters;
+use crate::systems::simulation::spawning::random_position_in_grid;
+use crate::resources::config::random::{RngStream, RunSeed};
+use bevy::prelude::*;
+
+/// Vieillit les particules et fait réapparaître celles qui ont atteint leur durée de vie
+pub fn age_particles_system(
+    sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
+    grid: Res<GridParameters>,
+    mut particles: Query<(&mut Age, &mut Transform, &mut Velocity), With<Particle>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    if sim_params.particle_lifespan.is_none() {
+        return;
+    }
+
+    let elapsed = global_config.physics_timestep * sim_params.simulation_speed.multiplier();
+    if elapsed <= 0.0 {
+        return;
+    }
+
+    let rng = rng_stream.get(&run_seed, "aging");
+
+    for (mut age, mut transform, mut velocity) in particles.iter_mut() {
+        age.current += elapsed;
+
+        if age.current >= age.max {
+            // Réapparition : on conserve la moitié de l'élan plutôt que de repartir à zéro
+            transform.translation = random_position_in_grid(&grid, rng);
+            velocity.0 *= 0.5;
+            age.current = 0.0;
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use crate::components::entities::particle::Particle;
+use crate::components::entities::simulation::{Extinct, Simulation};
+use crate::components::genetics::score::{ClusterScore, Score};
+use crate::globals::PARTICLE_RADIUS;
+use crate::resources::config::simulation::SimulationParameters;
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Intervalle de détection des amas (en frames)
+const CLUSTER_SAMPLE_INTERVAL: u32 = 30;
+
+/// Distance en dessous de laquelle deux particules appartiennent au même amas
+const CLUSTER_LINK_DISTANCE: f32 = PARTICLE_RADIUS * 4.0;
+
+/// Union-find avec compression de chemin et union par taille
+struct DisjointSet {
+    parents: Vec<usize>,
+    sizes: Vec<usize>,
+}
+
+impl DisjointSet {
+    fn new(count: usize) -> Self {
+        Self {
+            parents: (0..count).collect(),
+            sizes: vec![1; count],
+        }
+    }
+
+    fn find(&mut self, mut index: usize) -> usize {
+        while self.parents[index] != index {
+            self.parents[index] = self.parents[self.parents[index]];
+            index = self.parents[index];
+        }
+        index
+    }
+
+    fn union(&mut self, a: usize, b: usize) {
+        let (root_a, root_b) = (self.find(a), self.find(b));
+        if root_a == root_b {
+            return;
+        }
+
+        let (large, small) = if self.sizes[root_a] >= self.sizes[root_b] {
+            (root_a, root_b)
+        } else {
+            (root_b, root_a)
+        };
+        self.parents[small] = large;
+        self.sizes[large] += self.sizes[small];
+    }
+}
+
+/// Nombre d'amas et taille du plus grand, via une grille de cellules de la distance de liaison
+fn find_clusters(positions: &[Vec3]) -> (usize, usize) {
+    let cell_of = |position: Vec3| (position / CLUSTER_LINK_DISTANCE).floor().as_ivec3();
+
+    let mut cells: HashMap<IVec3, Vec<usize>> = HashMap::new();
+    for (index, position) in positions.iter().enumerate() {
+        cells.entry(cell_of(*position)).or_default().push(index);
+    }
+
+    let mut clusters = DisjointSet::new(positions.len());
+    let link_distance_squared = CLUSTER_LINK_DISTANCE * CLUSTER_LINK_DISTANCE;
+
+    for (index, position) in positions.iter().enumerate() {
+        let cell = cell_of(*position);
+        for offset_x in -1..=1 {
+            for offset_y in -1..=1 {
+                for offset_z in -1..=1 {
+                    let neighbor_cell = cell + IVec3::new(offset_x, offset_y, offset_z);
+                    let Some(neighbors) = cells.get(&neighbor_cell) else {
+                        continue;
+                    };
+                    for &other in neighbors.iter().filter(|&&other| other > index) {
+                        if position.distance_squared(positions[other]) < link_distance_squared {
+                            clusters.union(index, other);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let mut cluster_count = 0;
+    let mut largest_cluster = 0;
+    for index in 0..positions.len() {
+        if clusters.find(index) == index {
+            cluster_count += 1;
+            largest_cluster = largest_cluster.max(clusters.sizes[index]);
+        }
+    }
+
+    (cluster_count, largest_cluster)
+}
+
+/// Détecte les amas de chaque simulation et ajuste le bonus inclus dans son score
+pub fn detect_clusters_system(
+    particles: Query<(&Transform, &ChildOf), With<Particle>>,
+    mut simulations: Query<
+        (&mut ClusterScore, &mut Score),
+        (With<Simulation>, Without<Extinct>),
+    >,
+    sim_params: Res<SimulationParameters>,
+    mut frame_counter: Local<u32>,
+) {
+    *frame_counter += 1;
+    if *frame_counter % CLUSTER_SAMPLE_INTERVAL != 0 {
+        return;
+    }
+
+    let mut positions_by_simulation: HashMap<Entity, Vec<Vec3>> = HashMap::new();
+    for (transform, parent) in particles.iter() {
+        positions_by_simulation
+            .entry(parent.parent())
+            .or_default()
+            .push(transform.translation);
+    }
+
+    for (simulation, positions) in positions_by_simulation {
+        let Ok((mut cluster_score, mut score)) = simulations.get_mut(simulation) else {
+            continue;
+        };
+
+        let (cluster_count, largest_cluster) = find_clusters(&positions);
+
+        // Le score contient toujours le bonus courant : on n'ajoute que sa variation
+        let previous_bonus = cluster_score.bonus();
+        cluster_score.record(cluster_count, largest_cluster, sim_params.cluster_bonus_multiplier);
+        score.add(cluster_score.bonus() - previous_bonus);
+    }
+}
+
+/// Remet à zéro les amas au début de chaque époque
+pub fn reset_cluster_scores(mut simulations: Query<&mut ClusterScore, With<Simulation>>) {
+    for mut cluster_score in simulations.iter_mut() {
+        cluster_score.reset();
+    }
+}
@@ -1,15 +1,24 @@
+use crate::resources::config::random::{RngStream, RunSeed};
 use bevy::prelude::*;
 use crate::components::entities::food::{Food, FoodRespawnTimer, FoodValue};
-use crate::components::entities::particle::Particle;
-use crate::components::entities::simulation::Simulation;
-use crate::components::genetics::score::Score;
+use crate::components::entities::particle::{Particle, ParticleType};
+use crate::components::entities::simulation::{Energy, Extinct, Simulation};
+use crate::components::genetics::score::{FoodEatenCount, Score};
 use crate::globals::*;
+use crate::resources::config::food::FoodParameters;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::predation::PredationStrength;
+use crate::resources::world::grid::GridParameters;
+use crate::systems::audio::SoundEffect;
+use crate::systems::simulation::spawning::random_position_in_grid;
+use std::collections::{HashMap, HashSet};
 
 /// Détecte les collisions entre particules et nourriture
 pub fn detect_food_collision(
     mut commands: Commands,
     time: Res<Time>,
-    particles: Query<(&Transform, &ChildOf), With<Particle>>,
+    particle_config: Res<ParticleTypesConfig>,
+    particles: Query<(&Transform, &ParticleType, &ChildOf), With<Particle>>,
     mut food_query: Query<
         (
             Entity,
@@ -20,8 +29,21 @@ pub fn detect_food_collision(
         ),
         With<Food>,
     >,
-    mut simulations: Query<&mut Score, With<Simulation>>,
+    // Les simulations éteintes gardent leur score figé
+    mut simulations: Query<
+        (Entity, &mut Score, &mut FoodEatenCount, Option<&mut Energy>),
+        (With<Simulation>, Without<Extinct>),
+    >,
+    extinct: Query<(), With<Extinct>>,
+    mut sound_events: EventWriter<SoundEffect>,
 ) {
+    // Seule la meilleure simulation déclenche le son, pour éviter la cacophonie
+    let best_simulation = simulations
+        .iter()
+        .max_by(|a, b| a.1.get().partial_cmp(&b.1.get()).unwrap())
+        .map(|(entity, _, _, _)| entity);
+    let mut best_has_eaten = false;
+
     // Pour chaque nourriture
     for (food_entity, food_transform, food_value, mut respawn_timer, visibility) in
         food_query.iter_mut()
@@ -40,17 +62,33 @@ pub fn detect_food_collision(
         }
 
         let food_pos = food_transform.translation;
-        let collision_distance = PARTICLE_RADIUS + FOOD_RADIUS;
 
         // Vérifier collision avec chaque particule
-        for (particle_transform, parent) in particles.iter() {
+        for (particle_transform, particle_type, parent) in particles.iter() {
+            // Particules cachées d'une simulation éteinte : elles ne mangent plus
+            if extinct.contains(parent.parent()) {
+                continue;
+            }
+
             let distance = (particle_transform.translation - food_pos).length();
+            let collision_distance =
+                particle_config.get_radius_for_type(particle_type.0) + FOOD_RADIUS;
 
             if distance < collision_distance {
                 // Collision détectée !
                 // Augmenter le score de la simulation parente
-                if let Ok(mut score) = simulations.get_mut(parent.parent()) {
+                if let Ok((_, mut score, mut food_eaten, energy)) =
+                    simulations.get_mut(parent.parent())
+                {
                     score.add(food_value.0);
+                    food_eaten.0 += 1;
+                    // Budget d'énergie : la nourriture est aussi la seule source d'énergie
+                    if let Some(mut energy) = energy {
+                        energy.0 += food_value.0;
+                    }
+                }
+                if Some(parent.parent()) == best_simulation {
+                    best_has_eaten = true;
                 }
 
                 // Gérer la nourriture
@@ -70,4 +108,97 @@ pub fn detect_food_collision(
             }
         }
     }
+
+    if best_has_eaten {
+        sound_events.write(SoundEffect::FoodEaten);
+    }
+}
+
+/// Remet à zéro les compteurs de nourriture au début de chaque époque
+pub fn reset_food_eaten_counts(mut simulations: Query<&mut FoodEatenCount, With<Simulation>>) {
+    for mut food_eaten in simulations.iter_mut() {
+        food_eaten.0 = 0;
+    }
+}
+
+/// Chaîne alimentaire : une particule de type i mange une particule de type (i+1) % type_count
+pub fn detect_particle_predation(
+    predation_strength: Res<PredationStrength>,
+    particle_config: Res<ParticleTypesConfig>,
+    food_params: Res<FoodParameters>,
+    grid: Res<GridParameters>,
+    mut particles: Query<(Entity, &mut Transform, &ParticleType, &ChildOf), With<Particle>>,
+    mut simulations: Query<&mut Score, (With<Simulation>, Without<Extinct>)>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    let type_count = particle_config.type_count;
+    if type_count < 2 || predation_strength.0 <= 0.0 {
+        return;
+    }
+
+    // Regroupement des particules par simulation
+    let mut by_simulation: HashMap<Entity, Vec<(Entity, Vec3, usize)>> = HashMap::new();
+    for (entity, transform, particle_type, parent) in particles.iter() {
+        by_simulation
+            .entry(parent.parent())
+            .or_default()
+            .push((entity, transform.translation, particle_type.0));
+    }
+
+    // Ordre stable : les tirages de réapparition restent reproductibles avec une graine
+    let mut by_simulation: Vec<_> = by_simulation.into_iter().collect();
+    by_simulation.sort_by_key(|(simulation_entity, _)| *simulation_entity);
+
+    let rng = rng_stream.get(&run_seed, "predation");
+
+    for (simulation_entity, members) in by_simulation {
+        // Les simulations éteintes sont masquées : leurs particules ne chassent plus
+        if !simulations.contains(simulation_entity) {
+            continue;
+        }
+
+        let mut eaten: HashSet<Entity> = HashSet::new();
+        let mut eaten_order: Vec<Entity> = Vec::new();
+        let mut meals = 0;
+
+        for &(predator, predator_pos, predator_type) in &members {
+            if eaten.contains(&predator) {
+                continue;
+            }
+
+            let prey_type = (predator_type + 1) % type_count;
+            let contact_distance = (particle_config.get_radius_for_type(predator_type)
+                + particle_config.get_radius_for_type(prey_type))
+                * predation_strength.0;
+
+            for &(prey, prey_pos, other_type) in &members {
+                if other_type != prey_type || eaten.contains(&prey) {
+                    continue;
+                }
+
+                if predator_pos.distance(prey_pos) < contact_distance {
+                    eaten.insert(prey);
+                    eaten_order.push(prey);
+                    meals += 1;
+                    break;
+                }
+            }
+        }
+
+        if meals == 0 {
+            continue;
+        }
+
+        if let Ok(mut score) = simulations.get_mut(simulation_entity) {
+            score.add(meals as f32 * food_params.food_value);
+        }
+
+        // La proie "meurt" et réapparaît ailleurs, dans l'ordre où elle a été mangée
+        for prey in eaten_order {
+            if let Ok((_, mut transform, _, _)) = particles.get_mut(prey) {
+                transform.translation = random_position_in_grid(&grid, rng);
+            }
+        }
+    }
 }
@@ -0,0 +1,127 @@
+use crate::components::entities::particle::{Age, Offspring, Particle, ParticleType};
+use crate::components::entities::simulation::{Energy, Extinct, Simulation, SimulationId};
+use crate::components::entities::trail::TrailHistory;
+use crate::globals::*;
+use crate::plugins::simulation::compute::ComputeEnabled;
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::ParticleTypeAssets;
+use crate::resources::config::random::{RngStream, RunSeed};
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::physics::PhysicsState;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use rand::Rng;
+
+/// Début d'époque : retire les naissances, remet la réserve à `particle_count × 10`
+/// et ranime les simulations éteintes
+pub fn reset_energy_budget(
+    mut commands: Commands,
+    sim_params: Res<SimulationParameters>,
+    simulations: Query<Entity, With<Simulation>>,
+    offspring: Query<Entity, With<Offspring>>,
+) {
+    if sim_params.energy_budget.is_none() {
+        return;
+    }
+
+    for entity in offspring.iter() {
+        commands.entity(entity).despawn();
+    }
+
+    let initial_energy = sim_params.particle_count as f32 * ENERGY_PER_PARTICLE;
+    for entity in simulations.iter() {
+        commands
+            .entity(entity)
+            .insert((Energy(initial_energy), Visibility::Inherited))
+            .remove::<Extinct>();
+    }
+}
+
+/// Consommation d'énergie à chaque pas physique, extinction à zéro et naissances au-delà du seuil
+pub fn energy_budget_system(
+    mut commands: Commands,
+    sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
+    physics_state: Res<PhysicsState>,
+    compute: Res<ComputeEnabled>,
+    type_assets: Res<ParticleTypeAssets>,
+    mut simulations: Query<
+        (Entity, &SimulationId, &mut Energy, &Children),
+        (With<Simulation>, Without<Extinct>),
+    >,
+    particles: Query<(&Transform, &ParticleType), With<Particle>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    let Some(birth_threshold) = sim_params.energy_budget else {
+        return;
+    };
+
+    // Pas réellement simulés cette frame : indépendant du framerate
+    let substeps = physics_state.last_substeps;
+    if substeps == 0 {
+        return;
+    }
+
+    // Les tampons GPU sont dimensionnés sur particle_count : pas de naissance dans ce cas
+    let births_allowed = !compute.0;
+    let max_particle_count = sim_params.particle_count * MAX_PARTICLE_COUNT_FACTOR;
+    let step_cost = global_config.physics_timestep * ENERGY_COST_RATE;
+    let rng = rng_stream.get(&run_seed, "energy_births");
+
+    for (entity, sim_id, mut energy, children) in simulations.iter_mut() {
+        let mut members: Vec<(Vec3, usize)> = children
+            .iter()
+            .filter_map(|child| particles.get(child).ok())
+            .map(|(transform, particle_type)| (transform.translation, particle_type.0))
+            .collect();
+
+        for _step in 0..substeps {
+            let particle_count = members.len();
+            energy.0 -= particle_count as f32 * step_cost;
+
+            if energy.0 <= 0.0 {
+                energy.0 = 0.0;
+                commands
+                    .entity(entity)
+                    .insert((Extinct, Visibility::Hidden));
+                info!("💀 Simulation #{} éteinte : énergie épuisée", sim_id.0 + 1);
+                break;
+            }
+
+            let can_grow = particle_count > 0 && particle_count < max_particle_count;
+            if !births_allowed || !can_grow || energy.0 <= birth_threshold * particle_count as f32 {
+                continue;
+            }
+
+            // Une naissance au plus par pas : copie d'une particule existante, au même endroit
+            let (position, particle_type) = members[rng.random_range(0..particle_count)];
+            let (Some(mesh), Some(material)) = (
+                type_assets.meshes.get(particle_type),
+                type_assets.materials.get(particle_type),
+            ) else {
+                continue;
+            };
+
+            energy.0 -= ENERGY_PER_PARTICLE;
+            members.push((position, particle_type));
+            let mut child = commands.spawn((
+                Particle,
+                Offspring,
+                ParticleType(particle_type),
+                Transform::from_translation(position),
+                Mesh3d(mesh.clone()),
+                MeshMaterial3d(material.clone()),
+                TrailHistory::new(sim_params.trail_length),
+                RenderLayers::layer(sim_id.0 + 1),
+                ChildOf(entity),
+            ));
+            if let Some(lifespan) = sim_params.particle_lifespan {
+                child.insert(Age {
+                    current: 0.0,
+                    max: lifespan,
+                });
+            }
+        }
+    }
+}
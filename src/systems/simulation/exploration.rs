@@ -0,0 +1,32 @@
+use crate::components::entities::particle::Particle;
+use crate::components::entities::simulation::Simulation;
+use crate::components::genetics::score::ExplorationScore;
+use bevy::prelude::*;
+
+/// Intervalle d'échantillonnage des positions (en frames)
+const EXPLORATION_SAMPLE_INTERVAL: u32 = 10;
+
+/// Échantillonne les positions des particules pour mesurer l'exploration de chaque simulation
+pub fn track_exploration_system(
+    particles: Query<(&Transform, &ChildOf), With<Particle>>,
+    mut simulations: Query<&mut ExplorationScore, With<Simulation>>,
+    mut frame_counter: Local<u32>,
+) {
+    *frame_counter += 1;
+    if *frame_counter % EXPLORATION_SAMPLE_INTERVAL != 0 {
+        return;
+    }
+
+    for (transform, parent) in particles.iter() {
+        if let Ok(mut exploration) = simulations.get_mut(parent.parent()) {
+            exploration.record(transform.translation);
+        }
+    }
+}
+
+/// Remet à zéro l'exploration au début de chaque époque
+pub fn reset_exploration_scores(mut simulations: Query<&mut ExplorationScore, With<Simulation>>) {
+    for mut exploration in simulations.iter_mut() {
+        exploration.reset();
+    }
+}
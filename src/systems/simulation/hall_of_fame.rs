@@ -0,0 +1,58 @@
+use crate::components::genetics::genotype::Genotype;
+use bevy::prelude::*;
+use std::collections::BTreeMap;
+
+/// Nombre maximal de génomes conservés au panthéon
+pub const HALL_OF_FAME_CAPACITY: usize = 50;
+
+/// Meilleurs génomes de tous les temps, indexés par époque
+#[derive(Resource, Default)]
+pub struct HallOfFame {
+    pub entries: BTreeMap<usize, (Genotype, f32)>,
+    pub global_best: f32,
+}
+
+impl HallOfFame {
+    /// Ajoute le génome s'il bat le record absolu ; renvoie `true` s'il a été ajouté
+    pub fn consider(&mut self, epoch: usize, genotype: &Genotype, score: f32) -> bool {
+        if score <= self.global_best {
+            return false;
+        }
+
+        self.global_best = score;
+        self.entries.insert(epoch, (genotype.clone(), score));
+
+        // Éviction du plus faible score au-delà de la capacité
+        while self.entries.len() > HALL_OF_FAME_CAPACITY {
+            let Some(weakest) = self
+                .entries
+                .iter()
+                .min_by(|a, b| a.1.1.partial_cmp(&b.1.1).unwrap())
+                .map(|(epoch, _)| *epoch)
+            else {
+                break;
+            };
+            self.entries.remove(&weakest);
+        }
+
+        true
+    }
+
+    /// Remplace le contenu, par exemple lors d'une reprise de session
+    pub fn restore(&mut self, entries: impl IntoIterator<Item = (usize, Genotype, f32)>) {
+        self.clear();
+        for (epoch, genotype, score) in entries {
+            self.global_best = self.global_best.max(score);
+            self.entries.insert(epoch, (genotype, score));
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.global_best = 0.0;
+    }
+}
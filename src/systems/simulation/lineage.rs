@@ -0,0 +1,80 @@
+use bevy::prelude::*;
+use serde::Serialize;
+
+/// Un génome évalué lors d'une époque, avec les simulations parentes de l'époque précédente
+#[derive(Serialize, Clone, Debug)]
+pub struct LineageNode {
+    pub epoch: usize,
+    pub simulation_id: usize,
+    pub score: f32,
+    pub parents: Vec<usize>,
+}
+
+/// Arbre généalogique de toutes les époques terminées
+#[derive(Resource, Serialize, Default)]
+pub struct LineageForest {
+    pub nodes: Vec<LineageNode>,
+}
+
+impl LineageForest {
+    pub fn push(&mut self, node: LineageNode) {
+        self.nodes.push(node);
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.nodes.clear();
+    }
+
+    pub fn last_epoch(&self) -> Option<usize> {
+        self.nodes.last().map(|node| node.epoch)
+    }
+
+    /// Noeud d'une simulation pour une époque donnée
+    pub fn find(&self, epoch: usize, simulation_id: usize) -> Option<&LineageNode> {
+        self.nodes
+            .iter()
+            .find(|node| node.epoch == epoch && node.simulation_id == simulation_id)
+    }
+
+    /// Rendu texte indenté des ancêtres d'une simulation, sur `depth` époques
+    pub fn ancestry_lines(&self, epoch: usize, simulation_id: usize, depth: usize) -> Vec<String> {
+        let mut lines = Vec::new();
+        self.collect_ancestry(epoch, simulation_id, depth, 0, &mut lines);
+        lines
+    }
+
+    fn collect_ancestry(
+        &self,
+        epoch: usize,
+        simulation_id: usize,
+        depth: usize,
+        indent: usize,
+        lines: &mut Vec<String>,
+    ) {
+        let Some(node) = self.find(epoch, simulation_id) else {
+            return;
+        };
+
+        lines.push(format!(
+            "{}└ Époque {} · Sim #{} · score {:.1}",
+            "   ".repeat(indent),
+            node.epoch,
+            node.simulation_id + 1,
+            node.score
+        ));
+
+        if depth == 0 || node.epoch == 0 {
+            return;
+        }
+
+        let mut parents = node.parents.clone();
+        parents.dedup();
+        for parent in parents {
+            self.collect_ancestry(node.epoch - 1, parent, depth - 1, indent + 1, lines);
+        }
+    }
+}
@@ -1,5 +1,20 @@
+pub mod aging;
+pub mod clustering;
 pub mod collision;
+pub mod energy;
+pub mod exploration;
+pub mod hall_of_fame;
+pub mod lineage;
+pub mod mutation;
+pub mod novelty;
+pub mod objectives;
+pub mod obstacles;
 pub mod physics;
+pub mod plasticity;
+pub mod replay;
 pub mod reset;
+pub mod session_stats;
 pub mod spawning;
+pub mod spatial_grid;
+pub mod type_counts;
 pub mod visualizer_spawning;
\ No newline at end of file
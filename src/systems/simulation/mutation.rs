@@ -0,0 +1,66 @@
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::Score;
+use crate::resources::config::random::{RngStream, RunSeed};
+use crate::resources::config::simulation::SimulationParameters;
+use bevy::prelude::*;
+
+/// « Mutate Now » : mutation immédiate des génomes hors élite, sans attendre la fin d'époque
+#[derive(Event, Clone, Copy, Debug)]
+pub struct ForceMutateNow;
+
+/// Mute tout de suite les simulations hors élite (classées sur le score courant)
+pub fn force_mutation_system(
+    mut events: EventReader<ForceMutateNow>,
+    sim_params: Res<SimulationParameters>,
+    mut simulations: Query<(&SimulationId, &Score, &mut Genotype), With<Simulation>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    // Plusieurs clics dans la même frame : une seule mutation
+    if events.read().count() == 0 {
+        return;
+    }
+
+    let mut ranked: Vec<_> = simulations.iter_mut().collect();
+    ranked.sort_by(|a, b| b.1.get().partial_cmp(&a.1.get()).unwrap_or(std::cmp::Ordering::Equal));
+
+    let elite_count =
+        ((sim_params.simulation_count as f32 * sim_params.elite_ratio).ceil() as usize).max(1);
+    let rng = rng_stream.get(&run_seed, "force_mutation");
+    let mut mutated = 0;
+
+    for (_, _, genotype) in ranked.iter_mut().skip(elite_count) {
+        genotype.mutate(sim_params.mutation_rate, sim_params.genome_encoding, rng);
+        if sim_params.symmetric_forces {
+            genotype.enforce_symmetry();
+        }
+        mutated += 1;
+    }
+
+    info!(
+        "🧬 Mutation immédiate de {} simulation(s) hors élite (taux {:.3})",
+        mutated, sim_params.mutation_rate
+    );
+}
+
+/// Taux de mutation changé en cours de simulation : appliqué aux génomes à la fin de l'époque,
+/// avant la sélection, pour que les descendants en héritent
+pub fn apply_mutation_rate_change(
+    sim_params: Res<SimulationParameters>,
+    mut genotypes: Query<&mut Genotype, With<Simulation>>,
+    mut applied_rate: Local<Option<f32>>,
+) {
+    // Nouveau lancement ou reprise : les génomes gardent le taux qu'ils ont déjà
+    let first_epoch = sim_params.current_epoch == 0 || applied_rate.is_none();
+    if first_epoch || *applied_rate == Some(sim_params.mutation_rate) {
+        *applied_rate = Some(sim_params.mutation_rate);
+        return;
+    }
+
+    for mut genotype in genotypes.iter_mut() {
+        genotype.meta_mutation_rate = sim_params.mutation_rate;
+    }
+    *applied_rate = Some(sim_params.mutation_rate);
+    info!("🧬 Nouveau taux de mutation appliqué: {:.3}", sim_params.mutation_rate);
+}
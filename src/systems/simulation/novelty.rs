@@ -0,0 +1,148 @@
+use crate::components::entities::particle::{Particle, ParticleType};
+use crate::components::entities::simulation::Simulation;
+use crate::components::genetics::score::NoveltyScore;
+use crate::globals::{NOVELTY_ARCHIVE_ADDITIONS, NOVELTY_ARCHIVE_CAPACITY, NOVELTY_NEIGHBOURS};
+use crate::resources::config::simulation::{SimulationMode, SimulationParameters};
+use crate::resources::world::grid::GridParameters;
+use bevy::prelude::*;
+use std::collections::{HashMap, VecDeque};
+
+/// Caractérisations comportementales des générations passées
+#[derive(Resource, Default)]
+pub struct NoveltyArchive {
+    pub entries: VecDeque<Vec<f32>>,
+}
+
+impl NoveltyArchive {
+    /// Distance moyenne aux k plus proches voisins parmi l'archive et le reste de la population
+    pub fn novelty(&self, behaviour: &[f32], population: &[&[f32]]) -> f32 {
+        let mut distances: Vec<f32> = self
+            .entries
+            .iter()
+            .map(Vec::as_slice)
+            .chain(population.iter().copied())
+            .filter(|other| !std::ptr::eq(*other, behaviour))
+            .map(|other| behaviour_distance(behaviour, other))
+            .collect();
+
+        if distances.is_empty() {
+            return 0.0;
+        }
+
+        distances.sort_by(f32::total_cmp);
+        let neighbours = &distances[..distances.len().min(NOVELTY_NEIGHBOURS)];
+        neighbours.iter().sum::<f32>() / neighbours.len() as f32
+    }
+
+    pub fn add(&mut self, behaviour: Vec<f32>) {
+        if self.entries.len() >= NOVELTY_ARCHIVE_CAPACITY {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(behaviour);
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+}
+
+/// Distance euclidienne entre deux caractérisations (composantes absentes = 0)
+fn behaviour_distance(a: &[f32], b: &[f32]) -> f32 {
+    (0..a.len().max(b.len()))
+        .map(|i| {
+            let x = a.get(i).copied().unwrap_or(0.0);
+            let y = b.get(i).copied().unwrap_or(0.0);
+            (x - y).powi(2)
+        })
+        .sum::<f32>()
+        .sqrt()
+}
+
+/// Position moyenne de chaque type, normalisée dans [0, 1] sur chaque axe de la grille
+fn characterise(
+    positions: &[(usize, Vec3)],
+    particle_types: usize,
+    grid: &GridParameters,
+) -> Vec<f32> {
+    let dimensions = Vec3::new(grid.width, grid.height, grid.depth);
+    let mut sums = vec![(Vec3::ZERO, 0u32); particle_types];
+
+    for (particle_type, position) in positions {
+        if let Some((sum, count)) = sums.get_mut(*particle_type) {
+            *sum += *position;
+            *count += 1;
+        }
+    }
+
+    sums.iter()
+        .flat_map(|(sum, count)| {
+            let mean = if *count > 0 {
+                *sum / *count as f32
+            } else {
+                Vec3::ZERO
+            };
+            let normalized = (mean / dimensions + Vec3::splat(0.5)).clamp(Vec3::ZERO, Vec3::ONE);
+            normalized.to_array()
+        })
+        .collect()
+}
+
+/// Fin d'époque : nouveauté de chaque simulation, puis archivage des plus nouvelles.
+/// Tourne avant `reset_for_new_epoch`, tant que les particules sont à leur position finale.
+pub fn compute_novelty_scores(
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    mut archive: ResMut<NoveltyArchive>,
+    mut simulations: Query<(Entity, &mut NoveltyScore), With<Simulation>>,
+    particles: Query<(&Transform, &ParticleType, &ChildOf), With<Particle>>,
+) {
+    if !sim_params.use_novelty_search
+        || sim_params.simulation_mode != SimulationMode::EpochBased
+        || sim_params.current_epoch == 0
+    {
+        return;
+    }
+
+    let mut positions: HashMap<Entity, Vec<(usize, Vec3)>> = HashMap::new();
+    for (transform, particle_type, parent) in particles.iter() {
+        positions
+            .entry(parent.parent())
+            .or_default()
+            .push((particle_type.0, transform.translation));
+    }
+
+    let behaviours: Vec<(Entity, Vec<f32>)> = simulations
+        .iter()
+        .map(|(entity, _)| {
+            let simulation_positions = positions.get(&entity).map_or(&[][..], Vec::as_slice);
+            (
+                entity,
+                characterise(simulation_positions, sim_params.particle_types, &grid),
+            )
+        })
+        .collect();
+
+    let population: Vec<&[f32]> = behaviours.iter().map(|(_, b)| b.as_slice()).collect();
+    let mut novelties: Vec<(f32, usize)> = behaviours
+        .iter()
+        .enumerate()
+        .map(|(index, (_, behaviour))| (archive.novelty(behaviour, &population), index))
+        .collect();
+
+    for (novelty, index) in &novelties {
+        if let Ok((_, mut score)) = simulations.get_mut(behaviours[*index].0) {
+            score.0 = *novelty;
+        }
+    }
+
+    novelties.sort_by(|a, b| b.0.total_cmp(&a.0));
+    for (_, index) in novelties.iter().take(NOVELTY_ARCHIVE_ADDITIONS) {
+        archive.add(behaviours[*index].1.clone());
+    }
+
+    info!(
+        "✨ Nouveauté: max {:.3}, archive {} comportements",
+        novelties.first().map_or(0.0, |(novelty, _)| *novelty),
+        archive.entries.len()
+    );
+}
@@ -0,0 +1,69 @@
+use crate::components::entities::simulation::Simulation;
+use crate::components::genetics::score::{
+    ClusterScore, ExplorationScore, FoodEatenCount, FoodEfficiency, Score, SecondaryScore,
+    TotalDistanceTravelled,
+};
+use crate::resources::config::simulation::{ObjectiveFn, SimulationParameters};
+use bevy::prelude::*;
+
+/// Valeur d'un objectif pour une simulation
+fn objective_value(
+    objective: ObjectiveFn,
+    food_eaten: &FoodEatenCount,
+    exploration: &ExplorationScore,
+    cluster_score: &ClusterScore,
+    food_efficiency: f32,
+) -> f32 {
+    match objective {
+        ObjectiveFn::FoodEaten => food_eaten.0 as f32,
+        ObjectiveFn::ExplorationVolume => exploration.get(),
+        ObjectiveFn::ClusterSize => cluster_score.average_largest_cluster(),
+        ObjectiveFn::FoodEfficiency => food_efficiency,
+    }
+}
+
+pub fn multi_objective_enabled(sim_params: Res<SimulationParameters>) -> bool {
+    sim_params.is_multi_objective()
+}
+
+/// Recalcule les objectifs sélectionnés, lus par la sélection en fin d'époque
+pub fn update_secondary_scores(
+    sim_params: Res<SimulationParameters>,
+    mut simulations: Query<
+        (
+            &Score,
+            &FoodEatenCount,
+            &ExplorationScore,
+            &ClusterScore,
+            &TotalDistanceTravelled,
+            &mut SecondaryScore,
+        ),
+        With<Simulation>,
+    >,
+) {
+    for (score, food_eaten, exploration, cluster_score, distance, mut secondary) in
+        simulations.iter_mut()
+    {
+        let food_efficiency = distance.efficiency(score);
+        secondary.0 = sim_params
+            .objectives
+            .iter()
+            .map(|&objective| {
+                objective_value(objective, food_eaten, exploration, cluster_score, food_efficiency)
+            })
+            .collect();
+    }
+}
+
+/// Fin d'époque : efficacité de fourrage retenue pour l'affichage, distances remises à zéro
+pub fn record_food_efficiency(
+    mut simulations: Query<
+        (&Score, &mut TotalDistanceTravelled, &mut FoodEfficiency),
+        With<Simulation>,
+    >,
+) {
+    for (score, mut distance, mut efficiency) in simulations.iter_mut() {
+        efficiency.0 = distance.efficiency(score);
+        distance.0 = 0.0;
+    }
+}
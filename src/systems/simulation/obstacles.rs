@@ -0,0 +1,126 @@
+use crate::components::entities::obstacle::{Obstacle, ObstacleShape};
+use crate::components::entities::particle::Particle;
+use crate::resources::world::grid::GridParameters;
+use crate::systems::rendering::camera::camera_under_cursor;
+use crate::ui::panels::obstacles::ObstacleEditorUI;
+use bevy::prelude::*;
+use bevy::render::view::RenderLayers;
+use bevy::window::PrimaryWindow;
+use bevy_egui::EguiContexts;
+
+/// Obstacles d'une population chargée, créés à l'entrée dans le visualiseur
+#[derive(Resource, Default)]
+pub struct ObstacleLayout(pub Vec<(Vec3, ObstacleShape)>);
+
+/// Crée un obstacle semi-transparent sur la couche 0, visible dans toutes les vues
+pub fn spawn_obstacle(
+    commands: &mut Commands,
+    meshes: &mut Assets<Mesh>,
+    materials: &mut Assets<StandardMaterial>,
+    position: Vec3,
+    shape: ObstacleShape,
+) {
+    commands.spawn((
+        Obstacle { shape },
+        Transform::from_translation(position),
+        Mesh3d(meshes.add(shape.mesh())),
+        MeshMaterial3d(materials.add(StandardMaterial {
+            base_color: Color::srgba(0.6, 0.65, 0.75, 0.3),
+            alpha_mode: AlphaMode::Blend,
+            ..default()
+        })),
+        RenderLayers::layer(0),
+    ));
+}
+
+/// Position et forme de chaque obstacle, pour la physique et les sauvegardes
+pub fn collect_obstacles(
+    obstacles: &Query<(&Transform, &Obstacle), Without<Particle>>,
+) -> Vec<(Vec3, ObstacleShape)> {
+    obstacles
+        .iter()
+        .map(|(transform, obstacle)| (transform.translation, obstacle.shape))
+        .collect()
+}
+
+/// Pose un obstacle là où le rayon du curseur coupe le plan médian horizontal de la grille
+pub fn place_obstacle_on_click(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    editor: Res<ObstacleEditorUI>,
+    grid: Res<GridParameters>,
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    window: Single<&Window, With<PrimaryWindow>>,
+    cameras: Query<(Entity, &Camera, &GlobalTransform)>,
+    mut contexts: EguiContexts,
+) {
+    if !editor.show_panel || !editor.placing || !mouse_buttons.just_pressed(MouseButton::Left) {
+        return;
+    }
+
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.is_pointer_over_area())
+    {
+        return;
+    }
+
+    let (Some(physical_cursor), Some(cursor)) =
+        (window.physical_cursor_position(), window.cursor_position())
+    else {
+        return;
+    };
+
+    let Some((_, camera, camera_transform)) = camera_under_cursor(
+        cameras.iter().map(|(entity, camera, _)| (entity, camera)),
+        physical_cursor,
+    )
+    .and_then(|entity| cameras.get(entity).ok()) else {
+        return;
+    };
+
+    let Ok(ray) = camera.viewport_to_world(camera_transform, cursor) else {
+        return;
+    };
+    let Some(distance) = ray.intersect_plane(Vec3::ZERO, InfinitePlane3d::new(Vec3::Y)) else {
+        return;
+    };
+
+    let half_extents = Vec3::new(grid.width, grid.height, grid.depth) / 2.0;
+    let position = ray.get_point(distance).clamp(-half_extents, half_extents);
+
+    spawn_obstacle(
+        &mut commands,
+        &mut meshes,
+        &mut materials,
+        position,
+        editor.shape(),
+    );
+    info!(
+        "🧱 Obstacle placé en ({:.0}, {:.0}, {:.0})",
+        position.x, position.y, position.z
+    );
+}
+
+/// Recrée les obstacles de la population visualisée
+pub fn spawn_saved_obstacles(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+    layout: Option<Res<ObstacleLayout>>,
+) {
+    let Some(layout) = layout else {
+        return;
+    };
+
+    for (position, shape) in layout.0.iter() {
+        spawn_obstacle(&mut commands, &mut meshes, &mut materials, *position, *shape);
+    }
+}
+
+pub fn despawn_obstacles(mut commands: Commands, obstacles: Query<Entity, With<Obstacle>>) {
+    for entity in obstacles.iter() {
+        commands.entity(entity).despawn();
+    }
+}
@@ -1,15 +1,28 @@
-use crate::components::entities::food::Food;
+use crate::components::entities::food::{Food, FoodType};
+use crate::components::entities::obstacle::{Obstacle, ObstacleShape};
 use crate::components::entities::particle::{Particle, ParticleType, Velocity};
 use crate::components::entities::simulation::{Simulation, SimulationId};
 use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::TotalDistanceTravelled;
 use crate::globals::*;
-use crate::resources::config::simulation::{SimulationParameters, SimulationSpeed};
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::{
+    SimulationDimension, SimulationParameters, SimulationSpeed,
+};
 use crate::resources::world::boundary::BoundaryMode;
 use crate::resources::world::grid::GridParameters;
+use crate::resources::world::physics::PhysicsState;
+use crate::systems::simulation::obstacles::collect_obstacles;
+use crate::systems::simulation::spatial_grid::SpatialGrid;
 use bevy::prelude::*;
 
 pub fn physics_simulation_system(
+    time: Res<Time>,
+    mut physics_state: ResMut<PhysicsState>,
     sim_params: Res<SimulationParameters>,
+    global_config: Res<GlobalConfig>,
+    particle_config: Res<ParticleTypesConfig>,
     grid: Res<GridParameters>,
     boundary_mode: Res<BoundaryMode>,
     simulations: Query<(&SimulationId, &Genotype), With<Simulation>>,
@@ -23,22 +36,27 @@ pub fn physics_simulation_system(
         ),
         With<Particle>,
     >,
-    food_query: Query<(&Transform, &ViewVisibility), (With<Food>, Without<Particle>)>,
+    food_query: Query<(&Transform, &ViewVisibility, &FoodType), (With<Food>, Without<Particle>)>,
+    obstacle_query: Query<(&Transform, &Obstacle), Without<Particle>>,
+    mut distances: Query<&mut TotalDistanceTravelled, With<Simulation>>,
 ) {
     if sim_params.simulation_speed == SimulationSpeed::Paused {
+        physics_state.last_substeps = 0;
         return;
     }
 
-    let iterations = match sim_params.simulation_speed {
-        SimulationSpeed::Paused => 0,
-        SimulationSpeed::Normal => 1,
-        SimulationSpeed::Fast => 2,
-        SimulationSpeed::VeryFast => 4,
-    };
+    let obstacles = collect_obstacles(&obstacle_query);
+
+    let substeps = physics_state.accumulate(
+        time.delta_secs() * sim_params.simulation_speed.multiplier(),
+        global_config.physics_timestep,
+    );
 
-    for _iteration in 0..iterations {
+    for _substep in 0..substeps {
         let particle_forces = calculate_forces(
             &sim_params,
+            &global_config,
+            &particle_config,
             &grid,
             &boundary_mode,
             &simulations,
@@ -46,18 +64,31 @@ pub fn physics_simulation_system(
             &food_query,
         );
 
-        apply_physics_step(
+        let travelled = apply_physics_step(
             &grid,
             &boundary_mode,
+            sim_params.dimension,
+            sim_params.gravity,
+            sim_params.velocity_half_life_scale(),
             &mut particles,
             &particle_forces,
-            &sim_params,
+            &particle_config,
+            &global_config,
+            &obstacles,
         );
+
+        for (simulation, distance) in travelled {
+            if let Ok(mut total) = distances.get_mut(simulation) {
+                total.0 += distance;
+            }
+        }
     }
 }
 
 fn calculate_forces(
     sim_params: &SimulationParameters,
+    global_config: &GlobalConfig,
+    particle_config: &ParticleTypesConfig,
     grid: &GridParameters,
     boundary_mode: &BoundaryMode,
     simulations: &Query<(&SimulationId, &Genotype), With<Simulation>>,
@@ -71,19 +102,69 @@ fn calculate_forces(
         ),
         With<Particle>,
     >,
-    food_query: &Query<(&Transform, &ViewVisibility), (With<Food>, Without<Particle>)>,
+    food_query: &Query<(&Transform, &ViewVisibility, &FoodType), (With<Food>, Without<Particle>)>,
 ) -> std::collections::HashMap<Entity, Vec3> {
     let mut genotypes_cache = std::collections::HashMap::new();
     for (sim_id, genotype) in simulations.iter() {
         genotypes_cache.insert(sim_id.0, genotype);
     }
 
-    let food_positions: Vec<Vec3> = food_query
+    let mut food_positions: Vec<(Vec3, usize)> = food_query
         .iter()
-        .filter(|(_, visibility)| visibility.get())
-        .map(|(transform, _)| transform.translation)
+        .filter(|(_, visibility, _)| visibility.get())
+        .map(|(transform, _, food_type)| (transform.translation, food_type.0))
         .collect();
 
+    // Sources d'interaction : (entité, position, type, simulation)
+    let mut sources: Vec<(Entity, Vec3, usize, usize)> = particles
+        .iter()
+        .filter_map(|(entity, transform, _, particle_type, parent)| {
+            let (sim_id, _) = simulations.get(parent.parent()).ok()?;
+            Some((entity, transform.translation, particle_type.0, sim_id.0))
+        })
+        .collect();
+
+    // Particules fantômes : les images de l'autre côté des murs remplacent le plus court chemin
+    let use_ghosts =
+        sim_params.use_ghost_particles && matches!(*boundary_mode, BoundaryMode::Teleport);
+    if use_ghosts {
+        let ghost_margin = sim_params.max_force_range / 2.0;
+
+        let ghost_sources: Vec<_> = sources
+            .iter()
+            .flat_map(|&(entity, position, particle_type, sim_id)| {
+                grid.ghost_images(position, ghost_margin)
+                    .into_iter()
+                    .map(move |ghost| (entity, ghost, particle_type, sim_id))
+            })
+            .collect();
+        sources.extend(ghost_sources);
+
+        let ghost_food: Vec<_> = food_positions
+            .iter()
+            .flat_map(|&(position, food_type)| {
+                grid.ghost_images(position, ghost_margin)
+                    .into_iter()
+                    .map(move |ghost| (ghost, food_type))
+            })
+            .collect();
+        food_positions.extend(ghost_food);
+    }
+
+    let mut spatial_grid = SpatialGrid::new(grid, sim_params.spatial_grid_resolution);
+    for (index, &(_, position, _, _)) in sources.iter().enumerate() {
+        spatial_grid.insert(position, index);
+    }
+    // Tore sans fantômes : les voisins de l'autre côté des murs sont dans les cellules opposées
+    let wrap_neighbours = matches!(*boundary_mode, BoundaryMode::Teleport) && !use_ghosts;
+
+    let direction_to = |from: Vec3, to: Vec3| match *boundary_mode {
+        BoundaryMode::Teleport if !use_ghosts => torus_direction_vector(from, to, grid),
+        _ => to - from,
+    };
+
+    let oscillation_factor = sim_params.force_oscillation_factor();
+
     let mut forces = std::collections::HashMap::new();
 
     for (entity_a, transform, _, particle_type, parent) in particles.iter() {
@@ -97,24 +178,26 @@ fn calculate_forces(
         if let Some(genotype) = genotypes_cache.get(&sim_id.0) {
             // Forces avec autres particules
             let mut interaction_count = 0;
-            for (entity_b, other_transform, _, other_type, other_parent) in particles.iter() {
+            for index in
+                spatial_grid.neighbours(position, sim_params.max_force_range, wrap_neighbours)
+            {
+                let (entity_b, other_position, other_type, other_sim_id) = sources[index];
+                // Une particule n'interagit ni avec elle-même ni avec ses fantômes
                 if entity_a == entity_b || interaction_count >= 100 {
                     continue;
                 }
 
-                let Ok((other_sim_id, _)) = simulations.get(other_parent.parent()) else {
+                // Les autres simulations n'agissent qu'en mode expérimental, atténuées ;
+                // la force reste dictée par le génome de la particule qui la subit
+                let source_scale = if other_sim_id == sim_id.0 {
+                    1.0
+                } else if sim_params.inter_simulation_interaction {
+                    sim_params.inter_sim_force_scale
+                } else {
                     continue;
                 };
-                if other_sim_id.0 != sim_id.0 {
-                    continue;
-                }
 
-                let distance_vec = match *boundary_mode {
-                    BoundaryMode::Teleport => {
-                        torus_direction_vector(position, other_transform.translation, grid)
-                    }
-                    BoundaryMode::Bounce => other_transform.translation - position,
-                };
+                let distance_vec = direction_to(position, other_position);
 
                 let distance_squared = distance_vec.dot(distance_vec);
                 if distance_squared > sim_params.max_force_range * sim_params.max_force_range
@@ -123,11 +206,25 @@ fn calculate_forces(
                     continue;
                 }
 
+                let min_r = repulsion_distance(
+                    particle_config.get_radius_for_type(particle_type.0),
+                    particle_config.get_radius_for_type(other_type),
+                );
+                let gene = genotype.expressed_force(
+                    particle_type.0,
+                    other_type,
+                    sim_params.gene_expression_threshold,
+                );
+                // Gène silencieux : seule la répulsion à courte portée subsiste
+                if gene == 0.0 && distance_squared >= min_r * min_r {
+                    continue;
+                }
+
                 interaction_count += 1;
 
-                let min_r = sim_params.particle_types as f32 * PARTICLE_RADIUS;
-                let attraction =
-                    genotype.get_force(particle_type.0, other_type.0) * FORCE_SCALE_FACTOR;
+                let attraction = gene
+                    * global_config.force_scale_factor
+                    * oscillation_factor;
                 let acceleration = calculate_acceleration(
                     min_r,
                     distance_vec,
@@ -135,25 +232,25 @@ fn calculate_forces(
                     sim_params.max_force_range,
                 );
 
-                total_force += acceleration * sim_params.max_force_range;
+                total_force += acceleration * sim_params.max_force_range * source_scale;
             }
 
-            // Forces avec nourriture
-            let food_force = genotype.get_food_force(particle_type.0) * FORCE_SCALE_FACTOR;
-            if food_force.abs() > 0.001 {
-                for food_pos in &food_positions {
-                    let distance_vec = match *boundary_mode {
-                        BoundaryMode::Teleport => torus_direction_vector(position, *food_pos, grid),
-                        BoundaryMode::Bounce => *food_pos - position,
-                    };
-
-                    let distance = distance_vec.length();
-                    if distance > 0.001 && distance < sim_params.max_force_range {
-                        let force_direction = distance_vec.normalize();
-                        let distance_factor = ((FOOD_RADIUS * 2.0) / distance).min(1.0).powf(0.5);
-                        let force_magnitude = food_force * distance_factor;
-                        total_force += force_direction * force_magnitude;
-                    }
+            // Forces avec nourriture (selon le type de chaque nourriture)
+            for (food_pos, food_type) in &food_positions {
+                let food_force = genotype.get_food_force(particle_type.0, *food_type)
+                    * global_config.force_scale_factor;
+                if food_force.abs() <= 0.001 {
+                    continue;
+                }
+
+                let distance_vec = direction_to(position, *food_pos);
+
+                let distance = distance_vec.length();
+                if distance > 0.001 && distance < sim_params.max_force_range {
+                    let force_direction = distance_vec.normalize();
+                    let distance_factor = ((FOOD_RADIUS * 2.0) / distance).min(1.0).powf(0.5);
+                    let force_magnitude = food_force * distance_factor;
+                    total_force += force_direction * force_magnitude;
                 }
             }
         }
@@ -167,6 +264,9 @@ fn calculate_forces(
 fn apply_physics_step(
     grid: &GridParameters,
     boundary_mode: &BoundaryMode,
+    dimension: SimulationDimension,
+    gravity: Option<Vec3>,
+    half_life_scale: f32,
     particles: &mut Query<
         (
             Entity,
@@ -178,20 +278,158 @@ fn apply_physics_step(
         With<Particle>,
     >,
     forces: &std::collections::HashMap<Entity, Vec3>,
-    sim_params: &SimulationParameters,
-) {
-    for (entity, mut transform, mut velocity, _, _) in particles.iter_mut() {
+    particle_config: &ParticleTypesConfig,
+    global_config: &GlobalConfig,
+    obstacles: &[(Vec3, ObstacleShape)],
+) -> std::collections::HashMap<Entity, f32> {
+    let timestep = global_config.physics_timestep;
+    let max_velocity = global_config.max_velocity;
+    // Distance parcourue par simulation (entité parente) pendant ce pas
+    let mut travelled = std::collections::HashMap::new();
+
+    for (entity, mut transform, mut velocity, particle_type, parent) in particles.iter_mut() {
         if let Some(force) = forces.get(&entity) {
-            velocity.0 += *force * PHYSICS_TIMESTEP;
-            velocity.0 *= (0.5_f32).powf(PHYSICS_TIMESTEP / sim_params.velocity_half_life);
+            let mut force = *force;
+            if let BoundaryMode::SoftWall { stiffness, decay } = *boundary_mode {
+                force += grid.soft_wall_force(transform.translation, stiffness, decay);
+            }
 
-            if velocity.0.length() > MAX_VELOCITY {
-                velocity.0 = velocity.0.normalize() * MAX_VELOCITY;
+            velocity.0 += force * timestep;
+            if let Some(gravity) = gravity {
+                velocity.0 += gravity * timestep;
+            }
+            // Demi-vie par type, modulée par le planning de recuit
+            let half_life = particle_config.get_velocity_half_life_for_type(particle_type.0)
+                * half_life_scale;
+            velocity.0 *= (0.5_f32).powf(timestep / half_life);
+
+            if velocity.0.length() > max_velocity {
+                velocity.0 = velocity.0.normalize() * max_velocity;
             }
         }
 
-        transform.translation += velocity.0 * PHYSICS_TIMESTEP;
+        // Déplacement avant les bords : une téléportation torique n'est pas un trajet
+        let displacement = velocity.0 * timestep;
+        transform.translation += displacement;
+        *travelled.entry(parent.parent()).or_insert(0.0) += displacement.length();
+        // Sans sol, la gravité ferait boucler les particules à travers la grille torique
+        if let (Some(gravity), BoundaryMode::Teleport) = (gravity, *boundary_mode) {
+            grid.apply_floor_bounce(&mut transform.translation, &mut velocity.0, gravity);
+        }
         grid.apply_bounds(&mut transform.translation, &mut velocity.0, *boundary_mode);
+
+        let particle_radius = particle_config.get_radius_for_type(particle_type.0);
+        for (center, shape) in obstacles {
+            shape.resolve_collision(
+                *center,
+                &mut transform.translation,
+                &mut velocity.0,
+                particle_radius,
+            );
+        }
+
+        // Mode 2D : tout reste dans le plan XY
+        if dimension.is_flat() {
+            transform.translation.z = 0.0;
+            velocity.0.z = 0.0;
+        }
+    }
+
+    travelled
+}
+
+/// Sphères dures : choc entre particules d'une même simulation en contact, de restitution
+/// `COLLISION_DAMPING` (masses égales), puis séparation des sphères qui se chevauchent
+pub fn resolve_particle_collisions(
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    particle_config: Res<ParticleTypesConfig>,
+    mut particles: Query<
+        (&mut Transform, &mut Velocity, &ParticleType, &ChildOf),
+        With<Particle>,
+    >,
+) {
+    if sim_params.simulation_speed == SimulationSpeed::Paused {
+        return;
+    }
+
+    // (position, vitesse, rayon, simulation)
+    let mut bodies: Vec<(Vec3, Vec3, f32, Entity)> = particles
+        .iter()
+        .map(|(transform, velocity, particle_type, parent)| {
+            (
+                transform.translation,
+                velocity.0,
+                particle_config.get_radius_for_type(particle_type.0),
+                parent.parent(),
+            )
+        })
+        .collect();
+
+    let mut spatial_grid = SpatialGrid::new(&grid, sim_params.spatial_grid_resolution);
+    let mut max_radius: f32 = 0.0;
+    for (index, &(position, _, radius, _)) in bodies.iter().enumerate() {
+        spatial_grid.insert(position, index);
+        max_radius = max_radius.max(radius);
+    }
+
+    for a in 0..bodies.len() {
+        // Pas de contact à travers les murs du tore
+        let neighbours: Vec<usize> = spatial_grid
+            .neighbours(bodies[a].0, max_radius * 2.0, false)
+            .filter(|&b| b > a && bodies[b].3 == bodies[a].3)
+            .collect();
+
+        for b in neighbours {
+            let (position_a, velocity_a, radius_a, _) = bodies[a];
+            let (position_b, velocity_b, radius_b, _) = bodies[b];
+
+            let offset = position_b - position_a;
+            let distance = offset.length();
+            let contact = radius_a + radius_b;
+            if distance >= contact || distance < MIN_DISTANCE {
+                continue;
+            }
+
+            let normal = offset / distance;
+            let closing_speed = (velocity_a - velocity_b).dot(normal);
+            if closing_speed > 0.0 {
+                let impulse = normal * closing_speed * (1.0 + COLLISION_DAMPING) / 2.0;
+                bodies[a].1 -= impulse;
+                bodies[b].1 += impulse;
+            }
+
+            let correction = normal * (contact - distance) / 2.0;
+            bodies[a].0 -= correction;
+            bodies[b].0 += correction;
+        }
+    }
+
+    for ((mut transform, mut velocity, _, _), (position, new_velocity, _, _)) in
+        particles.iter_mut().zip(bodies)
+    {
+        transform.translation = position;
+        velocity.0 = new_velocity;
+    }
+}
+
+/// Distance sous laquelle deux particules se repoussent, quelle que soit leur attraction
+pub fn repulsion_distance(radius_a: f32, radius_b: f32) -> f32 {
+    (radius_a + radius_b) / 2.0
+}
+
+/// Profil de force en fonction de la distance : répulsion linéaire sous `min_r`,
+/// puis triangle d'attraction culminant à mi-chemin entre `min_r` et la portée
+pub fn force_profile(min_r: f32, distance: f32, attraction: f32, max_force_range: f32) -> f32 {
+    let normalized_dist = distance / max_force_range;
+    let min_r_normalized = min_r / max_force_range;
+
+    if normalized_dist < min_r_normalized {
+        normalized_dist / min_r_normalized - 1.0
+    } else {
+        attraction
+            * (1.0
+                - (1.0 + min_r_normalized - 2.0 * normalized_dist).abs() / (1.0 - min_r_normalized))
     }
 }
 
@@ -206,19 +444,9 @@ fn calculate_acceleration(
         return Vec3::ZERO;
     }
 
-    let normalized_pos = relative_pos / max_force_range;
-    let normalized_dist = dist / max_force_range;
-    let min_r_normalized = min_r / max_force_range;
-
-    let force = if normalized_dist < min_r_normalized {
-        normalized_dist / min_r_normalized - 1.0
-    } else {
-        attraction
-            * (1.0
-                - (1.0 + min_r_normalized - 2.0 * normalized_dist).abs() / (1.0 - min_r_normalized))
-    };
+    let force = force_profile(min_r, dist, attraction, max_force_range);
 
-    normalized_pos * force / normalized_dist
+    relative_pos / dist * force
 }
 
 fn torus_direction_vector(from: Vec3, to: Vec3, grid: &GridParameters) -> Vec3 {
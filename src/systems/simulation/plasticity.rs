@@ -0,0 +1,130 @@
+use crate::components::entities::particle::{Offspring, Particle, ParticleType};
+use crate::components::entities::simulation::Simulation;
+use crate::resources::config::particle_types::{ParticleTypeAssets, ParticleTypesConfig};
+use crate::resources::config::random::{RngStream, RunSeed};
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::physics::PhysicsState;
+use crate::systems::rendering::particle_palette::OwnMaterial;
+use crate::systems::simulation::reset::EpochHistoryResource;
+use bevy::prelude::*;
+use rand::Rng;
+
+/// Plasticité phénotypique : à chaque pas, une particule peut changer de type au hasard.
+/// Elle prend alors le mesh (rayon) et le matériau partagés de son nouveau type.
+pub fn particle_type_migration_system(
+    mut commands: Commands,
+    sim_params: Res<SimulationParameters>,
+    physics_state: Res<PhysicsState>,
+    type_assets: Res<ParticleTypeAssets>,
+    mut epoch_history: ResMut<EpochHistoryResource>,
+    mut particles: Query<
+        (
+            Entity,
+            &mut ParticleType,
+            &mut Mesh3d,
+            &mut MeshMaterial3d<StandardMaterial>,
+            Has<OwnMaterial>,
+        ),
+        With<Particle>,
+    >,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    // Probabilité par pas physique : sur n pas simulés cette frame, 1 − (1 − p)^n
+    let rate = sim_params.type_migration_rate.clamp(0.0, 1.0);
+    let probability = 1.0 - (1.0 - rate).powi(physics_state.last_substeps as i32);
+    let type_count = type_assets.materials.len().min(type_assets.meshes.len());
+    if probability <= 0.0 || type_count < 2 {
+        return;
+    }
+
+    let rng = rng_stream.get(&run_seed, "type_migration");
+    let mut changes = 0;
+
+    for (entity, mut particle_type, mut mesh, mut material, has_own_material) in
+        particles.iter_mut()
+    {
+        if rng.random::<f32>() >= probability {
+            continue;
+        }
+
+        let new_type = rng.random_range(0..type_count);
+        if new_type == particle_type.0 {
+            continue;
+        }
+
+        particle_type.0 = new_type;
+        mesh.0 = type_assets.meshes[new_type].clone();
+        material.0 = type_assets.materials[new_type].clone();
+        // Coloration par vitesse : la copie propre sera refaite à partir du nouveau type
+        if has_own_material {
+            commands.entity(entity).remove::<OwnMaterial>();
+        }
+        changes += 1;
+    }
+
+    if changes > 0 {
+        epoch_history.pending_type_changes += changes;
+    }
+}
+
+/// Début d'époque : chaque particule d'origine retrouve le type de sa création
+/// (les types sont créés par blocs de `particles_per_type`), avant le repositionnement
+pub fn restore_particle_types(
+    mut commands: Commands,
+    particle_config: Res<ParticleTypesConfig>,
+    sim_params: Res<SimulationParameters>,
+    type_assets: Res<ParticleTypeAssets>,
+    simulations: Query<&Children, With<Simulation>>,
+    mut particles: Query<
+        (
+            Entity,
+            &mut ParticleType,
+            &mut Mesh3d,
+            &mut MeshMaterial3d<StandardMaterial>,
+            Has<OwnMaterial>,
+        ),
+        (With<Particle>, Without<Offspring>),
+    >,
+) {
+    let type_count = particle_config
+        .type_count
+        .min(type_assets.materials.len())
+        .min(type_assets.meshes.len());
+    if type_count == 0 {
+        return;
+    }
+
+    let particles_per_type =
+        (sim_params.particle_count + particle_config.type_count - 1) / particle_config.type_count;
+    let mut restored = 0;
+
+    for children in simulations.iter() {
+        let mut particle_index = 0;
+        for child in children.iter() {
+            let Ok((entity, mut particle_type, mut mesh, mut material, has_own_material)) =
+                particles.get_mut(child)
+            else {
+                continue;
+            };
+
+            let original_type = (particle_index / particles_per_type.max(1)).min(type_count - 1);
+            particle_index += 1;
+            if particle_type.0 == original_type {
+                continue;
+            }
+
+            particle_type.0 = original_type;
+            mesh.0 = type_assets.meshes[original_type].clone();
+            material.0 = type_assets.materials[original_type].clone();
+            if has_own_material {
+                commands.entity(entity).remove::<OwnMaterial>();
+            }
+            restored += 1;
+        }
+    }
+
+    if restored > 0 {
+        info!("🔀 {} particules ramenées à leur type d'origine", restored);
+    }
+}
@@ -0,0 +1,118 @@
+use crate::components::entities::particle::{Particle, Velocity};
+use crate::globals::MAX_RECORDED_FRAMES;
+use bevy::prelude::*;
+use std::collections::VecDeque;
+
+/// Position et vitesse de chaque particule, triées par entité
+pub type ParticleSnapshot = Vec<(Vec3, Vec3)>;
+
+/// Enregistrement image par image du visualiseur, rejouable au curseur
+#[derive(Resource, Default)]
+pub struct SimulationRecorder {
+    /// Capture une image à chaque frame tant que la physique tourne
+    pub recording: bool,
+    pub frames: VecDeque<ParticleSnapshot>,
+    /// Image affichée en mode rejeu
+    pub cursor: usize,
+    /// Mode rejeu : la physique est suspendue et les particules suivent l'enregistrement
+    pub replaying: bool,
+    /// Lecture automatique de l'enregistrement
+    pub playing: bool,
+}
+
+impl SimulationRecorder {
+    pub fn has_recording(&self) -> bool {
+        !self.frames.is_empty()
+    }
+
+    pub fn last_frame(&self) -> usize {
+        self.frames.len().saturating_sub(1)
+    }
+
+    /// Passe en mode rejeu sur l'image `frame`
+    pub fn seek(&mut self, frame: usize) {
+        self.cursor = frame.min(self.last_frame());
+        self.replaying = true;
+        self.recording = false;
+    }
+
+    /// Quitte le rejeu : la physique reprend depuis l'image affichée
+    pub fn resume_live(&mut self) {
+        self.replaying = false;
+        self.playing = false;
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    fn push(&mut self, snapshot: ParticleSnapshot) {
+        if self.frames.len() >= MAX_RECORDED_FRAMES {
+            self.frames.pop_front();
+        }
+        self.frames.push_back(snapshot);
+    }
+}
+
+/// Condition : la physique ne tourne pas pendant le rejeu
+pub fn replay_inactive(recorder: Res<SimulationRecorder>) -> bool {
+    !recorder.replaying
+}
+
+/// Capture l'état des particules à chaque frame
+pub fn record_simulation_frame(
+    mut recorder: ResMut<SimulationRecorder>,
+    particles: Query<(Entity, &Transform, &Velocity), With<Particle>>,
+) {
+    if !recorder.recording || recorder.replaying {
+        return;
+    }
+
+    let mut entries: Vec<_> = particles.iter().collect();
+    entries.sort_by_key(|(entity, _, _)| *entity);
+
+    recorder.push(
+        entries
+            .into_iter()
+            .map(|(_, transform, velocity)| (transform.translation, velocity.0))
+            .collect(),
+    );
+}
+
+/// Avance la lecture et applique l'image courante aux particules
+pub fn apply_replay_frame(
+    mut recorder: ResMut<SimulationRecorder>,
+    mut particles: Query<(Entity, &mut Transform, &mut Velocity), With<Particle>>,
+) {
+    if !recorder.replaying || !recorder.has_recording() {
+        return;
+    }
+
+    if recorder.playing {
+        if recorder.cursor < recorder.last_frame() {
+            recorder.cursor += 1;
+        } else {
+            recorder.playing = false;
+        }
+    }
+
+    let Some(snapshot) = recorder.frames.get(recorder.cursor) else {
+        return;
+    };
+
+    let mut entries: Vec<_> = particles.iter_mut().collect();
+    entries.sort_by_key(|(entity, _, _)| *entity);
+
+    // Les particules mortes depuis la capture décalent la correspondance : on s'arrête au plus court
+    for ((_, transform, velocity), (position, recorded_velocity)) in
+        entries.iter_mut().zip(snapshot.iter())
+    {
+        transform.translation = *position;
+        velocity.0 = *recorded_velocity;
+    }
+}
+
+/// Un nouvel enregistrement par visualisation
+pub fn clear_recording(mut recorder: ResMut<SimulationRecorder>) {
+    recorder.clear();
+}
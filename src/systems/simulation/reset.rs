@@ -1,37 +1,256 @@
 use crate::components::entities::food::{Food, FoodRespawnTimer};
 use crate::components::entities::particle::{Particle, ParticleType, Velocity};
 use crate::components::entities::simulation::{Simulation, SimulationId};
-use crate::components::genetics::genotype::Genotype;
-use crate::components::genetics::score::Score;
+use crate::components::genetics::genotype::{
+    average_entropy, calculate_genotype_distance, Genotype,
+};
+use crate::components::genetics::score::{
+    ClusterScore, ExplorationScore, FoodEfficiency, NoveltyScore, Score, SecondaryScore,
+    TotalDistanceTravelled,
+};
 use crate::resources::config::food::FoodParameters;
 use crate::resources::config::particle_types::ParticleTypesConfig;
-use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::config::simulation::{
+    CrossoverOperator, ObjectiveFn, SimulationMode, SimulationParameters,
+};
 use crate::resources::world::grid::GridParameters;
+use crate::systems::simulation::hall_of_fame::HallOfFame;
+use crate::systems::simulation::lineage::{LineageForest, LineageNode};
 use crate::systems::simulation::spawning::FoodPositions;
+use crate::resources::config::random::{RngStream, RunSeed};
 use bevy::prelude::*;
 use rand::Rng;
+use std::collections::{HashMap, VecDeque};
 
 #[derive(Clone)]
 struct ScoredGenome {
+    simulation_id: usize,
     genotype: Genotype,
     score: f32,
+    objectives: Vec<f32>, // Valeurs des objectifs sélectionnés (SecondaryScore)
+    novelty: f32,
     generation: usize,
 }
 
-#[derive(Default)]
-struct EpochStats {
-    best_score: f32,
-    worst_score: f32,
-    average_score: f32,
-    median_score: f32,
-    std_deviation: f32,
-    improvement: f32,
+impl ScoredGenome {
+    /// Copie du génome dont le seul parent est cette simulation
+    fn cloned_with_lineage(&self) -> Genotype {
+        let mut genotype = self.genotype.clone();
+        genotype.lineage = Some(vec![self.simulation_id, self.simulation_id]);
+        genotype
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+pub struct EpochStats {
+    pub epoch: usize,
+    pub best_score: f32,
+    pub worst_score: f32,
+    pub average_score: f32,
+    pub median_score: f32,
+    pub std_deviation: f32,
+    pub improvement: f32,
+    /// Entropie moyenne des matrices de forces (bits)
+    pub average_entropy: f32,
+}
+
+/// Historique des statistiques de chaque époque terminée
+#[derive(Resource, Default)]
+pub struct EpochHistoryResource {
+    pub best_scores: Vec<f32>,
+    pub stats: Vec<EpochStats>,
+    pub stagnation_events: usize,
+    /// Index des générations où une stagnation a été détectée
+    pub stagnation_epochs: Vec<usize>,
+    /// Changements de type (plasticité) de chaque époque, alignés sur `stats`
+    pub type_changes: Vec<usize>,
+    /// Changements de type de l'époque en cours
+    pub pending_type_changes: usize,
+}
+
+impl EpochHistoryResource {
+    pub fn push(&mut self, stats: EpochStats) {
+        self.best_scores.push(stats.best_score);
+        self.stats.push(stats);
+        self.type_changes.push(std::mem::take(&mut self.pending_type_changes));
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.stats.is_empty()
+    }
+
+    /// Marque la dernière génération enregistrée comme stagnante
+    pub fn record_stagnation(&mut self) {
+        self.stagnation_events += 1;
+        self.stagnation_epochs.push(self.stats.len().saturating_sub(1));
+    }
+
+    pub fn clear(&mut self) {
+        self.best_scores.clear();
+        self.stats.clear();
+        self.stagnation_events = 0;
+        self.stagnation_epochs.clear();
+        self.type_changes.clear();
+        self.pending_type_changes = 0;
+    }
+}
+
+/// Nombre de meilleurs scores utilisés pour estimer la tendance
+const CONVERGENCE_WINDOW: usize = 10;
+/// Gain par époque en dessous duquel l'évolution est considérée en convergence
+const CONVERGENCE_SLOPE_THRESHOLD: f32 = 0.1;
+
+/// Tendance des meilleurs scores : régression linéaire sur les dernières époques
+#[derive(Resource, Default)]
+pub struct ConvergenceEstimator {
+    window: VecDeque<f32>,
+    slope: Option<f32>,
+    seen: usize, // Époques de l'historique déjà prises en compte
+}
+
+impl ConvergenceEstimator {
+    fn push(&mut self, best_score: f32) {
+        if self.window.len() >= CONVERGENCE_WINDOW {
+            self.window.pop_front();
+        }
+        self.window.push_back(best_score);
+
+        // Estimation seulement une fois la fenêtre pleine
+        self.slope = (self.window.len() == CONVERGENCE_WINDOW).then(|| self.regression_slope());
+    }
+
+    /// Pente des moindres carrés : (nΣxy − ΣxΣy) / (nΣx² − (Σx)²)
+    fn regression_slope(&self) -> f32 {
+        let n = self.window.len() as f32;
+        let (sum_x, sum_y, sum_xy, sum_x2) = self.window.iter().enumerate().fold(
+            (0.0, 0.0, 0.0, 0.0),
+            |(sx, sy, sxy, sx2), (i, &y)| {
+                let x = i as f32;
+                (sx + x, sy + y, sxy + x * y, sx2 + x * x)
+            },
+        );
+
+        let denominator = n * sum_x2 - sum_x * sum_x;
+        if denominator.abs() < f32::EPSILON {
+            0.0
+        } else {
+            (n * sum_xy - sum_x * sum_y) / denominator
+        }
+    }
+
+    /// Texte de la barre du haut ; None tant que la fenêtre n'est pas pleine
+    pub fn label(&self) -> Option<String> {
+        let slope = self.slope?;
+        if slope >= CONVERGENCE_SLOPE_THRESHOLD {
+            return Some(format!("~+{:.1}/epoch", slope));
+        }
+
+        // Plateau visé : 5 % au-dessus du meilleur score de la fenêtre
+        let target = self.window.iter().copied().fold(f32::MIN, f32::max) * 1.05;
+        let current_best = self.window.back().copied().unwrap_or(0.0);
+        let remaining = if slope > 0.0 {
+            format!("{:.0}", ((target - current_best) / slope).max(0.0).ceil())
+        } else {
+            "∞".to_string()
+        };
+        Some(format!("Converging (est. {} more epochs)", remaining))
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Ajoute les meilleurs scores des époques terminées ; repart de zéro avec l'historique
+pub fn update_convergence_estimator(
+    epoch_history: Res<EpochHistoryResource>,
+    mut estimator: ResMut<ConvergenceEstimator>,
+) {
+    let recorded = epoch_history.best_scores.len();
+    if recorded < estimator.seen {
+        estimator.clear();
+    }
+
+    for index in estimator.seen..recorded {
+        estimator.push(epoch_history.best_scores[index]);
+    }
+    estimator.seen = recorded;
+}
+
+/// Diversité de la population (écart-type / moyenne des scores) à chaque époque
+#[derive(Resource, Default)]
+pub struct PopulationDiversityHistory(pub Vec<f32>);
+
+/// Durée d'affichage du résumé de fin d'époque (secondes)
+const EPOCH_SUMMARY_DURATION: f32 = 5.0;
+
+/// Résumé de la dernière époque terminée, affiché quelques secondes dans l'interface
+#[derive(Resource)]
+pub struct EpochEndSummary {
+    pub epoch: usize,
+    pub best_score: f32,
+    pub worst_score: f32,
+    pub average_score: f32,
+    pub winner: usize,
+    pub is_fresh: bool,
+    pub timer: Timer,
+}
+
+impl Default for EpochEndSummary {
+    fn default() -> Self {
+        Self {
+            epoch: 0,
+            best_score: 0.0,
+            worst_score: 0.0,
+            average_score: 0.0,
+            winner: 0,
+            is_fresh: false,
+            timer: Timer::from_seconds(EPOCH_SUMMARY_DURATION, TimerMode::Once),
+        }
+    }
+}
+
+impl EpochEndSummary {
+    fn publish(&mut self, stats: &EpochStats, winner: usize) {
+        self.epoch = stats.epoch;
+        self.best_score = stats.best_score;
+        self.worst_score = stats.worst_score;
+        self.average_score = stats.average_score;
+        self.winner = winner;
+        self.is_fresh = true;
+        self.timer.reset();
+    }
+
+    pub fn dismiss(&mut self) {
+        self.is_fresh = false;
+    }
+}
+
+/// Score de chaque simulation à chaque époque (ID de simulation → scores)
+#[derive(Resource, Default)]
+pub struct FitnessHistory(pub HashMap<usize, Vec<f32>>);
+
+impl FitnessHistory {
+    pub fn scores(&self, simulation_id: usize) -> &[f32] {
+        self.0.get(&simulation_id).map_or(&[], |scores| scores.as_slice())
+    }
+}
+
+impl EpochStats {
+    /// Coefficient de variation des scores, 0 si la moyenne est nulle
+    pub fn diversity(&self) -> f32 {
+        if self.average_score > 0.0 {
+            self.std_deviation / self.average_score
+        } else {
+            0.0
+        }
+    }
 }
 
 pub fn reset_for_new_epoch(
     mut commands: Commands,
     grid: Res<GridParameters>,
-    sim_params: Res<SimulationParameters>,
+    mut sim_params: ResMut<SimulationParameters>,
     particle_config: Res<ParticleTypesConfig>,
     food_params: Res<FoodParameters>,
     mut simulations: Query<(&SimulationId, &mut Genotype, &mut Score, &Children), With<Simulation>>,
@@ -40,59 +259,121 @@ pub fn reset_for_new_epoch(
         (&mut Transform, &mut FoodRespawnTimer, &mut Visibility),
         (With<Food>, Without<Particle>),
     >,
-    mut previous_best_score: Local<f32>,
+    mut epoch_history: ResMut<EpochHistoryResource>,
+    mut lineage_forest: ResMut<LineageForest>,
+    mut diversity_history: ResMut<PopulationDiversityHistory>,
+    mut fitness_history: ResMut<FitnessHistory>,
+    mut epoch_summary: ResMut<EpochEndSummary>,
+    mut hall_of_fame: ResMut<HallOfFame>,
+    explorations: Query<(&SimulationId, &SecondaryScore, &NoveltyScore), With<Simulation>>,
+    // État local et flux aléatoire regroupés : limite de 16 paramètres par système
+    (mut recent_best_scores, run_seed, mut rng_stream): (
+        Local<RecentBestScores>,
+        Res<RunSeed>,
+        Local<RngStream>,
+    ),
 ) {
+    // Aussi à l'époque 0 : premier lancement ou reprise en boucle
+    sim_params.update_velocity_half_life();
+
     if sim_params.current_epoch == 0 {
         return;
     }
 
-    let mut rng = rand::rng();
+    let rng = rng_stream.get(&run_seed, "epoch_selection");
 
-    let mut scored_genomes: Vec<ScoredGenome> = simulations
+    let objective_scores: HashMap<usize, Vec<f32>> = explorations
         .iter()
-        .map(|(_, genotype, score, _)| ScoredGenome {
-            genotype: genotype.clone(),
-            score: score.get(),
-            generation: sim_params.current_epoch,
-        })
+        .map(|(sim_id, secondary, _)| (sim_id.0, secondary.0.clone()))
         .collect();
+    let novelty_scores: HashMap<usize, f32> = explorations
+        .iter()
+        .map(|(sim_id, _, novelty)| (sim_id.0, novelty.0))
+        .collect();
+    let mut scored_genomes = collect_scored_genomes(
+        &simulations,
+        &objective_scores,
+        &novelty_scores,
+        sim_params.current_epoch,
+    );
 
-    let stats = calculate_epoch_stats(&scored_genomes, *previous_best_score);
-    scored_genomes.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
-    *previous_best_score = stats.best_score;
+    let mut stats = calculate_epoch_stats(&scored_genomes, recent_best_scores.previous);
+    // current_epoch a déjà été incrémenté : l'époque terminée est la précédente
+    stats.epoch = sim_params.current_epoch - 1;
+    recent_best_scores.previous = stats.best_score;
+    record_generation(
+        &stats,
+        &scored_genomes,
+        &mut epoch_history,
+        &mut diversity_history,
+        &mut fitness_history,
+        &mut lineage_forest,
+    );
+    if sim_params.type_migration_rate > 0.0 {
+        info!(
+            "🔀 {} changements de type pendant l'époque {}",
+            epoch_history.type_changes.last().copied().unwrap_or(0),
+            stats.epoch
+        );
+    }
+
+    if let Some(winner) = scored_genomes
+        .iter()
+        .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
+    {
+        epoch_summary.publish(&stats, winner.simulation_id);
+
+        if hall_of_fame.consider(stats.epoch, &winner.genotype, stats.best_score) {
+            info!(
+                "🏆 Nouveau record: simulation #{} (score {:.2}) entre au panthéon",
+                winner.simulation_id + 1,
+                stats.best_score
+            );
+        }
+    }
 
     log_genetic_algorithm_stats(&stats, &sim_params, &scored_genomes);
 
+    // La sélection se fait sur la fitness de sélection, les statistiques restent sur les scores bruts
+    apply_selection_fitness(&mut scored_genomes, &sim_params);
+
     let elite_count =
         ((sim_params.simulation_count as f32 * sim_params.elite_ratio).ceil() as usize).max(1);
-    let mut new_genomes = Vec::with_capacity(sim_params.simulation_count);
 
-    // Conservation des élites
-    for i in 0..elite_count {
-        new_genomes.push(scored_genomes[i].genotype.clone());
-    }
-
-    // Génération de nouveaux individus
-    while new_genomes.len() < sim_params.simulation_count {
-        let mut new_genotype;
+    let mut new_genomes = if recent_best_scores.is_stagnant(stats.best_score, &sim_params)
+        && !scored_genomes.is_empty()
+    {
+        warn!(
+            "⚠ Stagnation detected — injecting diversity (meilleur score stable sur {} époques)",
+            sim_params.stagnation_window
+        );
+        recent_best_scores.window.clear();
+        epoch_history.record_stagnation();
 
-        if rng.random::<f32>() < sim_params.crossover_rate && scored_genomes.len() >= 2 {
-            let parent1 = &weighted_tournament_selection(&scored_genomes, &mut rng);
-            let parent2 = &weighted_tournament_selection(&scored_genomes, &mut rng);
-            new_genotype = improved_crossover(parent1, parent2, &mut rng);
-        } else {
-            let parent = weighted_tournament_selection(&scored_genomes, &mut rng);
-            new_genotype = parent;
-        }
+        scored_genomes.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+        inject_diversity(
+            &scored_genomes,
+            sim_params.simulation_count,
+            elite_count,
+            &sim_params,
+            rng,
+        )
+    } else if sim_params.island_count > 1 {
+        island_model_reset(&scored_genomes, &sim_params, rng)
+    } else {
+        scored_genomes.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
 
-        let adaptive_mutation_rate = calculate_adaptive_mutation_rate(
-            &stats,
-            sim_params.mutation_rate,
-            sim_params.current_epoch,
-        );
+        breed_population(
+            &scored_genomes,
+            sim_params.simulation_count,
+            elite_count,
+            &sim_params,
+            rng,
+        )
+    };
 
-        new_genotype.mutate(adaptive_mutation_rate, &mut rng);
-        new_genomes.push(new_genotype);
+    if sim_params.symmetric_forces {
+        new_genomes.iter_mut().for_each(Genotype::enforce_symmetry);
     }
 
     reset_simulations_with_new_genomes(
@@ -105,7 +386,521 @@ pub fn reset_for_new_epoch(
         &mut simulations,
         &mut particles,
         &mut food_query,
-        &mut rng,
+        rng,
+    );
+}
+
+/// Meilleurs scores des dernières époques, pour l'amélioration et la détection de stagnation
+#[derive(Default)]
+pub struct RecentBestScores {
+    previous: f32,
+    window: VecDeque<f32>,
+}
+
+impl RecentBestScores {
+    /// Ajoute le meilleur score de l'époque ; stagnant si la fenêtre pleine varie de moins que le seuil
+    fn is_stagnant(&mut self, best_score: f32, sim_params: &SimulationParameters) -> bool {
+        self.window.push_back(best_score);
+        while self.window.len() > sim_params.stagnation_window {
+            self.window.pop_front();
+        }
+
+        if sim_params.stagnation_window < 2 || self.window.len() < sim_params.stagnation_window {
+            return false;
+        }
+
+        let max = self.window.iter().copied().fold(f32::MIN, f32::max);
+        let min = self.window.iter().copied().fold(f32::MAX, f32::min);
+        max - min < sim_params.stagnation_threshold
+    }
+}
+
+/// Stagnation : les élites sont gardées telles quelles, le reste repart de génomes aléatoires
+fn inject_diversity(
+    sorted_genomes: &[ScoredGenome],
+    target_size: usize,
+    elite_count: usize,
+    sim_params: &SimulationParameters,
+    rng: &mut impl Rng,
+) -> Vec<Genotype> {
+    let mut new_genomes: Vec<Genotype> = sorted_genomes
+        .iter()
+        .take(elite_count.min(target_size))
+        .map(ScoredGenome::cloned_with_lineage)
+        .collect();
+
+    let reference = &sorted_genomes[0].genotype;
+    while new_genomes.len() < target_size {
+        let mut genotype = Genotype::random_encoded(
+            reference.type_count,
+            reference.food_type_count,
+            sim_params.genome_encoding,
+            rng,
+        );
+        genotype.meta_mutation_rate = sim_params.mutation_rate;
+        new_genomes.push(genotype);
+    }
+
+    new_genomes
+}
+
+/// Mode continu : toutes les `rolling_selection_interval` secondes, les génomes hors élite
+/// sont remplacés sur place ; les élites gardent leurs particules et leurs positions
+pub fn rolling_selection_system(
+    time: Res<Time>,
+    grid: Res<GridParameters>,
+    mut sim_params: ResMut<SimulationParameters>,
+    mut simulations: Query<(&SimulationId, &mut Genotype, &mut Score, &Children), With<Simulation>>,
+    mut particles: Query<(&mut Transform, &mut Velocity), With<Particle>>,
+    mut epoch_history: ResMut<EpochHistoryResource>,
+    mut diversity_history: ResMut<PopulationDiversityHistory>,
+    mut fitness_history: ResMut<FitnessHistory>,
+    mut lineage_forest: ResMut<LineageForest>,
+    mut explorations: Query<
+        (
+            &SimulationId,
+            &mut ExplorationScore,
+            &mut ClusterScore,
+            &SecondaryScore,
+            &mut TotalDistanceTravelled,
+            &mut FoodEfficiency,
+        ),
+        With<Simulation>,
+    >,
+    mut elapsed: Local<f32>,
+    mut previous_best_score: Local<f32>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    if sim_params.simulation_mode != SimulationMode::Continuous {
+        return;
+    }
+
+    *elapsed += time.delta_secs() * sim_params.simulation_speed.multiplier();
+    if *elapsed < sim_params.rolling_selection_interval {
+        return;
+    }
+    *elapsed = 0.0;
+
+    let rng = rng_stream.get(&run_seed, "rolling_selection");
+
+    let objective_scores: HashMap<usize, Vec<f32>> = explorations
+        .iter()
+        .map(|(sim_id, _, _, secondary, _, _)| (sim_id.0, secondary.0.clone()))
+        .collect();
+    // Nouveauté : mode par époques uniquement
+    let mut scored_genomes = collect_scored_genomes(
+        &simulations,
+        &objective_scores,
+        &HashMap::new(),
+        sim_params.current_epoch,
+    );
+
+    if scored_genomes.is_empty() {
+        return;
+    }
+
+    let mut stats = calculate_epoch_stats(&scored_genomes, *previous_best_score);
+    stats.epoch = sim_params.current_epoch;
+    *previous_best_score = stats.best_score;
+    record_generation(
+        &stats,
+        &scored_genomes,
+        &mut epoch_history,
+        &mut diversity_history,
+        &mut fitness_history,
+        &mut lineage_forest,
+    );
+
+    log_genetic_algorithm_stats(&stats, &sim_params, &scored_genomes);
+
+    // Scores bruts, avant la fitness de sélection, pour l'efficacité de fourrage
+    let raw_scores: HashMap<usize, Score> = scored_genomes
+        .iter()
+        .map(|genome| (genome.simulation_id, Score::new(genome.score)))
+        .collect();
+
+    apply_selection_fitness(&mut scored_genomes, &sim_params);
+
+    scored_genomes.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+
+    let population_size = scored_genomes.len();
+    let elite_count = ((population_size as f32 * sim_params.elite_ratio).ceil() as usize)
+        .max(1)
+        .min(population_size);
+    let mut offspring = breed_population(
+        &scored_genomes,
+        population_size - elite_count,
+        0,
+        &sim_params,
+        rng,
+    );
+    if sim_params.symmetric_forces {
+        offspring.iter_mut().for_each(Genotype::enforce_symmetry);
+    }
+
+    let mut replacements: HashMap<usize, Genotype> = scored_genomes
+        [elite_count..]
+        .iter()
+        .map(|genome| genome.simulation_id)
+        .zip(offspring)
+        .collect();
+
+    for (sim_id, mut genotype, mut score, children) in simulations.iter_mut() {
+        *score = Score::default();
+
+        let Some(new_genotype) = replacements.remove(&sim_id.0) else {
+            continue;
+        };
+        *genotype = new_genotype;
+
+        for child in children.iter() {
+            if let Ok((mut transform, mut velocity)) = particles.get_mut(child) {
+                transform.translation = random_position_in_grid(&grid, rng);
+                velocity.0 = Vec3::ZERO;
+            }
+        }
+    }
+
+    // Les scores repartent de zéro, bonus d'amas compris
+    for (sim_id, mut exploration, mut cluster_score, _, mut distance, mut efficiency) in
+        explorations.iter_mut()
+    {
+        exploration.reset();
+        cluster_score.reset();
+        if let Some(score) = raw_scores.get(&sim_id.0) {
+            efficiency.0 = distance.efficiency(score);
+        }
+        distance.0 = 0.0;
+    }
+
+    sim_params.current_epoch += 1;
+    sim_params.update_velocity_half_life();
+
+    info!(
+        "🔄 Sélection glissante {}: {} élites conservées, {} génomes remplacés",
+        stats.epoch,
+        elite_count,
+        population_size - elite_count
+    );
+}
+
+fn collect_scored_genomes(
+    simulations: &Query<(&SimulationId, &mut Genotype, &mut Score, &Children), With<Simulation>>,
+    objective_scores: &HashMap<usize, Vec<f32>>,
+    novelty_scores: &HashMap<usize, f32>,
+    generation: usize,
+) -> Vec<ScoredGenome> {
+    // Triés par SimulationId : l'ordre de la requête change avec les archétypes (Extinct…)
+    let mut scored_genomes: Vec<ScoredGenome> = simulations
+        .iter()
+        .map(|(sim_id, genotype, score, _)| ScoredGenome {
+            simulation_id: sim_id.0,
+            genotype: genotype.clone(),
+            score: score.get(),
+            objectives: objective_scores.get(&sim_id.0).cloned().unwrap_or_default(),
+            novelty: novelty_scores.get(&sim_id.0).copied().unwrap_or(0.0),
+            generation,
+        })
+        .collect();
+    scored_genomes.sort_by_key(|genome| genome.simulation_id);
+    scored_genomes
+}
+
+/// Remplace les scores bruts par la fitness utilisée pour la sélection
+fn apply_selection_fitness(scored_genomes: &mut [ScoredGenome], sim_params: &SimulationParameters) {
+    if sim_params.use_novelty_search && sim_params.simulation_mode == SimulationMode::EpochBased {
+        apply_novelty_blend(scored_genomes, sim_params.novelty_weight);
+    }
+    if sim_params.is_multi_objective() {
+        apply_pareto_ranking(scored_genomes, &sim_params.objectives);
+    }
+    if sim_params.use_speciation {
+        apply_fitness_sharing(scored_genomes, sim_params.niche_radius);
+    }
+}
+
+/// Mélange score et nouveauté, chacun normalisé par son maximum dans la population
+fn apply_novelty_blend(scored_genomes: &mut [ScoredGenome], novelty_weight: f32) {
+    let max_score = scored_genomes.iter().map(|g| g.score).fold(0.0, f32::max);
+    let max_novelty = scored_genomes.iter().map(|g| g.novelty).fold(0.0, f32::max);
+    let normalize = |value: f32, max: f32| if max > 0.0 { value / max } else { 0.0 };
+
+    for genome in scored_genomes.iter_mut() {
+        genome.score = (1.0 - novelty_weight) * normalize(genome.score, max_score)
+            + novelty_weight * normalize(genome.novelty, max_novelty);
+    }
+}
+
+/// Tri non dominé (NSGA-II) sur les objectifs sélectionnés : rang du front, puis distance de
+/// crowding dans le front ; la fitness de sélection suit cet ordre
+fn apply_pareto_ranking(scored_genomes: &mut [ScoredGenome], objectives: &[ObjectiveFn]) {
+    let objective_count = objectives.len();
+    let value = |genome: &ScoredGenome, k: usize| genome.objectives.get(k).copied().unwrap_or(0.0);
+    let dominates = |a: &ScoredGenome, b: &ScoredGenome| {
+        (0..objective_count).all(|k| value(a, k) >= value(b, k))
+            && (0..objective_count).any(|k| value(a, k) > value(b, k))
+    };
+
+    let population_size = scored_genomes.len();
+    let mut ranks: Vec<Option<usize>> = vec![None; population_size];
+    let mut crowding = vec![0.0f32; population_size];
+    let mut current_rank = 0;
+
+    while ranks.iter().any(Option::is_none) {
+        // Front courant : individus non classés dominés par aucun autre non classé
+        let front: Vec<usize> = (0..population_size)
+            .filter(|&i| ranks[i].is_none())
+            .filter(|&i| {
+                !(0..population_size).any(|j| {
+                    ranks[j].is_none() && dominates(&scored_genomes[j], &scored_genomes[i])
+                })
+            })
+            .collect();
+
+        // Distance de crowding : les extrêmes de chaque objectif sont toujours préférés
+        for k in 0..objective_count {
+            let mut sorted = front.clone();
+            sorted.sort_by(|&a, &b| {
+                value(&scored_genomes[a], k).total_cmp(&value(&scored_genomes[b], k))
+            });
+
+            let (Some(&first), Some(&last)) = (sorted.first(), sorted.last()) else {
+                continue;
+            };
+            crowding[first] = f32::INFINITY;
+            crowding[last] = f32::INFINITY;
+
+            let range = value(&scored_genomes[last], k) - value(&scored_genomes[first], k);
+            if range <= 0.0 {
+                continue;
+            }
+            for window in sorted.windows(3) {
+                let gap =
+                    value(&scored_genomes[window[2]], k) - value(&scored_genomes[window[0]], k);
+                crowding[window[1]] += gap / range;
+            }
+        }
+
+        for &i in &front {
+            ranks[i] = Some(current_rank);
+        }
+        current_rank += 1;
+    }
+
+    let mut order: Vec<usize> = (0..population_size).collect();
+    order.sort_by(|&a, &b| {
+        ranks[a]
+            .cmp(&ranks[b])
+            .then_with(|| crowding[b].total_cmp(&crowding[a]))
+    });
+
+    // Meilleur individu : fitness = taille de la population, dernier : 1
+    for (position, &i) in order.iter().enumerate() {
+        scored_genomes[i].score = (population_size - position) as f32;
+    }
+
+    let labels: Vec<&str> = objectives.iter().map(ObjectiveFn::label).collect();
+    info!("🎯 Pareto: {} fronts ({})", current_rank, labels.join(" × "));
+}
+
+/// Enregistre les statistiques et la lignée d'une génération terminée
+fn record_generation(
+    stats: &EpochStats,
+    scored_genomes: &[ScoredGenome],
+    epoch_history: &mut EpochHistoryResource,
+    diversity_history: &mut PopulationDiversityHistory,
+    fitness_history: &mut FitnessHistory,
+    lineage_forest: &mut LineageForest,
+) {
+    epoch_history.push(stats.clone());
+    diversity_history.0.push(stats.diversity());
+
+    for genome in scored_genomes {
+        fitness_history
+            .0
+            .entry(genome.simulation_id)
+            .or_default()
+            .push(genome.score);
+
+        lineage_forest.push(LineageNode {
+            epoch: stats.epoch,
+            simulation_id: genome.simulation_id,
+            score: genome.score,
+            parents: genome.genotype.lineage.clone().unwrap_or_default(),
+        });
+    }
+}
+
+/// Produit une nouvelle génération à partir d'une population triée par score décroissant
+fn breed_population(
+    sorted_genomes: &[ScoredGenome],
+    target_size: usize,
+    elite_count: usize,
+    sim_params: &SimulationParameters,
+    rng: &mut impl Rng,
+) -> Vec<Genotype> {
+    let mut new_genomes = Vec::with_capacity(target_size);
+
+    // Conservation des élites
+    for genome in sorted_genomes.iter().take(elite_count.min(target_size)) {
+        new_genomes.push(genome.cloned_with_lineage());
+    }
+
+    // Génération de nouveaux individus
+    while new_genomes.len() < target_size {
+        let mut new_genotype;
+
+        if rng.random::<f32>() < sim_params.crossover_rate && sorted_genomes.len() >= 2 {
+            let parent1 =
+                weighted_tournament_selection(sorted_genomes, sim_params.tournament_size, rng);
+            let parent2 =
+                weighted_tournament_selection(sorted_genomes, sim_params.tournament_size, rng);
+            new_genotype = match sim_params.crossover_operator {
+                CrossoverOperator::Uniform => {
+                    improved_crossover(&parent1.genotype, &parent2.genotype, rng)
+                }
+                CrossoverOperator::Blend(alpha) => {
+                    Genotype::crossover_blend(&parent1.genotype, &parent2.genotype, alpha, rng)
+                }
+                CrossoverOperator::DifferentialEvolution { f, cr } => {
+                    de_crossover(&parent1.genotype, sorted_genomes, f, cr, rng).unwrap_or_else(
+                        || improved_crossover(&parent1.genotype, &parent2.genotype, rng),
+                    )
+                }
+            };
+            new_genotype.lineage = Some(vec![parent1.simulation_id, parent2.simulation_id]);
+        } else {
+            let parent =
+                weighted_tournament_selection(sorted_genomes, sim_params.tournament_size, rng);
+            new_genotype = parent.cloned_with_lineage();
+        }
+
+        // Chaque génome mute selon son propre taux, hérité puis muté avec lui
+        new_genotype.mutate(
+            new_genotype.meta_mutation_rate,
+            sim_params.genome_encoding,
+            rng,
+        );
+        new_genomes.push(new_genotype);
+    }
+
+    new_genomes
+}
+
+/// Modèle en îles : chaque île évolue indépendamment, avec migration en anneau
+fn island_model_reset(
+    scored_genomes: &[ScoredGenome],
+    sim_params: &SimulationParameters,
+    rng: &mut impl Rng,
+) -> Vec<Genotype> {
+    let population_size = scored_genomes.len();
+    if population_size == 0 {
+        return Vec::new();
+    }
+
+    let island_count = sim_params.island_count.min(population_size).max(1);
+
+    // Répartition des simulations en îles contiguës
+    let mut islands: Vec<Vec<usize>> = vec![Vec::new(); island_count];
+    for index in 0..population_size {
+        islands[index * island_count / population_size].push(index);
+    }
+
+    let mut new_genomes: Vec<Genotype> = scored_genomes.iter().map(|g| g.genotype.clone()).collect();
+    let mut island_bests = Vec::with_capacity(island_count);
+    let mut island_elite_counts = Vec::with_capacity(island_count);
+
+    for (island_id, members) in islands.iter().enumerate() {
+        let mut island_genomes: Vec<ScoredGenome> =
+            members.iter().map(|&i| scored_genomes[i].clone()).collect();
+        island_genomes.sort_by(|a, b| b.score.partial_cmp(&a.score).unwrap());
+
+        let elite_count =
+            ((members.len() as f32 * sim_params.elite_ratio).ceil() as usize).max(1);
+        let island_offspring = breed_population(
+            &island_genomes,
+            members.len(),
+            elite_count,
+            sim_params,
+            rng,
+        );
+
+        for (slot, genotype) in members.iter().zip(island_offspring) {
+            new_genomes[*slot] = genotype;
+        }
+
+        info!(
+            "🏝 Île {}: {} simulations, meilleur score {:.2}",
+            island_id,
+            members.len(),
+            island_genomes[0].score
+        );
+
+        island_bests.push(island_genomes[0].cloned_with_lineage());
+        island_elite_counts.push(elite_count.min(members.len()));
+    }
+
+    // Migration en anneau : le meilleur de chaque île remplace un individu de l'île suivante
+    let migration_due = sim_params.migration_interval > 0
+        && sim_params.current_epoch % sim_params.migration_interval == 0;
+
+    if migration_due && island_count > 1 {
+        for sender in 0..island_count {
+            let receiver = (sender + 1) % island_count;
+            let receiver_members = &islands[receiver];
+
+            // On évite d'écraser les élites de l'île receveuse si possible
+            let first_slot = if receiver_members.len() > island_elite_counts[receiver] {
+                island_elite_counts[receiver]
+            } else {
+                0
+            };
+            let slot = receiver_members[rng.random_range(first_slot..receiver_members.len())];
+
+            new_genomes[slot] = island_bests[sender].clone();
+
+            info!(
+                "🔀 Migration: île {} → île {} (simulation #{})",
+                sender,
+                receiver,
+                scored_genomes[slot].simulation_id + 1
+            );
+        }
+    }
+
+    new_genomes
+}
+
+/// Partage de fitness : score / (1 + nombre de voisins dans le rayon de niche)
+fn apply_fitness_sharing(scored_genomes: &mut [ScoredGenome], niche_radius: f32) {
+    let niche_counts: Vec<usize> = scored_genomes
+        .iter()
+        .enumerate()
+        .map(|(i, genome)| {
+            scored_genomes
+                .iter()
+                .enumerate()
+                .filter(|(j, other)| {
+                    *j != i
+                        && calculate_genotype_distance(&genome.genotype, &other.genotype)
+                            <= niche_radius
+                })
+                .count()
+        })
+        .collect();
+
+    for (genome, niche_count) in scored_genomes.iter_mut().zip(&niche_counts) {
+        genome.score /= 1.0 + *niche_count as f32;
+    }
+
+    let species_estimate = niche_counts
+        .iter()
+        .map(|count| 1.0 / (1.0 + *count as f32))
+        .sum::<f32>();
+    info!(
+        "🧬 Spéciation: ~{:.1} niches (rayon {:.2})",
+        species_estimate, niche_radius
     );
 }
 
@@ -142,12 +937,14 @@ fn calculate_epoch_stats(scored_genomes: &[ScoredGenome], previous_best: f32) ->
     let improvement = best - previous_best;
 
     EpochStats {
+        epoch: 0,
         best_score: best,
         worst_score: worst,
         average_score: average,
         median_score: median,
         std_deviation,
         improvement,
+        average_entropy: average_entropy(scored_genomes.iter().map(|g| &g.genotype)),
     }
 }
 
@@ -166,6 +963,7 @@ fn log_genetic_algorithm_stats(
     info!("   • Moyenne: {:.2}", stats.average_score);
     info!("   • Médiane: {:.2}", stats.median_score);
     info!("   • Écart-type: {:.2}", stats.std_deviation);
+    info!("🧬 Entropie moyenne des génomes: {:.2} bits", stats.average_entropy);
 
     if stats.improvement > 0.0 {
         info!(
@@ -200,9 +998,11 @@ fn log_genetic_algorithm_stats(
     }
 }
 
-fn weighted_tournament_selection(population: &[ScoredGenome], rng: &mut impl Rng) -> Genotype {
-    const TOURNAMENT_SIZE: usize = 3;
-
+fn weighted_tournament_selection<'a>(
+    population: &'a [ScoredGenome],
+    tournament_size: usize,
+    rng: &mut impl Rng,
+) -> &'a ScoredGenome {
     let weights: Vec<f32> = population
         .iter()
         .enumerate()
@@ -210,7 +1010,7 @@ fn weighted_tournament_selection(population: &[ScoredGenome], rng: &mut impl Rng
         .collect();
 
     let mut tournament_indices = Vec::new();
-    for _ in 0..TOURNAMENT_SIZE.min(population.len()) {
+    for _ in 0..tournament_size.min(population.len()) {
         let total_weight: f32 = weights.iter().sum();
         let mut random = rng.random::<f32>() * total_weight;
 
@@ -227,12 +1027,17 @@ fn weighted_tournament_selection(population: &[ScoredGenome], rng: &mut impl Rng
         .into_iter()
         .map(|i| &population[i])
         .max_by(|a, b| a.score.partial_cmp(&b.score).unwrap())
-        .map(|g| g.genotype.clone())
-        .unwrap_or(population[0].genotype.clone())
+        .unwrap_or(&population[0])
 }
 
-fn improved_crossover(parent1: &Genotype, parent2: &Genotype, rng: &mut impl Rng) -> Genotype {
-    let mut new_genotype = Genotype::new(parent1.type_count);
+pub fn improved_crossover(
+    parent1: &Genotype,
+    parent2: &Genotype,
+    rng: &mut impl Rng,
+) -> Genotype {
+    let mut new_genotype = Genotype::new(parent1.type_count, parent1.food_type_count);
+    new_genotype.meta_mutation_rate =
+        (parent1.meta_mutation_rate + parent2.meta_mutation_rate) / 2.0;
 
     // Crossover des forces particule-particule
     for i in 0..parent1.force_matrix.len() {
@@ -255,20 +1060,41 @@ fn improved_crossover(parent1: &Genotype, parent2: &Genotype, rng: &mut impl Rng
     new_genotype
 }
 
-fn calculate_adaptive_mutation_rate(stats: &EpochStats, base_rate: f32, epoch: usize) -> f32 {
-    let diversity_factor = if stats.std_deviation < 5.0 {
-        2.0
-    } else if stats.std_deviation > 20.0 {
-        0.5
-    } else {
-        1.0
-    };
+/// Évolution différentielle (DE/rand/1/bin) : `None` s'il y a moins de trois génomes
+fn de_crossover(
+    target: &Genotype,
+    population: &[ScoredGenome],
+    scale: f32,
+    crossover_probability: f32,
+    rng: &mut impl Rng,
+) -> Option<Genotype> {
+    if population.len() < 3 {
+        return None;
+    }
 
-    let stagnation_factor = if stats.improvement <= 0.0 { 1.5 } else { 1.0 };
+    let donors = rand::seq::index::sample(rng, population.len(), 3);
+    let a = &population[donors.index(0)].genotype;
+    let b = &population[donors.index(1)].genotype;
+    let c = &population[donors.index(2)].genotype;
+    let trial = |a: f32, b: f32, c: f32| (a + scale * (b - c)).clamp(-2.0, 2.0);
 
-    let early_exploration = if epoch < 10 { 1.5 } else { 1.0 };
+    let mut child = target.clone();
+
+    // Au moins un gène de la matrice vient du vecteur d'essai
+    let forced_gene = rng.random_range(0..child.force_matrix.len().max(1));
+    for (i, gene) in child.force_matrix.iter_mut().enumerate() {
+        if i == forced_gene || rng.random::<f32>() < crossover_probability {
+            *gene = trial(a.force_matrix[i], b.force_matrix[i], c.force_matrix[i]);
+        }
+    }
+
+    for (i, gene) in child.food_forces.iter_mut().enumerate() {
+        if rng.random::<f32>() < crossover_probability {
+            *gene = trial(a.food_forces[i], b.food_forces[i], c.food_forces[i]);
+        }
+    }
 
-    (base_rate * diversity_factor * stagnation_factor * early_exploration).min(0.5)
+    Some(child)
 }
 
 fn reset_simulations_with_new_genomes(
@@ -299,10 +1125,10 @@ fn reset_simulations_with_new_genomes(
         }
     }
 
-    let mut sim_index = 0;
-    for (_, mut genotype, mut score, children) in simulations.iter_mut() {
-        if sim_index < new_genomes.len() {
-            *genotype = new_genomes[sim_index].clone();
+    // Attribution par SimulationId, indépendante de l'ordre d'itération de la requête
+    for (sim_id, mut genotype, mut score, children) in simulations.iter_mut() {
+        if let Some(new_genotype) = new_genomes.get(sim_id.0) {
+            *genotype = new_genotype.clone();
         }
 
         *score = Score::default();
@@ -320,7 +1146,6 @@ fn reset_simulations_with_new_genomes(
                 particle_index += 1;
             }
         }
-        sim_index += 1;
     }
 
     let new_food_positions: Vec<Vec3> = (0..food_params.food_count)
@@ -0,0 +1,130 @@
+use bevy::prelude::*;
+use bevy_egui::egui;
+
+use crate::resources::config::simulation::SimulationParameters;
+use crate::systems::simulation::reset::EpochHistoryResource;
+
+/// Bilan de la session, affiché dans le menu principal au retour d'une simulation
+#[derive(Resource, Default)]
+pub struct SessionStats {
+    pub epochs_run: usize,
+    /// Meilleur score atteint et époque correspondante
+    pub best_score: Option<(f32, usize)>,
+    pub worst_score: Option<f32>,
+    pub simulations_evaluated: usize,
+    /// Temps réel passé en simulation (secondes)
+    pub wall_clock_seconds: f32,
+    /// Temps réel depuis le lancement de la simulation en cours (secondes)
+    pub run_seconds: f32,
+    pub populations_saved: usize,
+    /// Statistiques d'époque déjà prises en compte
+    seen: usize,
+}
+
+impl SessionStats {
+    pub fn is_empty(&self) -> bool {
+        self.epochs_run == 0 && self.populations_saved == 0 && self.wall_clock_seconds == 0.0
+    }
+
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn record_save(&mut self) {
+        self.populations_saved += 1;
+    }
+
+    pub fn display_egui(&self, ui: &mut egui::Ui) {
+        if self.is_empty() {
+            ui.label(
+                egui::RichText::new("Aucune simulation lancée pendant cette session")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+            return;
+        }
+
+        egui::Grid::new("session_stats_grid")
+            .num_columns(2)
+            .spacing([20.0, 4.0])
+            .show(ui, |ui| {
+                ui.label("Époques jouées:");
+                ui.label(self.epochs_run.to_string());
+                ui.end_row();
+
+                ui.label("Meilleur score:");
+                match self.best_score {
+                    Some((score, epoch)) => {
+                        ui.label(format!("{:.1} (époque {})", score, epoch))
+                    }
+                    None => ui.label("—"),
+                };
+                ui.end_row();
+
+                ui.label("Pire score:");
+                match self.worst_score {
+                    Some(score) => ui.label(format!("{:.1}", score)),
+                    None => ui.label("—"),
+                };
+                ui.end_row();
+
+                ui.label("Simulations évaluées:");
+                ui.label(self.simulations_evaluated.to_string());
+                ui.end_row();
+
+                ui.label("Temps passé:");
+                ui.label(format_duration(self.wall_clock_seconds));
+                ui.end_row();
+
+                ui.label("Populations sauvegardées:");
+                ui.label(self.populations_saved.to_string());
+                ui.end_row();
+            });
+    }
+}
+
+/// Durée lisible, par exemple « 1h 05min 12s »
+pub fn format_duration(seconds: f32) -> String {
+    let total_seconds = seconds as u64;
+    format!(
+        "{}h {:02}min {:02}s",
+        total_seconds / 3600,
+        total_seconds / 60 % 60,
+        total_seconds % 60
+    )
+}
+
+/// Remet à zéro le chronomètre de la simulation qui démarre
+pub fn start_run_clock(mut session_stats: ResMut<SessionStats>) {
+    session_stats.run_seconds = 0.0;
+}
+
+/// Cumule les époques terminées et le temps réel passé en simulation
+pub fn update_session_stats(
+    time: Res<Time<Real>>,
+    epoch_history: Res<EpochHistoryResource>,
+    sim_params: Res<SimulationParameters>,
+    mut session_stats: ResMut<SessionStats>,
+) {
+    session_stats.wall_clock_seconds += time.delta_secs();
+    session_stats.run_seconds += time.delta_secs();
+
+    // Historique vidé au lancement d'une nouvelle simulation
+    let recorded = epoch_history.stats.len();
+    if recorded < session_stats.seen {
+        session_stats.seen = 0;
+    }
+
+    for stats in &epoch_history.stats[session_stats.seen..recorded] {
+        session_stats.epochs_run += 1;
+        session_stats.simulations_evaluated += sim_params.simulation_count;
+
+        if session_stats.best_score.is_none_or(|(best, _)| stats.best_score > best) {
+            session_stats.best_score = Some((stats.best_score, stats.epoch));
+        }
+        if session_stats.worst_score.is_none_or(|worst| stats.worst_score < worst) {
+            session_stats.worst_score = Some(stats.worst_score);
+        }
+    }
+    session_stats.seen = recorded;
+}
@@ -0,0 +1,92 @@
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+use crate::resources::world::grid::GridParameters;
+
+/// Hachage spatial uniforme : `resolution` cellules sur l'axe le plus long de la grille
+pub struct SpatialGrid {
+    origin: Vec3,
+    cell_size: f32,
+    dimensions: [usize; 3],
+    // Seules les cellules occupées sont allouées
+    cells: HashMap<usize, Vec<usize>>,
+}
+
+impl SpatialGrid {
+    pub fn new(grid: &GridParameters, resolution: usize) -> Self {
+        let size = Vec3::new(grid.width, grid.height, grid.depth);
+        let cell_size = (size.max_element() / resolution.max(1) as f32).max(f32::EPSILON);
+        let dimensions = [0, 1, 2].map(|axis| ((size[axis] / cell_size).ceil() as usize).max(1));
+
+        Self {
+            origin: -size / 2.0,
+            cell_size,
+            dimensions,
+            cells: HashMap::new(),
+        }
+    }
+
+    /// Cellule d'une position ; hors de la grille (particules fantômes), la cellule du bord
+    fn cell_coords(&self, position: Vec3) -> [usize; 3] {
+        let relative = (position - self.origin) / self.cell_size;
+        [0, 1, 2].map(|axis| {
+            (relative[axis].floor().max(0.0) as usize).min(self.dimensions[axis] - 1)
+        })
+    }
+
+    fn cell_index(&self, [x, y, z]: [usize; 3]) -> usize {
+        (z * self.dimensions[1] + y) * self.dimensions[0] + x
+    }
+
+    pub fn insert(&mut self, position: Vec3, item: usize) {
+        let index = self.cell_index(self.cell_coords(position));
+        self.cells.entry(index).or_default().push(item);
+    }
+
+    /// Éléments des cellules à portée de `position`, candidats dont la distance reste à vérifier.
+    /// `wrap` : voisinage périodique (tore sans particules fantômes)
+    pub fn neighbours(
+        &self,
+        position: Vec3,
+        range: f32,
+        wrap: bool,
+    ) -> impl Iterator<Item = usize> + '_ {
+        let center = self.cell_coords(position);
+        let reach = (range / self.cell_size).ceil() as usize;
+        let [xs, ys, zs] =
+            [0, 1, 2].map(|axis| axis_cells(center[axis], reach, self.dimensions[axis], wrap));
+
+        let mut indices = Vec::with_capacity(xs.len() * ys.len() * zs.len());
+        for &z in &zs {
+            for &y in &ys {
+                for &x in &xs {
+                    indices.push(self.cell_index([x, y, z]));
+                }
+            }
+        }
+
+        indices
+            .into_iter()
+            .filter_map(|index| self.cells.get(&index))
+            .flatten()
+            .copied()
+    }
+}
+
+/// Cellules d'un axe à moins de `reach` cellules du centre, sans doublon
+fn axis_cells(center: usize, reach: usize, count: usize, wrap: bool) -> Vec<usize> {
+    if 2 * reach + 1 >= count {
+        return (0..count).collect();
+    }
+
+    let (center, reach, count) = (center as isize, reach as isize, count as isize);
+    (center - reach..=center + reach)
+        .filter_map(|cell| {
+            if wrap {
+                Some(cell.rem_euclid(count) as usize)
+            } else {
+                (0..count).contains(&cell).then_some(cell as usize)
+            }
+        })
+        .collect()
+}
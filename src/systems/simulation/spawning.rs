@@ -1,13 +1,16 @@
-use crate::components::entities::food::{Food, FoodRespawnTimer, FoodValue};
-use crate::components::entities::particle::{Particle, ParticleType};
+use crate::components::entities::food::{Food, FoodRespawnTimer, FoodType, FoodValue};
+use crate::components::entities::particle::{Age, Particle, ParticleType};
 use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::entities::trail::{ParticleTrails, TrailHistory};
 use crate::components::genetics::genotype::Genotype;
 use crate::components::genetics::score::Score;
 use crate::globals::*;
 use crate::resources::config::food::FoodParameters;
-use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::particle_types::{ParticleTypeAssets, ParticleTypesConfig};
 use crate::resources::config::simulation::SimulationParameters;
 use crate::resources::world::grid::GridParameters;
+use crate::systems::persistence::session::ResumeSession;
+use crate::resources::config::random::{RngStream, RunSeed};
 use bevy::prelude::*;
 use bevy::render::view::RenderLayers;
 use rand::Rng;
@@ -25,26 +28,35 @@ pub fn spawn_simulations_with_particles(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut gizmo_assets: ResMut<Assets<GizmoAsset>>,
     grid: Res<GridParameters>,
     particle_config: Res<ParticleTypesConfig>,
     simulation_params: Res<SimulationParameters>,
+    food_params: Res<FoodParameters>,
     mut entities_spawned: ResMut<EntitiesSpawned>,
     existing_simulations: Query<Entity, With<Simulation>>,
+    resume: Option<Res<ResumeSession>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
 ) {
     // Si les entités ont déjà été créées, on ne fait rien
     if entities_spawned.0 || !existing_simulations.is_empty() {
         return;
     }
 
-    let mut rng = rand::rng();
+    let rng = rng_stream.get(&run_seed, "spawn_simulations");
 
-    // Créer un mesh partagé pour toutes les particules
-    let particle_mesh = meshes.add(
-        Sphere::new(PARTICLE_RADIUS)
-            .mesh()
-            .ico(PARTICLE_SUBDIVISIONS)
-            .unwrap(),
-    );
+    // Créer un mesh par type (rayon propre à chaque type)
+    let particle_meshes: Vec<_> = (0..particle_config.type_count)
+        .map(|i| {
+            meshes.add(
+                Sphere::new(particle_config.get_radius_for_type(i))
+                    .mesh()
+                    .ico(PARTICLE_SUBDIVISIONS)
+                    .unwrap(),
+            )
+        })
+        .collect();
 
     // Créer les matériaux pour chaque type avec émissive
     let particle_materials: Vec<_> = (0..particle_config.type_count)
@@ -78,14 +90,32 @@ pub fn spawn_simulations_with_particles(
 
     for particle_type in 0..particle_config.type_count {
         for _ in 0..particles_per_type {
-            initial_positions.push((particle_type, random_position_in_grid(&grid, &mut rng)));
+            initial_positions.push((particle_type, random_position_in_grid(&grid, rng)));
         }
     }
 
     // Pour chaque simulation
     for sim_id in 0..simulation_params.simulation_count {
-        // Créer un génome avec le bon nombre de types
-        let genotype = Genotype::random(particle_config.type_count);
+        // Génome de la session reprise, sinon un génome aléatoire avec le bon nombre de types
+        let genotype = resume
+            .as_ref()
+            .and_then(|resume| {
+                resume.0.genotype_for(
+                    sim_id,
+                    particle_config.type_count,
+                    food_params.food_type_count,
+                )
+            })
+            .unwrap_or_else(|| {
+                let mut genotype = Genotype::random_encoded(
+                    particle_config.type_count,
+                    food_params.food_type_count,
+                    simulation_params.genome_encoding,
+                    rng,
+                );
+                genotype.meta_mutation_rate = simulation_params.mutation_rate;
+                genotype
+            });
 
         // Spawn la simulation avec son RenderLayer
         commands
@@ -100,19 +130,46 @@ pub fn spawn_simulations_with_particles(
             .with_children(|parent| {
                 // Spawn toutes les particules comme enfants avec les positions communes
                 for (particle_type, position) in &initial_positions {
-                    parent.spawn((
+                    let mut particle = parent.spawn((
                         Particle,
                         ParticleType(*particle_type),
                         Transform::from_translation(*position),
-                        Mesh3d(particle_mesh.clone()),
+                        Mesh3d(particle_meshes[*particle_type].clone()),
                         MeshMaterial3d(particle_materials[*particle_type].clone()),
+                        TrailHistory::new(simulation_params.trail_length),
                         // Les particules héritent automatiquement du RenderLayer du parent
                         RenderLayers::layer(sim_id + 1),
                     ));
+
+                    if let Some(lifespan) = simulation_params.particle_lifespan {
+                        particle.insert(initial_age(lifespan, rng));
+                    }
+                }
+
+                // Gizmo des traînées sur le même RenderLayer que les particules
+                if simulation_params.trail_length > 0 {
+                    parent.spawn((
+                        ParticleTrails,
+                        Gizmo {
+                            handle: gizmo_assets.add(GizmoAsset::default()),
+                            line_config: GizmoLineConfig {
+                                width: 1.5,
+                                ..default()
+                            },
+                            ..default()
+                        },
+                        RenderLayers::layer(sim_id + 1),
+                    ));
                 }
             });
     }
 
+    // Conservés pour les particules qui changent de type en cours d'époque
+    commands.insert_resource(ParticleTypeAssets {
+        meshes: particle_meshes,
+        materials: particle_materials,
+    });
+
     // Marquer que les entités ont été créées
     entities_spawned.0 = true;
     info!(
@@ -129,12 +186,14 @@ pub fn spawn_food(
     grid: Res<GridParameters>,
     food_params: Res<FoodParameters>,
     existing_food: Query<Entity, With<Food>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
 ) {
     if !existing_food.is_empty() {
         return;
     }
 
-    let mut rng = rand::rng();
+    let rng = rng_stream.get(&run_seed, "spawn_food");
 
     let food_mesh = meshes.add(
         Sphere::new(FOOD_RADIUS)
@@ -143,20 +202,30 @@ pub fn spawn_food(
             .unwrap(),
     );
 
-    let food_material = materials.add(StandardMaterial {
-        base_color: Color::WHITE,
-        emissive: LinearRgba::WHITE,
-        unlit: true,
-        ..default()
-    });
+    // Un matériau par type de nourriture (le premier reste blanc)
+    let food_type_count = food_params.food_type_count.max(1);
+    let food_materials: Vec<_> = (0..food_type_count)
+        .map(|food_type| {
+            let base_color = food_type_color(food_type, food_type_count);
+            materials.add(StandardMaterial {
+                base_color,
+                emissive: base_color.to_linear(),
+                unlit: true,
+                ..default()
+            })
+        })
+        .collect();
 
     let food_positions: Vec<Vec3> = (0..food_params.food_count)
-        .map(|_| random_position_in_grid(&grid, &mut rng))
+        .map(|_| random_position_in_grid(&grid, rng))
         .collect();
 
     commands.insert_resource(FoodPositions(food_positions.clone()));
 
-    for position in food_positions {
+    for (index, position) in food_positions.into_iter().enumerate() {
+        // Répartition équitable des nourritures entre les types
+        let food_type = index % food_type_count;
+
         let respawn_timer = if food_params.respawn_enabled {
             Some(Timer::from_seconds(
                 food_params.respawn_cooldown,
@@ -169,10 +238,11 @@ pub fn spawn_food(
         commands.spawn((
             Food,
             FoodValue(food_params.food_value),
+            FoodType(food_type),
             FoodRespawnTimer(respawn_timer),
             Transform::from_translation(position),
             Mesh3d(food_mesh.clone()),
-            MeshMaterial3d(food_material.clone()),
+            MeshMaterial3d(food_materials[food_type].clone()),
             RenderLayers::layer(0),
         ));
     }
@@ -183,8 +253,102 @@ pub fn spawn_food(
     );
 }
 
+/// Pression environnementale : ajuste le nombre de nourritures au début de chaque époque.
+/// La variation fractionnaire est cumulée d'une époque à l'autre.
+pub fn apply_food_pressure(
+    mut commands: Commands,
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    mut food_params: ResMut<FoodParameters>,
+    food: Query<
+        (Entity, &FoodType, &Mesh3d, &MeshMaterial3d<StandardMaterial>),
+        With<Food>,
+    >,
+    mut carry: Local<f32>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
+) {
+    if sim_params.current_epoch == 0 {
+        *carry = 0.0;
+        return;
+    }
+
+    *carry += food_params.food_growth_per_epoch - food_params.food_decay_per_epoch;
+    let change = carry.trunc();
+    if change == 0.0 {
+        return;
+    }
+    *carry -= change;
+
+    let previous = food_params.food_count;
+    food_params.food_count = (previous as f32 + change).max(0.0) as usize;
+    food_params.food_count = food_params.food_count.max(food_params.min_food_count);
+    if food_params.food_count == previous {
+        return;
+    }
+
+    let mut existing: Vec<_> = food.iter().collect();
+    existing.sort_by_key(|(entity, ..)| *entity);
+
+    if food_params.food_count < existing.len() {
+        for (entity, ..) in &existing[food_params.food_count..] {
+            commands.entity(*entity).despawn();
+        }
+    } else {
+        // Les nouvelles nourritures reprennent le mesh et le matériau de leur type
+        let food_type_count = food_params.food_type_count.max(1);
+        let rng = rng_stream.get(&run_seed, "food_pressure");
+        for index in existing.len()..food_params.food_count {
+            let food_type = index % food_type_count;
+            let Some((_, _, mesh, material)) =
+                existing.iter().find(|(_, existing_type, ..)| existing_type.0 == food_type)
+            else {
+                continue;
+            };
+
+            let respawn_timer = food_params
+                .respawn_enabled
+                .then(|| Timer::from_seconds(food_params.respawn_cooldown, TimerMode::Once));
+
+            commands.spawn((
+                Food,
+                FoodValue(food_params.food_value),
+                FoodType(food_type),
+                FoodRespawnTimer(respawn_timer),
+                Transform::from_translation(random_position_in_grid(&grid, rng)),
+                Mesh3d(mesh.0.clone()),
+                MeshMaterial3d(material.0.clone()),
+                RenderLayers::layer(0),
+            ));
+        }
+    }
+
+    info!(
+        "🍎 Nourriture: {} → {} (époque {})",
+        previous, food_params.food_count, sim_params.current_epoch
+    );
+}
+
+/// Âge initial aléatoire pour éviter que toutes les particules meurent en même temps
+pub fn initial_age(lifespan: f32, rng: &mut impl Rng) -> Age {
+    Age {
+        current: rng.random_range(0.0..lifespan.max(f32::EPSILON)),
+        max: lifespan,
+    }
+}
+
+/// Couleur d'un type de nourriture : blanc puis teintes HSL réparties uniformément
+pub fn food_type_color(food_type: usize, food_type_count: usize) -> Color {
+    if food_type == 0 {
+        return Color::WHITE;
+    }
+
+    let hue = ((food_type - 1) as f32 / (food_type_count - 1).max(1) as f32) * 360.0;
+    Color::hsl(hue, 0.9, 0.75)
+}
+
 /// Génère une position aléatoire dans la grille
-fn random_position_in_grid(grid: &GridParameters, rng: &mut impl Rng) -> Vec3 {
+pub fn random_position_in_grid(grid: &GridParameters, rng: &mut impl Rng) -> Vec3 {
     let half_width = grid.width / 2.0;
     let half_height = grid.height / 2.0;
     let half_depth = grid.depth / 2.0;
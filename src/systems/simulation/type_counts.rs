@@ -0,0 +1,51 @@
+use crate::components::entities::particle::{Particle, ParticleType};
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use bevy::prelude::*;
+use std::collections::HashMap;
+
+/// Intervalle de recomptage (en frames)
+const TYPE_COUNT_INTERVAL: u32 = 5;
+
+/// Nombre de particules vivantes par simulation et par type
+#[derive(Resource, Default)]
+pub struct ParticleTypeCounts {
+    /// sim_id → type → nombre de particules
+    pub counts: HashMap<usize, HashMap<usize, u32>>,
+}
+
+impl ParticleTypeCounts {
+    pub fn count(&self, simulation_id: usize, type_index: usize) -> u32 {
+        self.counts
+            .get(&simulation_id)
+            .and_then(|types| types.get(&type_index))
+            .copied()
+            .unwrap_or(0)
+    }
+}
+
+/// Recompte les particules de chaque type toutes les `TYPE_COUNT_INTERVAL` frames
+pub fn count_particles_by_type_system(
+    particles: Query<(&ParticleType, &ChildOf), With<Particle>>,
+    simulations: Query<&SimulationId, With<Simulation>>,
+    mut type_counts: ResMut<ParticleTypeCounts>,
+    mut frame_counter: Local<u32>,
+) {
+    *frame_counter += 1;
+    if *frame_counter % TYPE_COUNT_INTERVAL != 0 {
+        return;
+    }
+
+    let mut counts: HashMap<usize, HashMap<usize, u32>> = HashMap::new();
+    for (particle_type, parent) in particles.iter() {
+        let Ok(simulation_id) = simulations.get(parent.parent()) else {
+            continue;
+        };
+        *counts
+            .entry(simulation_id.0)
+            .or_default()
+            .entry(particle_type.0)
+            .or_default() += 1;
+    }
+
+    type_counts.counts = counts;
+}
@@ -1,13 +1,16 @@
+use crate::resources::config::random::{RngStream, RunSeed};
 use bevy::prelude::*;
 use bevy::render::view::RenderLayers;
 use rand::Rng;
 use crate::components::entities::particle::{Particle, ParticleType};
 use crate::components::entities::simulation::{Simulation, SimulationId};
-use crate::components::genetics::score::Score;
+use crate::components::entities::trail::{ParticleTrails, TrailHistory};
+use crate::components::genetics::score::{Score, TotalDistanceTravelled};
 use crate::globals::*;
 use crate::resources::config::particle_types::ParticleTypesConfig;
 use crate::resources::config::simulation::SimulationParameters;
 use crate::resources::world::grid::GridParameters;
+use crate::systems::simulation::spawning::initial_age;
 use crate::ui::menus::visualizer_menu::VisualizerGenome;
 
 /// Spawn une seule simulation avec le génome spécifique du visualiseur
@@ -15,25 +18,32 @@ pub fn spawn_visualizer_simulation(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut gizmo_assets: ResMut<Assets<GizmoAsset>>,
     grid: Res<GridParameters>,
     particle_config: Res<ParticleTypesConfig>,
     simulation_params: Res<SimulationParameters>,
     visualizer_genome: Res<VisualizerGenome>,
     existing_simulations: Query<Entity, With<Simulation>>,
+    run_seed: Res<RunSeed>,
+    mut rng_stream: Local<RngStream>,
 ) {
     if !existing_simulations.is_empty() {
         return;
     }
 
-    let mut rng = rand::rng();
+    let rng = rng_stream.get(&run_seed, "visualizer_spawn");
 
-    // Mesh et matériaux pour les particules
-    let particle_mesh = meshes.add(
-        Sphere::new(PARTICLE_RADIUS)
-            .mesh()
-            .ico(PARTICLE_SUBDIVISIONS)
-            .unwrap(),
-    );
+    // Meshes (un par type, rayons variables) et matériaux pour les particules
+    let particle_meshes: Vec<_> = (0..particle_config.type_count)
+        .map(|i| {
+            meshes.add(
+                Sphere::new(particle_config.get_radius_for_type(i))
+                    .mesh()
+                    .ico(PARTICLE_SUBDIVISIONS)
+                    .unwrap(),
+            )
+        })
+        .collect();
 
     let particle_materials: Vec<_> = (0..particle_config.type_count)
         .map(|i| {
@@ -54,7 +64,7 @@ pub fn spawn_visualizer_simulation(
 
     for particle_type in 0..particle_config.type_count {
         for _ in 0..particles_per_type {
-            initial_positions.push((particle_type, random_position_in_grid(&grid, &mut rng)));
+            initial_positions.push((particle_type, random_position_in_grid(&grid, rng)));
         }
     }
 
@@ -65,16 +75,37 @@ pub fn spawn_visualizer_simulation(
             SimulationId(0),             
             visualizer_genome.0.clone(), 
             Score::default(),
+            TotalDistanceTravelled::default(),
             RenderLayers::layer(1),
         ))
         .with_children(|parent| {
             for (particle_type, position) in &initial_positions {
-                parent.spawn((
+                let mut particle = parent.spawn((
                     Particle,
                     ParticleType(*particle_type),
                     Transform::from_translation(*position),
-                    Mesh3d(particle_mesh.clone()),
+                    Mesh3d(particle_meshes[*particle_type].clone()),
                     MeshMaterial3d(particle_materials[*particle_type].clone()),
+                    TrailHistory::new(simulation_params.trail_length),
+                    RenderLayers::layer(1),
+                ));
+
+                if let Some(lifespan) = simulation_params.particle_lifespan {
+                    particle.insert(initial_age(lifespan, rng));
+                }
+            }
+
+            if simulation_params.trail_length > 0 {
+                parent.spawn((
+                    ParticleTrails,
+                    Gizmo {
+                        handle: gizmo_assets.add(GizmoAsset::default()),
+                        line_config: GizmoLineConfig {
+                            width: 1.5,
+                            ..default()
+                        },
+                        ..default()
+                    },
                     RenderLayers::layer(1),
                 ));
             }
@@ -0,0 +1,269 @@
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::{OnMaxEpochs, SimulationMode, SimulationParameters};
+use crate::states::app::AppState;
+use crate::systems::simulation::reset::EpochHistoryResource;
+use crate::ui::menus::main_menu::{MenuConfig, apply_configuration};
+use bevy::prelude::*;
+use std::fs;
+use std::str::FromStr;
+
+/// Fichier de résultats écrit à la fin du balayage
+pub const SWEEP_RESULTS_PATH: &str = "sweep_results.csv";
+
+/// Graine utilisée par défaut pour chaque combinaison
+pub const DEFAULT_SWEEP_SEED: u64 = 42;
+
+/// Un point de la grille de paramètres
+#[derive(Clone, Copy, Debug)]
+pub struct SweepCombination {
+    pub mutation_rate: f32,
+    pub particle_types: usize,
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct SweepResult {
+    pub combination: SweepCombination,
+    pub best_score: f32,
+}
+
+/// Balayage de paramètres : chaque combinaison est simulée `sweep_epochs` époques
+#[derive(Resource)]
+pub struct ParameterSweep {
+    /// Valeurs saisies dans le menu, séparées par des virgules
+    pub mutation_rates_input: String,
+    pub particle_types_input: String,
+    pub sweep_epochs: usize,
+    pub seed: u64,
+
+    base_config: Option<MenuConfig>,
+    combinations: Vec<SweepCombination>,
+    results: Vec<SweepResult>,
+    /// Une combinaison est en cours dans l'état Simulation
+    running: bool,
+}
+
+impl Default for ParameterSweep {
+    fn default() -> Self {
+        Self {
+            mutation_rates_input: "0.05, 0.1, 0.2".to_string(),
+            particle_types_input: "2, 3, 4".to_string(),
+            sweep_epochs: 5,
+            seed: DEFAULT_SWEEP_SEED,
+            base_config: None,
+            combinations: Vec::new(),
+            results: Vec::new(),
+            running: false,
+        }
+    }
+}
+
+impl ParameterSweep {
+    /// Produit cartésien des valeurs saisies, `None` si une liste est vide ou invalide
+    pub fn parse_grid(&self) -> Option<Vec<SweepCombination>> {
+        let mutation_rates: Vec<f32> = parse_list(&self.mutation_rates_input)?;
+        let particle_types: Vec<usize> = parse_list(&self.particle_types_input)?;
+
+        if mutation_rates.iter().any(|rate| !(0.0..=1.0).contains(rate))
+            || particle_types.iter().any(|types| !(2..=5).contains(types))
+        {
+            return None;
+        }
+
+        Some(
+            mutation_rates
+                .iter()
+                .flat_map(|&mutation_rate| {
+                    particle_types.iter().map(move |&particle_types| SweepCombination {
+                        mutation_rate,
+                        particle_types,
+                    })
+                })
+                .collect(),
+        )
+    }
+
+    /// Démarre un balayage à partir de la configuration courante du menu
+    pub fn start(&mut self, base_config: &MenuConfig) {
+        let Some(combinations) = self.parse_grid() else {
+            return;
+        };
+
+        info!(
+            "🔬 Balayage de paramètres: {} combinaisons × {} époques (graine {})",
+            combinations.len(),
+            self.sweep_epochs,
+            self.seed
+        );
+
+        self.base_config = Some(base_config.clone());
+        self.combinations = combinations;
+        self.results.clear();
+        self.running = false;
+    }
+
+    pub fn cancel(&mut self) {
+        warn!(
+            "Balayage interrompu après {}/{} combinaisons",
+            self.results.len(),
+            self.combinations.len()
+        );
+        self.base_config = None;
+        self.combinations.clear();
+        self.results.clear();
+        self.running = false;
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.results.len() < self.combinations.len()
+    }
+
+    /// Numéro (à partir de 1) de la combinaison en cours
+    pub fn current(&self) -> usize {
+        (self.results.len() + 1).min(self.combinations.len())
+    }
+
+    pub fn total(&self) -> usize {
+        self.combinations.len()
+    }
+
+    pub fn progress(&self) -> f32 {
+        if self.combinations.is_empty() {
+            return 0.0;
+        }
+        self.results.len() as f32 / self.combinations.len() as f32
+    }
+
+    pub fn results(&self) -> &[SweepResult] {
+        &self.results
+    }
+
+    /// Configuration du menu adaptée à la prochaine combinaison
+    fn next_config(&self) -> Option<(SweepCombination, MenuConfig)> {
+        let combination = *self.combinations.get(self.results.len())?;
+        let base_config = self.base_config.as_ref()?;
+        let particle_types = combination.particle_types;
+
+        let config = MenuConfig {
+            mutation_rate: combination.mutation_rate,
+            particle_types,
+            max_epochs: self.sweep_epochs,
+            on_max_epochs: OnMaxEpochs::Stop,
+            simulation_mode: SimulationMode::EpochBased,
+            particle_radii: ParticleTypesConfig::generate_radii(particle_types),
+            particle_velocity_half_lives: ParticleTypesConfig::generate_velocity_half_lives(
+                particle_types,
+            ),
+            particle_type_names: ParticleTypesConfig::generate_type_names(particle_types),
+            custom_colors: None,
+            auto_save_enabled: false,
+            // Même graine pour chaque combinaison : seules les valeurs balayées diffèrent
+            use_fixed_seed: true,
+            rng_seed: self.seed,
+            ..base_config.clone()
+        };
+
+        Some((combination, config))
+    }
+
+    fn write_csv(&self) -> std::io::Result<()> {
+        let mut csv = String::from("mutation_rate,particle_types,best_score\n");
+        for result in &self.results {
+            csv.push_str(&format!(
+                "{},{},{:.4}\n",
+                result.combination.mutation_rate,
+                result.combination.particle_types,
+                result.best_score
+            ));
+        }
+        fs::write(SWEEP_RESULTS_PATH, csv)
+    }
+}
+
+fn parse_list<T: FromStr>(input: &str) -> Option<Vec<T>> {
+    let values = input
+        .split(',')
+        .map(str::trim)
+        .filter(|value| !value.is_empty())
+        .map(|value| value.parse::<T>().ok())
+        .collect::<Option<Vec<T>>>()?;
+
+    (!values.is_empty()).then_some(values)
+}
+
+/// Lance la combinaison suivante depuis le menu principal
+pub fn launch_next_combination(
+    mut sweep: ResMut<ParameterSweep>,
+    mut commands: Commands,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if sweep.running || !sweep.is_active() {
+        return;
+    }
+
+    let Some((combination, config)) = sweep.next_config() else {
+        return;
+    };
+
+    info!(
+        "🔬 Combinaison {}/{}: mutation {:.3}, {} types",
+        sweep.current(),
+        sweep.total(),
+        combination.mutation_rate,
+        combination.particle_types
+    );
+
+    apply_configuration(&mut commands, &config);
+    sweep.running = true;
+    next_state.set(AppState::Simulation);
+}
+
+/// Enregistre le meilleur score après la dernière époque d'une combinaison
+pub fn record_sweep_epoch(
+    mut sweep: ResMut<ParameterSweep>,
+    sim_params: Res<SimulationParameters>,
+    epoch_history: Res<EpochHistoryResource>,
+    mut next_state: ResMut<NextState<AppState>>,
+) {
+    if !sweep.running || sim_params.current_epoch < sweep.sweep_epochs {
+        return;
+    }
+
+    let Some(combination) = sweep.combinations.get(sweep.results.len()).copied() else {
+        return;
+    };
+
+    let best_score = epoch_history
+        .stats
+        .last()
+        .map(|stats| stats.best_score)
+        .unwrap_or(0.0);
+
+    info!(
+        "🔬 Combinaison {}/{} terminée: meilleur score {:.2}",
+        sweep.current(),
+        sweep.total(),
+        best_score
+    );
+
+    sweep.results.push(SweepResult {
+        combination,
+        best_score,
+    });
+    sweep.running = false;
+
+    if !sweep.is_active() {
+        match sweep.write_csv() {
+            Ok(()) => info!("Résultats du balayage écrits dans {}", SWEEP_RESULTS_PATH),
+            Err(e) => error!("Impossible d'écrire {}: {}", SWEEP_RESULTS_PATH, e),
+        }
+    }
+
+    next_state.set(AppState::MainMenu);
+}
+
+/// Un retour manuel au menu pendant une combinaison annule le balayage
+pub fn cancel_interrupted_sweep(mut sweep: ResMut<ParameterSweep>) {
+    if sweep.running {
+        sweep.cancel();
+    }
+}
@@ -1,30 +1,167 @@
-use crate::components::entities::simulation::{Simulation, SimulationId};
-use crate::components::genetics::genotype::Genotype;
-use crate::components::genetics::score::Score;
-use crate::systems::persistence::population_save::{PopulationSaveEvents, PopulationSaveRequest};
-use crate::ui::panels::force_matrix::ForceMatrixUI;
+use crate::components::entities::simulation::{Energy, Extinct, Simulation, SimulationId};
+use crate::components::entities::particle::{Particle, Velocity};
+use crate::components::genetics::genotype::{GenomePreset, Genotype};
+use crate::components::genetics::score::{ClusterScore, ExplorationScore, FoodEfficiency, Score};
+use crate::systems::persistence::population_save::{
+    AvailablePopulations, PopulationSaveEvents, PopulationSaveRequest, SavedGenotype,
+    SavedPopulation, save_population_to_file,
+};
+use crate::globals::ENERGY_PER_PARTICLE;
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::grid::GridParameters;
+use crate::systems::simulation::session_stats::SessionStats;
+use crate::systems::simulation::spawning::random_position_in_grid;
+use crate::systems::simulation::type_counts::ParticleTypeCounts;
+use crate::ui::panels::force_matrix::{ForceMatrixUI, ViewportLayout};
 use bevy::prelude::*;
-use bevy_egui::{EguiContexts, egui};
+use bevy_egui::{EguiClipboard, EguiContexts, egui};
+
+/// Longueur maximale du nom proposé par « Save Best »
+const QUICK_SAVE_NAME_MAX_LENGTH: usize = 50;
+/// Au-delà, « Save All » demande confirmation
+const SAVE_ALL_CONFIRMATION_THRESHOLD: usize = 10;
 
 #[derive(Resource, Default)]
 pub struct SavePopulationUI {
     pub show_save_dialog: bool,
     pub simulation_to_save: Option<usize>,
+    pub merged_population: Option<SavedPopulation>, // Fusion issue du visualiseur
     pub save_name: String,
     pub save_description: String,
     pub save_in_progress: bool,
+    pub confirm_save_all: bool, // Confirmation de « Save All » en attente
+    pub clipboard_status: Option<Result<String, String>>, // Résultat du dernier copier/coller
+}
+
+impl SavePopulationUI {
+    /// Ouvre le dialogue pour une population fusionnée, nom et description pré-remplis
+    pub fn open_for_merge(&mut self, merged: SavedPopulation) {
+        self.show_save_dialog = true;
+        self.simulation_to_save = None;
+        self.save_name = merged.name.clone();
+        self.save_description = merged.description.clone().unwrap_or_default();
+        self.merged_population = Some(merged);
+    }
+
+    /// Ouvre le dialogue pour la meilleure simulation, nom pré-rempli
+    pub fn open_for_best(&mut self, simulation_id: usize, epoch: usize, score: f32) {
+        self.show_save_dialog = true;
+        self.simulation_to_save = Some(simulation_id);
+        self.merged_population = None;
+        self.save_name = best_save_name(epoch, score);
+        self.save_description.clear();
+    }
+
+    fn close(&mut self) {
+        self.show_save_dialog = false;
+        self.clipboard_status = None;
+        self.simulation_to_save = None;
+        self.merged_population = None;
+        self.save_name.clear();
+        self.save_description.clear();
+    }
+
+    fn description(&self) -> Option<String> {
+        if self.save_description.trim().is_empty() {
+            None
+        } else {
+            Some(self.save_description.trim().to_string())
+        }
+    }
+}
+
+/// Nom de sauvegarde rapide : « Best_epoch_<N>_score_<S> », tronqué à 50 caractères
+pub fn best_save_name(epoch: usize, score: f32) -> String {
+    format!("Best_epoch_{}_score_{:.1}", epoch, score)
+        .chars()
+        .take(QUICK_SAVE_NAME_MAX_LENGTH)
+        .collect()
+}
+
+/// Simulation au meilleur score : (ID, score)
+pub fn best_simulation<'a>(
+    simulations: impl Iterator<Item = (&'a SimulationId, &'a Score)>,
+) -> Option<(usize, f32)> {
+    simulations
+        .map(|(sim_id, score)| (sim_id.0, score.get()))
+        .max_by(|a, b| a.1.total_cmp(&b.1))
+}
+
+/// Remplace les forces du génome par celles d'un génome JSON collé ; renvoie le nombre de gènes
+fn import_genome_json(text: &str, genotype: &mut Genotype) -> Result<usize, String> {
+    let invalid = || "Invalid genome format".to_string();
+    let pasted: SavedGenotype = serde_json::from_str(text.trim()).map_err(|_| invalid())?;
+
+    // Les dimensions doivent correspondre à celles de la simulation cible
+    if pasted.type_count != genotype.type_count
+        || pasted.force_matrix.len() != genotype.force_matrix.len()
+        || pasted.food_forces.len() != genotype.food_forces.len()
+    {
+        return Err(invalid());
+    }
+
+    genotype.force_matrix = pasted.force_matrix;
+    genotype.food_forces = pasted.food_forces;
+    Ok(genotype.force_matrix.len() + genotype.food_forces.len())
+}
+
+/// Demande la sauvegarde de toutes les simulations, de la meilleure à la moins bonne
+fn queue_save_all(save_events: &mut PopulationSaveEvents, ranked: &[(usize, f32)], epoch: usize) {
+    for (simulation_id, score) in ranked {
+        save_events.save_requests.push(PopulationSaveRequest {
+            simulation_id: *simulation_id,
+            name: format!("Epoch{}_Sim{}_Score{:.1}", epoch, simulation_id + 1, score),
+            description: None,
+        });
+    }
+    info!("💾 Saved {} populations", ranked.len());
+}
+
+/// Ctrl+S : sauvegarde immédiate de la meilleure simulation, sans dialogue
+pub fn quick_save_best_input(
+    keyboard: Res<ButtonInput<KeyCode>>,
+    sim_params: Res<SimulationParameters>,
+    simulations: Query<(&SimulationId, &Score), With<Simulation>>,
+    mut save_events: ResMut<PopulationSaveEvents>,
+) {
+    let ctrl = keyboard.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    if !ctrl || !keyboard.just_pressed(KeyCode::KeyS) {
+        return;
+    }
+
+    let Some((simulation_id, score)) = best_simulation(simulations.iter()) else {
+        return;
+    };
+
+    let name = best_save_name(sim_params.current_epoch + 1, score);
+    info!("💾 Sauvegarde rapide de la simulation #{}: {}", simulation_id + 1, name);
+    save_events.save_requests.push(PopulationSaveRequest {
+        simulation_id,
+        name,
+        description: None,
+    });
+}
+
+enum SaveDialogAction {
+    Save,
+    Cancel,
 }
 
 pub fn save_population_ui(
     mut contexts: EguiContexts,
     mut save_ui: ResMut<SavePopulationUI>,
     mut save_events: ResMut<PopulationSaveEvents>,
-    simulations: Query<(&SimulationId, &Score, &Genotype), With<Simulation>>,
+    mut available: ResMut<AvailablePopulations>,
+    mut clipboard: ResMut<EguiClipboard>,
+    mut session_stats: ResMut<SessionStats>,
+    mut simulations: Query<(&SimulationId, &Score, &mut Genotype), With<Simulation>>,
 ) {
     let ctx = contexts.ctx_mut();
 
     if save_ui.show_save_dialog {
         let mut is_open = true;
+        let mut action = None;
 
         egui::Window::new("Sauvegarder Population")
             .resizable(false)
@@ -32,9 +169,26 @@ pub fn save_population_ui(
             .default_width(400.0)
             .open(&mut is_open)
             .show(ctx, |ui| {
-                if let Some(sim_id) = save_ui.simulation_to_save {
-                    if let Some((_, score, genotype)) = simulations
-                        .iter()
+                if let Some(merged) = &save_ui.merged_population {
+                    ui.group(|ui| {
+                        ui.label(egui::RichText::new("Population fusionnée").size(16.0).strong());
+                        ui.label(format!("Score moyen des parents: {:.1}", merged.score));
+                        ui.label(format!("Types de particules: {}", merged.genotype.type_count));
+                        ui.label(format!(
+                            "Forces particule-particule: {}",
+                            merged.genotype.force_matrix.len()
+                        ));
+                        ui.label(format!(
+                            "Forces nourriture: {}",
+                            merged.genotype.food_forces.len()
+                        ));
+                    });
+
+                    ui.separator();
+                    action = save_form(ui, &mut save_ui);
+                } else if let Some(sim_id) = save_ui.simulation_to_save {
+                    if let Some((_, score, mut genotype)) = simulations
+                        .iter_mut()
                         .find(|(simulation_id, _, _)| simulation_id.0 == sim_id)
                     {
                         ui.group(|ui| {
@@ -52,83 +206,241 @@ pub fn save_population_ui(
                             ui.label(format!("Forces nourriture: {}", genotype.food_forces.len()));
                         });
 
-                        ui.separator();
-
-                        ui.label("Nom de la population *");
-                        ui.text_edit_singleline(&mut save_ui.save_name);
-
-                        if save_ui.save_name.trim().is_empty() {
-                            ui.label(
-                                egui::RichText::new("Le nom est obligatoire")
-                                    .color(egui::Color32::RED)
-                                    .small(),
-                            );
-                        }
-
-                        ui.add_space(10.0);
-
-                        ui.label("Description (optionnelle)");
-                        ui.text_edit_multiline(&mut save_ui.save_description);
-
-                        ui.add_space(10.0);
-
                         ui.horizontal(|ui| {
-                            let can_save =
-                                !save_ui.save_name.trim().is_empty() && !save_ui.save_in_progress;
-
                             if ui
-                                .add_enabled(can_save, egui::Button::new("💾 Sauvegarder"))
+                                .button("📋 Copy Genome")
+                                .on_hover_text("Copie le génome en JSON dans le presse-papiers")
                                 .clicked()
                             {
-                                save_events.save_requests.push(PopulationSaveRequest {
-                                    simulation_id: sim_id,
-                                    name: save_ui.save_name.trim().to_string(),
-                                    description: if save_ui.save_description.trim().is_empty() {
-                                        None
-                                    } else {
-                                        Some(save_ui.save_description.trim().to_string())
-                                    },
-                                });
-
-                                save_ui.save_in_progress = true;
-                                save_ui.show_save_dialog = false;
-                                save_ui.simulation_to_save = None;
-                                save_ui.save_name.clear();
-                                save_ui.save_description.clear();
-                                save_ui.save_in_progress = false;
+                                save_ui.clipboard_status = Some(
+                                    serde_json::to_string_pretty(&SavedGenotype::from_genotype(
+                                        &genotype,
+                                    ))
+                                    .map(|json| {
+                                        ui.ctx().copy_text(json);
+                                        "Genome copied".to_string()
+                                    })
+                                    .map_err(|e| format!("Erreur de sérialisation: {}", e)),
+                                );
                             }
 
-                            if ui.button("❌ Annuler").clicked() {
-                                save_ui.show_save_dialog = false;
-                                save_ui.simulation_to_save = None;
-                                save_ui.save_name.clear();
-                                save_ui.save_description.clear();
+                            if ui
+                                .button("📋 Paste Genome")
+                                .on_hover_text("Remplace les forces par un génome JSON copié")
+                                .clicked()
+                            {
+                                let text = clipboard.get_text().unwrap_or_default();
+                                save_ui.clipboard_status = Some(
+                                    import_genome_json(&text, &mut genotype)
+                                        .map(|genes| format!("Genome imported ({} genes)", genes)),
+                                );
                             }
                         });
 
-                        if save_ui.save_in_progress {
-                            ui.add_space(5.0);
-                            ui.label("Sauvegarde en cours...");
+                        match save_ui.clipboard_status.as_ref() {
+                            Some(Ok(message)) => {
+                                ui.label(
+                                    egui::RichText::new(message)
+                                        .small()
+                                        .color(egui::Color32::GREEN),
+                                );
+                            }
+                            Some(Err(message)) => {
+                                ui.label(
+                                    egui::RichText::new(message).small().color(egui::Color32::RED),
+                                );
+                            }
+                            None => {}
                         }
+
+                        ui.separator();
+                        action = save_form(ui, &mut save_ui);
                     }
                 }
             });
 
+        match action {
+            Some(SaveDialogAction::Save) => {
+                save_ui.save_in_progress = true;
+
+                if let Some(mut merged) = save_ui.merged_population.take() {
+                    merged.name = save_ui.save_name.trim().to_string();
+                    merged.description = save_ui.description();
+
+                    match save_population_to_file(&merged) {
+                        Ok(()) => {
+                            info!("Population fusionnée '{}' sauvegardée", merged.name);
+                            session_stats.record_save();
+                            available.populations.push(merged);
+                        }
+                        Err(e) => error!("Erreur lors de la sauvegarde de la fusion: {}", e),
+                    }
+                } else if let Some(sim_id) = save_ui.simulation_to_save {
+                    save_events.save_requests.push(PopulationSaveRequest {
+                        simulation_id: sim_id,
+                        name: save_ui.save_name.trim().to_string(),
+                        description: save_ui.description(),
+                    });
+                }
+
+                save_ui.close();
+                save_ui.save_in_progress = false;
+            }
+            Some(SaveDialogAction::Cancel) => save_ui.close(),
+            None => {}
+        }
+
         if !is_open {
-            save_ui.show_save_dialog = false;
-            save_ui.simulation_to_save = None;
-            save_ui.save_name.clear();
-            save_ui.save_description.clear();
+            save_ui.close();
         }
     }
 }
 
+/// Champs nom/description et boutons du dialogue de sauvegarde
+fn save_form(ui: &mut egui::Ui, save_ui: &mut SavePopulationUI) -> Option<SaveDialogAction> {
+    let mut action = None;
+
+    ui.label("Nom de la population *");
+    ui.text_edit_singleline(&mut save_ui.save_name);
+
+    if save_ui.save_name.trim().is_empty() {
+        ui.label(
+            egui::RichText::new("Le nom est obligatoire")
+                .color(egui::Color32::RED)
+                .small(),
+        );
+    }
+
+    ui.add_space(10.0);
+
+    ui.label("Description (optionnelle)");
+    ui.text_edit_multiline(&mut save_ui.save_description);
+
+    ui.add_space(10.0);
+
+    ui.horizontal(|ui| {
+        let can_save = !save_ui.save_name.trim().is_empty() && !save_ui.save_in_progress;
+
+        if ui
+            .add_enabled(can_save, egui::Button::new("💾 Sauvegarder"))
+            .clicked()
+        {
+            action = Some(SaveDialogAction::Save);
+        }
+
+        if ui.button("❌ Annuler").clicked() {
+            action = Some(SaveDialogAction::Cancel);
+        }
+    });
+
+    if save_ui.save_in_progress {
+        ui.add_space(5.0);
+        ui.label("Sauvegarde en cours...");
+    }
+
+    action
+}
+
+/// Mini histogramme : une barre par type, de largeur proportionnelle au nombre vivant
+fn type_counts_bars(
+    ui: &mut egui::Ui,
+    simulation_id: usize,
+    type_counts: &ParticleTypeCounts,
+    particle_config: &ParticleTypesConfig,
+) {
+    const BAR_HEIGHT: f32 = 4.0;
+    const MAX_BAR_WIDTH: f32 = 60.0;
+
+    let counts: Vec<u32> = (0..particle_config.type_count)
+        .map(|type_index| type_counts.count(simulation_id, type_index))
+        .collect();
+    let max_count = counts.iter().copied().max().unwrap_or(0).max(1);
+
+    let size = egui::vec2(MAX_BAR_WIDTH, (BAR_HEIGHT + 1.0) * counts.len() as f32);
+    let (rect, response) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let painter = ui.painter_at(rect);
+
+    for (type_index, count) in counts.iter().enumerate() {
+        let [r, g, b, _] = particle_config
+            .get_color_for_type(type_index)
+            .0
+            .to_srgba()
+            .to_u8_array();
+        let top = rect.top() + type_index as f32 * (BAR_HEIGHT + 1.0);
+        let width = MAX_BAR_WIDTH * *count as f32 / max_count as f32;
+        painter.rect_filled(
+            egui::Rect::from_min_size(egui::pos2(rect.left(), top), egui::vec2(width, BAR_HEIGHT)),
+            egui::CornerRadius::ZERO,
+            egui::Color32::from_rgb(r, g, b),
+        );
+    }
+
+    response.on_hover_text(
+        counts
+            .iter()
+            .enumerate()
+            .map(|(type_index, count)| {
+                format!("{}: {}", particle_config.get_name_for_type(type_index), count)
+            })
+            .collect::<Vec<_>>()
+            .join("\n"),
+    );
+}
+
+/// Réserve d'énergie d'une simulation, colorée selon ce qu'il reste du budget initial
+fn energy_label(ui: &mut egui::Ui, energy: Option<(&Energy, bool)>, initial_energy: f32) {
+    let Some((energy, extinct)) = energy else {
+        ui.label("-");
+        return;
+    };
+
+    if extinct {
+        ui.label(egui::RichText::new("💀 éteinte").color(egui::Color32::GRAY))
+            .on_hover_text("Énergie épuisée : score figé jusqu'à la prochaine époque");
+        return;
+    }
+
+    let ratio = energy.0 / initial_energy.max(f32::EPSILON);
+    let color = if ratio > 1.0 {
+        egui::Color32::from_rgb(0, 255, 0)
+    } else if ratio > 0.25 {
+        egui::Color32::from_rgb(255, 255, 0)
+    } else {
+        egui::Color32::from_rgb(255, 80, 80)
+    };
+    ui.label(
+        egui::RichText::new(format!("🔋{:.0}", energy.0))
+            .color(color)
+            .monospace(),
+    )
+    .on_hover_text(format!("{:.0}% de la réserve initiale", ratio * 100.0));
+}
+
 pub fn simulations_list_ui(
     mut contexts: EguiContexts,
     mut ui_state: ResMut<ForceMatrixUI>,
     mut save_ui: ResMut<SavePopulationUI>,
     mut ui_space: ResMut<crate::systems::rendering::viewport_manager::UISpace>,
-    simulations: Query<(&SimulationId, &Score, &Genotype), With<Simulation>>,
+    sim_params: Res<SimulationParameters>,
+    grid: Res<GridParameters>,
+    mut simulations: Query<
+        (
+            Entity,
+            &SimulationId,
+            &Score,
+            &ExplorationScore,
+            &mut Genotype,
+            &ClusterScore,
+            &Children,
+            &FoodEfficiency,
+        ),
+        With<Simulation>,
+    >,
+    mut particles: Query<(&mut Transform, &mut Velocity), With<Particle>>,
+    energies: Query<(&Energy, Has<Extinct>), With<Simulation>>,
+    type_counts: Res<ParticleTypeCounts>,
+    particle_config: Res<ParticleTypesConfig>,
+    mut save_events: ResMut<PopulationSaveEvents>,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -137,7 +449,14 @@ pub fn simulations_list_ui(
         return;
     }
 
-    let panel_width = 400.0;
+    let panel_width = if sim_params.energy_budget.is_some() { 580.0 } else { 520.0 };
+    let mut preset_request = None;
+    let mut copy_request = None;
+    let mut save_all_requested = false;
+
+    if ui_state.genome_copy_source.is_some() {
+        ctx.set_cursor_icon(egui::CursorIcon::Copy);
+    }
 
     egui::SidePanel::right("simulations_panel")
         .exact_width(panel_width)
@@ -145,46 +464,111 @@ pub fn simulations_list_ui(
         .show(ctx, |ui| {
             ui.heading("Simulations");
 
+            if let Some(source) = ui_state.genome_copy_source {
+                ui.horizontal(|ui| {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "📋 Copie du génome #{} : cliquez la cible (Échap pour annuler)",
+                            source + 1
+                        ))
+                        .color(egui::Color32::from_rgb(0, 255, 0)),
+                    );
+                    if ui.small_button("❌").clicked() {
+                        ui_state.genome_copy_source = None;
+                    }
+                });
+            }
+
             ui.horizontal(|ui| {
                 if ui.button("Tout sélectionner").clicked() {
-                    for (sim_id, _, _) in simulations.iter() {
+                    for (_, sim_id, _, _, _, _, _, _) in simulations.iter() {
                         ui_state.selected_simulations.insert(sim_id.0);
                     }
                 }
                 if ui.button("Tout désélectionner").clicked() {
                     ui_state.selected_simulations.clear();
                 }
+
+                let can_compare = ui_state.selected_simulations.len() == 2;
+                if ui
+                    .add_enabled(can_compare, egui::Button::new("⚖ Comparer"))
+                    .on_hover_text("Compare côte à côte les deux simulations cochées")
+                    .on_disabled_hover_text("Cochez exactement deux simulations")
+                    .clicked()
+                {
+                    let mut pair: Vec<usize> =
+                        ui_state.selected_simulations.iter().copied().collect();
+                    pair.sort_unstable();
+                    ui_state.comparison = Some((pair[0], pair[1]));
+                }
+
+                if ui
+                    .add_enabled(sim_params.simulation_count > 0, egui::Button::new("💾 Save All"))
+                    .on_hover_text("Sauvegarder toutes les simulations, classées par score")
+                    .clicked()
+                {
+                    if sim_params.simulation_count > SAVE_ALL_CONFIRMATION_THRESHOLD {
+                        save_ui.confirm_save_all = true;
+                    } else {
+                        save_all_requested = true;
+                    }
+                }
             });
 
+            if sim_params.simulation_count > 1 {
+                viewport_layout_controls(ui, &mut ui_state);
+            }
+
             ui.separator();
 
             let mut sim_list: Vec<_> = simulations.iter().collect();
-            sim_list.sort_by(|a, b| b.1.get().partial_cmp(&a.1.get()).unwrap());
+            sim_list.sort_by(|a, b| b.2.get().partial_cmp(&a.2.get()).unwrap());
+
+            // Colonne d'énergie seulement avec le budget d'énergie
+            let show_energy = sim_params.energy_budget.is_some();
+            let column_count = if show_energy { 9 } else { 8 };
+            let initial_energy = sim_params.particle_count as f32 * ENERGY_PER_PARTICLE;
 
             egui::ScrollArea::vertical().show(ui, |ui| {
                 egui::Grid::new("simulations_grid")
-                    .num_columns(5)
+                    .num_columns(column_count)
                     .spacing([15.0, 5.0])
                     .striped(true)
                     .min_col_width(40.0)
                     .show(ui, |ui| {
                         ui.label(egui::RichText::new("Vue").strong());
                         ui.label(egui::RichText::new("Simulation").strong());
-                        ui.label(egui::RichText::new("Score").strong());
+                        ui.label(egui::RichText::new("Nourriture").strong());
+                        if show_energy {
+                            ui.label(egui::RichText::new("Énergie").strong());
+                        }
+                        ui.label(egui::RichText::new("Amas").strong());
+                        ui.label(egui::RichText::new("Types").strong());
+                        ui.label(egui::RichText::new("Exploration").strong());
                         ui.label(egui::RichText::new("Matrice").strong());
-                        ui.label(egui::RichText::new("Sauvegarder").strong());
+                        ui.label(egui::RichText::new("Actions").strong());
                         ui.end_row();
 
-                        ui.separator();
-                        ui.separator();
-                        ui.separator();
-                        ui.separator();
-                        ui.separator();
+                        for _ in 0..column_count {
+                            ui.separator();
+                        }
                         ui.end_row();
 
-                        for (sim_id, score, _genotype) in sim_list {
+                        for (
+                            entity,
+                            sim_id,
+                            score,
+                            exploration,
+                            genotype,
+                            cluster_score,
+                            _,
+                            food_efficiency,
+                        ) in sim_list
+                        {
                             let is_selected_for_matrix =
                                 ui_state.selected_simulation == Some(sim_id.0);
+                            let copy_source = ui_state.genome_copy_source;
+                            let is_copy_source = copy_source == Some(sim_id.0);
 
                             ui.with_layout(
                                 egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
@@ -204,7 +588,11 @@ pub fn simulations_list_ui(
                             ui.with_layout(
                                 egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
                                 |ui| {
-                                    let sim_label = if is_selected_for_matrix {
+                                    let sim_label = if is_copy_source {
+                                        egui::RichText::new(format!("📋 #{}", sim_id.0 + 1))
+                                            .color(egui::Color32::from_rgb(0, 255, 0))
+                                            .strong()
+                                    } else if is_selected_for_matrix {
                                         egui::RichText::new(format!("#{}", sim_id.0 + 1))
                                             .color(egui::Color32::from_rgb(100, 200, 255))
                                             .strong()
@@ -212,9 +600,27 @@ pub fn simulations_list_ui(
                                         egui::RichText::new(format!("#{}", sim_id.0 + 1))
                                     };
 
-                                    if ui.selectable_label(false, sim_label).clicked() {
-                                        ui_state.selected_simulation = Some(sim_id.0);
-                                        ui_state.show_matrix_window = true;
+                                    let response = ui
+                                        .selectable_label(is_copy_source, sim_label)
+                                        .on_hover_text(format!(
+                                            "Taux de mutation: {:.3}",
+                                            genotype.meta_mutation_rate
+                                        ));
+                                    if !response.clicked() {
+                                        return;
+                                    }
+
+                                    // Mode copie : le clic désigne la cible
+                                    match copy_source {
+                                        Some(source) if source != sim_id.0 => {
+                                            copy_request = Some((source, entity));
+                                            ui_state.genome_copy_source = None;
+                                        }
+                                        Some(_) => ui_state.genome_copy_source = None,
+                                        None => {
+                                            ui_state.selected_simulation = Some(sim_id.0);
+                                            ui_state.show_matrix_window = true;
+                                        }
                                     }
                                 },
                             );
@@ -222,7 +628,8 @@ pub fn simulations_list_ui(
                             ui.with_layout(
                                 egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
                                 |ui| {
-                                    let score_value = score.get();
+                                    // Le score inclut le bonus d'amas, affiché à part
+                                    let score_value = score.get() - cluster_score.bonus();
                                     let score_color = if score_value > 50.0 {
                                         egui::Color32::from_rgb(0, 255, 0)
                                     } else if score_value > 20.0 {
@@ -233,9 +640,67 @@ pub fn simulations_list_ui(
                                         egui::Color32::from_rgb(200, 200, 200)
                                     };
                                     ui.label(
-                                        egui::RichText::new(format!("{:.0}", score_value))
-                                            .color(score_color)
-                                            .monospace(),
+                                        egui::RichText::new(format!(
+                                            "{:.0} • ⚡{:.3}",
+                                            score_value, food_efficiency.0
+                                        ))
+                                        .color(score_color)
+                                        .monospace(),
+                                    )
+                                    .on_hover_text(
+                                        "Efficacité de fourrage (époque précédente) : \
+                                         score par unité de distance parcourue",
+                                    );
+                                },
+                            );
+
+                            if show_energy {
+                                ui.with_layout(
+                                    egui::Layout::centered_and_justified(
+                                        egui::Direction::LeftToRight,
+                                    ),
+                                    |ui| {
+                                        let energy = energies.get(entity).ok();
+                                        energy_label(ui, energy, initial_energy);
+                                    },
+                                );
+                            }
+
+                            ui.with_layout(
+                                egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
+                                |ui| {
+                                    ui.label(
+                                        egui::RichText::new(format!(
+                                            "+{:.1}",
+                                            cluster_score.bonus()
+                                        ))
+                                        .monospace(),
+                                    )
+                                    .on_hover_text(format!(
+                                        "{} amas • plus grand: {} particules",
+                                        cluster_score.cluster_count,
+                                        cluster_score.largest_cluster
+                                    ));
+                                },
+                            );
+
+                            ui.with_layout(
+                                egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
+                                |ui| {
+                                    type_counts_bars(ui, sim_id.0, &type_counts, &particle_config);
+                                },
+                            );
+
+                            ui.with_layout(
+                                egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
+                                |ui| {
+                                    // Volume exploré en milliers d'unités³
+                                    ui.label(
+                                        egui::RichText::new(format!(
+                                            "{:.0}k",
+                                            exploration.get() / 1000.0
+                                        ))
+                                        .monospace(),
                                     );
                                 },
                             );
@@ -253,16 +718,48 @@ pub fn simulations_list_ui(
                             ui.with_layout(
                                 egui::Layout::centered_and_justified(egui::Direction::LeftToRight),
                                 |ui| {
-                                    if ui
-                                        .button("💾")
-                                        .on_hover_text("Sauvegarder cette population")
-                                        .clicked()
-                                    {
-                                        save_ui.show_save_dialog = true;
-                                        save_ui.simulation_to_save = Some(sim_id.0);
-                                        save_ui.save_name = format!("Population_{}", sim_id.0 + 1);
-                                        save_ui.save_description.clear();
-                                    }
+                                    ui.horizontal(|ui| {
+                                        if ui
+                                            .button("💾")
+                                            .on_hover_text("Sauvegarder cette population")
+                                            .clicked()
+                                        {
+                                            save_ui.show_save_dialog = true;
+                                            save_ui.simulation_to_save = Some(sim_id.0);
+                                            save_ui.save_name =
+                                                format!("Population_{}", sim_id.0 + 1);
+                                            save_ui.save_description.clear();
+                                        }
+
+                                        if ui
+                                            .selectable_label(is_copy_source, "📋→")
+                                            .on_hover_text(
+                                                "Copy genome → : cliquez ensuite la cible",
+                                            )
+                                            .clicked()
+                                        {
+                                            ui_state.genome_copy_source =
+                                                (!is_copy_source).then_some(sim_id.0);
+                                        }
+
+                                        egui::ComboBox::from_id_salt(("genome_preset", sim_id.0))
+                                            .selected_text("✨")
+                                            .width(40.0)
+                                            .show_ui(ui, |ui| {
+                                                for preset in GenomePreset::ALL {
+                                                    if ui
+                                                        .selectable_label(false, preset.label())
+                                                        .clicked()
+                                                    {
+                                                        preset_request = Some((entity, preset));
+                                                    }
+                                                }
+                                            })
+                                            .response
+                                            .on_hover_text(
+                                                "Apply Preset : forces prédéfinies, particules replacées",
+                                            );
+                                    });
                                 },
                             );
 
@@ -278,5 +775,121 @@ pub fn simulations_list_ui(
             ));
         });
 
+    if save_ui.confirm_save_all {
+        egui::Window::new("💾 Save All")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(format!(
+                    "Sauvegarder les {} simulations ?",
+                    sim_params.simulation_count
+                ));
+                ui.horizontal(|ui| {
+                    if ui.button("✅ Confirmer").clicked() {
+                        save_all_requested = true;
+                        save_ui.confirm_save_all = false;
+                    }
+                    if ui.button("❌ Annuler").clicked() {
+                        save_ui.confirm_save_all = false;
+                    }
+                });
+            });
+    }
+
+    if save_all_requested {
+        let mut ranked: Vec<(usize, f32)> = simulations
+            .iter()
+            .map(|(_, sim_id, score, _, _, _, _, _)| (sim_id.0, score.get()))
+            .collect();
+        ranked.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+        queue_save_all(&mut save_events, &ranked, sim_params.current_epoch + 1);
+    }
+
+    // Seule la simulation concernée repart de zéro, les autres continuent
+    let preset_target = preset_request.and_then(|(entity, preset)| {
+        simulations
+            .get_mut(entity)
+            .ok()
+            .map(|simulation| (preset, simulation))
+    });
+    if let Some((preset, (_, sim_id, _, _, mut genotype, _, children, _))) = preset_target {
+        // Action de l'utilisateur : hors des flux reproductibles de la partie
+        let mut rng = rand::rng();
+        preset.apply(&mut genotype, &mut rng);
+
+        for child in children.iter() {
+            if let Ok((mut transform, mut velocity)) = particles.get_mut(child) {
+                transform.translation = random_position_in_grid(&grid, &mut rng);
+                velocity.0 = Vec3::ZERO;
+            }
+        }
+
+        info!("{} appliqué à la simulation #{}", preset.label(), sim_id.0 + 1);
+    }
+
+    // Les particules de la cible gardent leur position : seules ses forces changent
+    if let Some((source, target)) = copy_request {
+        let source_genotype = simulations
+            .iter()
+            .find(|(_, sim_id, ..)| sim_id.0 == source)
+            .map(|(_, _, _, _, genotype, ..)| genotype.clone());
+        if let (Some(source_genotype), Ok((_, sim_id, _, _, mut genotype, ..))) =
+            (source_genotype, simulations.get_mut(target))
+        {
+            genotype.copy_from(&source_genotype);
+            info!("📋 Génome #{} copié dans la simulation #{}", source + 1, sim_id.0 + 1);
+        }
+    }
+
     ui_space.right_panel_width = panel_width;
 }
+
+/// Choix de la disposition des viewports (grille colonnes × lignes)
+fn viewport_layout_controls(ui: &mut egui::Ui, ui_state: &mut ResMut<ForceMatrixUI>) {
+    // Copie locale : toute écriture dans ForceMatrixUI recalcule les viewports
+    let mut layout = ui_state.viewport_layout;
+    let (mut cols, mut rows) = match layout {
+        ViewportLayout::Grid(cols, rows) => (cols, rows),
+        ViewportLayout::Auto => (2, 2),
+    };
+    let mut custom = matches!(layout, ViewportLayout::Grid(..));
+
+    ui.horizontal(|ui| {
+        ui.checkbox(&mut custom, "Grille personnalisée");
+
+        ui.add_enabled_ui(custom, |ui| {
+            ui.label("Colonnes");
+            ui.add(egui::DragValue::new(&mut cols).range(1..=6));
+            ui.label("Lignes");
+            ui.add(egui::DragValue::new(&mut rows).range(1..=4));
+        });
+    });
+
+    layout = if custom {
+        ViewportLayout::Grid(cols, rows)
+    } else {
+        ViewportLayout::Auto
+    };
+
+    if layout != ui_state.viewport_layout {
+        ui_state.viewport_layout = layout;
+    }
+
+    if let ViewportLayout::Grid(cols, rows) = layout {
+        let selected = ui_state.selected_simulations.len();
+        if ((cols * rows) as usize) < selected {
+            ui.label(
+                egui::RichText::new(format!(
+                    "⚠ {}×{} = {} cases pour {} vues : certaines ne seront pas affichées",
+                    cols,
+                    rows,
+                    cols * rows,
+                    selected
+                ))
+                .color(egui::Color32::YELLOW)
+                .small(),
+            );
+        }
+    }
+}
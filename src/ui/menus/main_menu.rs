@@ -1,22 +1,53 @@
-use crate::globals::*;
+use crate::components::genetics::genotype::Genotype;
+use crate::plugins::core::cli::CliArgs;
 use crate::plugins::simulation::compute::ComputeEnabled;
 use crate::resources::config::food::FoodParameters;
-use crate::resources::config::particle_types::ParticleTypesConfig;
-use crate::resources::config::simulation::{SimulationParameters, SimulationSpeed};
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::particle_types::{
+    ColorPalette, MAX_TYPE_NAME_LENGTH, ParticleTypesConfig,
+};
+use crate::resources::config::predation::{PredationEnabled, PredationStrength};
+use crate::resources::config::random::RunSeed;
+use crate::globals::{
+    DEFAULT_AUTO_SAVE_INTERVAL, DEFAULT_BIRTH_THRESHOLD, DEFAULT_BLEND_ALPHA,
+    DEFAULT_CLUSTER_BONUS_MULTIPLIER, ENERGY_PER_PARTICLE,
+    DEFAULT_DE_CROSSOVER, DEFAULT_DE_SCALE, DEFAULT_FORCE_OSCILLATION_PERIOD, DEFAULT_GRAVITY,
+    DEFAULT_HALF_LIFE_SCHEDULE, DEFAULT_INTER_SIM_FORCE_SCALE, DEFAULT_NICHE_RADIUS,
+    DEFAULT_NOVELTY_WEIGHT, DEFAULT_ROLLING_SELECTION_INTERVAL, DEFAULT_SOFT_WALL_DECAY,
+    DEFAULT_SOFT_WALL_STIFFNESS, DEFAULT_STAGNATION_THRESHOLD, DEFAULT_STAGNATION_WINDOW,
+    DEFAULT_TOURNAMENT_SIZE,
+    DEFAULT_VELOCITY_HALF_LIFE, FLAT_GRID_DEPTH, MAX_SPATIAL_GRID_RESOLUTION,
+    MIN_SPATIAL_GRID_RESOLUTION, PARTICLE_RADIUS, SPATIAL_GRID_CELL_WARNING,
+};
+use crate::resources::config::simulation::{
+    CrossoverOperator, GenomeEncoding, HalfLifeDecay, ObjectiveFn, OnMaxEpochs,
+    SimulationDimension, SimulationMode, SimulationParameters, SimulationSpeed,
+};
 use crate::resources::world::boundary::BoundaryMode;
+use crate::resources::world::camera::BloomConfig;
 use crate::resources::world::grid::GridParameters;
 use crate::states::app::AppState;
 use crate::systems::persistence::population_save::*;
+use crate::systems::persistence::session::{PreviousSession, ResumeSession};
+use crate::systems::rendering::density_heatmap::{DensityHeatmap, DEFAULT_HEATMAP_RESOLUTION};
+use crate::systems::simulation::session_stats::SessionStats;
+use crate::systems::sweep::{ParameterSweep, SWEEP_RESULTS_PATH};
+use crate::ui::menus::presets::{Preset, load_presets, save_preset};
+use crate::ui::menus::visualizer_menu::VisualizerGenome;
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
 
 /// Configuration temporaire pour le menu
-#[derive(Resource)]
+#[derive(Resource, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct MenuConfig {
     // Paramètres de grille
     pub grid_width: f32,
     pub grid_height: f32,
     pub grid_depth: f32,
+    pub dimension: SimulationDimension,
 
     // Paramètres de simulation
     pub simulation_count: usize,
@@ -24,52 +55,216 @@ pub struct MenuConfig {
     pub particle_types: usize,
     pub epoch_duration: f32,
     pub max_epochs: usize,
+    pub on_max_epochs: OnMaxEpochs,
+    pub simulation_mode: SimulationMode,
+    pub rolling_selection_interval: f32,
+    pub use_fixed_seed: bool,
+    pub rng_seed: u64,
+    pub inter_simulation_interaction: bool,
+    pub inter_sim_force_scale: f32,
+    pub type_migration_rate: f32,
     pub max_force_range: f32,
+    pub spatial_grid_resolution: usize,
+    pub lifespan_enabled: bool,
+    pub particle_lifespan: f32,
+    pub energy_budget_enabled: bool,
+    pub energy_birth_threshold: f32,
+    pub gravity_enabled: bool,
+    pub enable_hard_collision: bool,
+    pub gravity: [f32; 3],
+    pub oscillating_forces: bool,
+    pub force_oscillation_period: f32,
+    pub half_life_schedule_enabled: bool,
+    pub half_life_schedule: (f32, f32),
+    pub half_life_decay: HalfLifeDecay,
+
+    // Paramètres des types de particules
+    pub particle_radii: Vec<f32>,
+    pub particle_velocity_half_lives: Vec<f32>,
+    pub particle_type_names: Vec<String>,
+    pub custom_colors: Option<Vec<[f32; 4]>>, // None = palette automatique
+    pub color_palette: ColorPalette,
 
     // Paramètres de nourriture
     pub food_count: usize,
     pub food_respawn_enabled: bool,
     pub food_respawn_time: f32,
     pub food_value: f32,
+    pub food_type_count: usize,
+    pub food_decay_per_epoch: f32,
+    pub food_growth_per_epoch: f32,
 
     // Mode de bords
     pub boundary_mode: BoundaryMode,
+    pub use_ghost_particles: bool,
+
+    // Chaîne alimentaire
+    pub predation_enabled: bool,
+    pub predation_strength: f32,
 
     // GPU compute
     pub use_gpu: bool,
 
+    // Résolution de la carte de densité (cellules par côté)
+    pub heatmap_resolution: u32,
+
+    // Sauvegarde automatique
+    pub auto_save_enabled: bool,
+    pub auto_save_interval: usize,
+
+    // Longueur des traînées (0 = désactivées)
+    pub trail_length: usize,
+    pub speed_coloring: bool,
+
     // Paramètres génétiques
     pub elite_ratio: f32,
+    pub tournament_size: usize,
     pub mutation_rate: f32,
     pub crossover_rate: f32,
+    pub crossover_operator: CrossoverOperator,
+    pub genome_encoding: GenomeEncoding,
+    pub symmetric_forces: bool,
+    pub objectives: Vec<ObjectiveFn>,
+    pub use_speciation: bool,
+    pub niche_radius: f32,
+    pub use_novelty_search: bool,
+    pub novelty_weight: f32,
+    pub cluster_bonus_multiplier: f32,
+
+    // Modèle en îles
+    pub island_count: usize,
+    pub migration_interval: usize,
+
+    // Préréglages nommés (`presets.toml`), non inclus dans un préréglage
+    #[serde(skip)]
+    pub presets: Vec<Preset>,
 }
 
-impl Default for MenuConfig {
-    fn default() -> Self {
+impl MenuConfig {
+    /// Construit la configuration du menu à partir des valeurs de `config.toml`
+    pub fn from_global_config(config: &GlobalConfig) -> Self {
         Self {
-            grid_width: DEFAULT_GRID_WIDTH,
-            grid_height: DEFAULT_GRID_HEIGHT,
-            grid_depth: DEFAULT_GRID_DEPTH,
-
-            simulation_count: DEFAULT_SIMULATION_COUNT,
-            particle_count: DEFAULT_PARTICLE_COUNT,
-            particle_types: DEFAULT_PARTICLE_TYPES,
-            epoch_duration: DEFAULT_EPOCH_DURATION,
+            grid_width: config.grid_width,
+            grid_height: config.grid_height,
+            grid_depth: config.grid_depth,
+            dimension: SimulationDimension::default(),
+
+            simulation_count: config.simulation_count,
+            particle_count: config.particle_count,
+            particle_types: config.particle_types,
+            epoch_duration: config.epoch_duration,
             max_epochs: 100,
-            max_force_range: DEFAULT_MAX_FORCE_RANGE,
-
-            food_count: DEFAULT_FOOD_COUNT,
+            on_max_epochs: OnMaxEpochs::default(),
+            simulation_mode: SimulationMode::default(),
+            rolling_selection_interval: DEFAULT_ROLLING_SELECTION_INTERVAL,
+            use_fixed_seed: false,
+            rng_seed: 0,
+            inter_simulation_interaction: false,
+            inter_sim_force_scale: DEFAULT_INTER_SIM_FORCE_SCALE,
+            type_migration_rate: 0.0,
+            max_force_range: config.max_force_range,
+            spatial_grid_resolution: SimulationParameters::default_spatial_grid_resolution(
+                config.grid_width,
+                config.max_force_range,
+            ),
+            lifespan_enabled: false,
+            particle_lifespan: 60.0,
+            energy_budget_enabled: false,
+            energy_birth_threshold: DEFAULT_BIRTH_THRESHOLD,
+            gravity_enabled: false,
+            enable_hard_collision: false,
+            gravity: DEFAULT_GRAVITY,
+            oscillating_forces: false,
+            force_oscillation_period: DEFAULT_FORCE_OSCILLATION_PERIOD,
+            half_life_schedule_enabled: false,
+            half_life_schedule: DEFAULT_HALF_LIFE_SCHEDULE,
+            half_life_decay: HalfLifeDecay::default(),
+
+            particle_radii: ParticleTypesConfig::generate_radii(config.particle_types),
+            particle_velocity_half_lives: ParticleTypesConfig::generate_velocity_half_lives(
+                config.particle_types,
+            ),
+            particle_type_names: ParticleTypesConfig::generate_type_names(config.particle_types),
+            custom_colors: None,
+            color_palette: ColorPalette::default(),
+
+            food_count: config.food_count,
             food_respawn_enabled: true,
-            food_respawn_time: DEFAULT_FOOD_RESPAWN_TIME,
-            food_value: DEFAULT_FOOD_VALUE,
+            food_respawn_time: config.food_respawn_time,
+            food_value: config.food_value,
+            food_type_count: 1,
+            food_decay_per_epoch: 0.0,
+            food_growth_per_epoch: 0.0,
 
             boundary_mode: BoundaryMode::default(),
+            use_ghost_particles: false,
+            predation_enabled: false,
+            predation_strength: 1.0,
             use_gpu: false,
+            heatmap_resolution: DEFAULT_HEATMAP_RESOLUTION,
+            auto_save_enabled: true,
+            auto_save_interval: DEFAULT_AUTO_SAVE_INTERVAL,
+            trail_length: 0,
+            speed_coloring: false,
+
+            elite_ratio: config.elite_ratio,
+            tournament_size: DEFAULT_TOURNAMENT_SIZE,
+            mutation_rate: config.mutation_rate,
+            crossover_rate: config.crossover_rate,
+            crossover_operator: CrossoverOperator::default(),
+            genome_encoding: GenomeEncoding::default(),
+            symmetric_forces: false,
+            objectives: vec![ObjectiveFn::FoodEaten],
+            use_speciation: false,
+            niche_radius: DEFAULT_NICHE_RADIUS,
+            use_novelty_search: false,
+            novelty_weight: DEFAULT_NOVELTY_WEIGHT,
+            cluster_bonus_multiplier: DEFAULT_CLUSTER_BONUS_MULTIPLIER,
+
+            island_count: config.island_count,
+            migration_interval: config.migration_interval,
+
+            presets: Vec::new(),
+        }
+    }
+
+    /// Remplace tous les paramètres par ceux d'un préréglage, sans toucher à la liste
+    fn apply_preset(&mut self, preset: &Preset) {
+        let presets = std::mem::take(&mut self.presets);
+        *self = preset.config.clone();
+        self.presets = presets;
+    }
+}
+
+impl Default for MenuConfig {
+    fn default() -> Self {
+        Self::from_global_config(&GlobalConfig::default())
+    }
+}
 
-            elite_ratio: DEFAULT_ELITE_RATIO,
-            mutation_rate: DEFAULT_MUTATION_RATE,
-            crossover_rate: DEFAULT_CROSSOVER_RATE,
+impl MenuConfig {
+    /// Configuration initiale : `config.toml`, préréglages et options de la ligne de commande.
+    /// Pas de `FromWorld` : il entrerait en conflit avec `Default`, requis par serde
+    pub fn from_startup_world(world: &World) -> Self {
+        let global_config = world.get_resource::<GlobalConfig>().cloned().unwrap_or_default();
+        let mut config = Self::from_global_config(&global_config);
+        config.presets = load_presets();
+
+        // Les options de la ligne de commande absentes de config.toml
+        if let Some(cli_args) = world.get_resource::<CliArgs>() {
+            if let Some(epochs) = cli_args.epochs {
+                config.max_epochs = epochs;
+            }
+            if let Some(boundary) = cli_args.boundary {
+                config.boundary_mode = boundary;
+            }
+            if let Some(seed) = cli_args.seed {
+                config.use_fixed_seed = true;
+                config.rng_seed = seed;
+            }
         }
+
+        config
     }
 }
 
@@ -79,9 +274,27 @@ pub fn main_menu_ui(
     mut next_state: ResMut<NextState<AppState>>,
     mut commands: Commands,
     mut available_populations: ResMut<AvailablePopulations>,
+    mut previous_session: ResMut<PreviousSession>,
+    mut bloom_config: ResMut<BloomConfig>,
+    global_config: Res<GlobalConfig>,
+    mut selected_preset: Local<Option<String>>,
+    mut new_preset_name: Local<String>,
+    mut sweep: ResMut<ParameterSweep>,
+    mut particle_config: ResMut<ParticleTypesConfig>,
+    mut session_stats: ResMut<SessionStats>,
 ) {
     let ctx = contexts.ctx_mut();
 
+    if previous_session.0.is_some() {
+        resume_session_dialog(
+            ctx,
+            &mut previous_session,
+            &mut menu_config,
+            &mut commands,
+            &mut next_state,
+        );
+    }
+
     egui::CentralPanel::default().show(ctx, |ui| {
         // Titre avec style amélioré
         ui.vertical_centered(|ui| {
@@ -105,6 +318,67 @@ pub fn main_menu_ui(
 
         // Utiliser un ScrollArea pour tout le contenu
         egui::ScrollArea::vertical().show(ui, |ui| {
+            // === Préréglages ===
+            ui.group(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label("Load Preset:");
+
+                    let mut chosen = None;
+                    egui::ComboBox::from_id_salt("preset_selector")
+                        .selected_text(selected_preset.as_deref().unwrap_or("— Custom —"))
+                        .show_ui(ui, |ui| {
+                            if ui
+                                .selectable_label(selected_preset.is_none(), "— Custom —")
+                                .clicked()
+                            {
+                                *selected_preset = None;
+                            }
+                            for (index, preset) in menu_config.presets.iter().enumerate() {
+                                let is_selected =
+                                    selected_preset.as_deref() == Some(preset.name.as_str());
+                                if ui.selectable_label(is_selected, &preset.name).clicked() {
+                                    chosen = Some(index);
+                                }
+                            }
+                        });
+
+                    if let Some(preset) = chosen.map(|index| menu_config.presets[index].clone()) {
+                        info!("Préréglage '{}' chargé", preset.name);
+                        menu_config.apply_preset(&preset);
+                        *selected_preset = Some(preset.name);
+                    }
+                });
+
+                ui.horizontal(|ui| {
+                    ui.add(
+                        egui::TextEdit::singleline(&mut *new_preset_name)
+                            .hint_text("Nom du préréglage")
+                            .desired_width(180.0),
+                    );
+
+                    let name = new_preset_name.trim().to_string();
+                    if ui
+                        .add_enabled(!name.is_empty(), egui::Button::new("💾 Save Preset"))
+                        .on_hover_text("Enregistre la configuration actuelle dans presets.toml")
+                        .clicked()
+                    {
+                        let mut config = menu_config.clone();
+                        config.presets.clear();
+
+                        save_preset(&mut menu_config.presets, Preset {
+                            name: name.clone(),
+                            config,
+                        });
+                        info!("Préréglage '{}' enregistré", name);
+
+                        *selected_preset = Some(name);
+                        new_preset_name.clear();
+                    }
+                });
+            });
+
+            ui.add_space(10.0);
+
             // === Paramètres de grille ===
             ui.group(|ui| {
                 ui.label(
@@ -118,6 +392,24 @@ pub fn main_menu_ui(
                     .num_columns(2)
                     .spacing([10.0, 8.0])
                     .show(ui, |ui| {
+                        ui.label("Dimension:");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(
+                                &mut menu_config.dimension,
+                                SimulationDimension::TwoD,
+                                "2D",
+                            )
+                            .on_hover_text(
+                                "Plan XY : profondeur réduite à 1, caméras orthographiques",
+                            );
+                            ui.radio_value(
+                                &mut menu_config.dimension,
+                                SimulationDimension::ThreeD,
+                                "3D",
+                            );
+                        });
+                        ui.end_row();
+
                         ui.label("Largeur:");
                         ui.add(
                             egui::DragValue::new(&mut menu_config.grid_width)
@@ -135,7 +427,8 @@ pub fn main_menu_ui(
                         ui.end_row();
 
                         ui.label("Profondeur:");
-                        ui.add(
+                        ui.add_enabled(
+                            menu_config.dimension.is_three_d(),
                             egui::DragValue::new(&mut menu_config.grid_depth)
                                 .range(100.0..=2000.0)
                                 .suffix(" unités"),
@@ -144,11 +437,13 @@ pub fn main_menu_ui(
                     });
 
                 ui.add_space(5.0);
+                let area = menu_config.grid_width * menu_config.grid_height;
                 ui.label(
-                    egui::RichText::new(format!(
-                        "Volume total: {:.0} unités³",
-                        menu_config.grid_width * menu_config.grid_height * menu_config.grid_depth
-                    ))
+                    egui::RichText::new(if menu_config.dimension.is_flat() {
+                        format!("Surface totale: {:.0} unités²", area)
+                    } else {
+                        format!("Volume total: {:.0} unités³", area * menu_config.grid_depth)
+                    })
                     .small()
                     .color(egui::Color32::GRAY),
                 );
@@ -208,6 +503,34 @@ pub fn main_menu_ui(
                         });
                         ui.end_row();
 
+                        ui.label("Évolution:");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(
+                                &mut menu_config.simulation_mode,
+                                SimulationMode::EpochBased,
+                                "Par époques",
+                            );
+                            ui.radio_value(
+                                &mut menu_config.simulation_mode,
+                                SimulationMode::Continuous,
+                                "Continue",
+                            )
+                            .on_hover_text(
+                                "Sélection glissante : seuls les moins bons génomes sont remplacés",
+                            );
+                        });
+                        ui.end_row();
+
+                        if menu_config.simulation_mode == SimulationMode::Continuous {
+                            ui.label("Intervalle de sélection:");
+                            ui.add(
+                                egui::DragValue::new(&mut menu_config.rolling_selection_interval)
+                                    .range(5.0..=300.0)
+                                    .suffix(" secondes"),
+                            );
+                            ui.end_row();
+                        }
+
                         ui.label("Durée d'une époque:");
                         ui.add(
                             egui::DragValue::new(&mut menu_config.epoch_duration)
@@ -220,6 +543,79 @@ pub fn main_menu_ui(
                         ui.add(egui::DragValue::new(&mut menu_config.max_epochs).range(1..=1000));
                         ui.end_row();
 
+                        ui.label("Après la dernière époque:");
+                        ui.horizontal(|ui| {
+                            ui.radio_value(
+                                &mut menu_config.on_max_epochs,
+                                OnMaxEpochs::ShowResults,
+                                "Résultats",
+                            )
+                            .on_hover_text("Met la simulation en pause et affiche le bilan");
+                            ui.radio_value(
+                                &mut menu_config.on_max_epochs,
+                                OnMaxEpochs::Stop,
+                                "Arrêter",
+                            )
+                            .on_hover_text("Sauvegarde le meilleur génome et revient au menu");
+                            ui.radio_value(
+                                &mut menu_config.on_max_epochs,
+                                OnMaxEpochs::Loop,
+                                "Boucler",
+                            )
+                            .on_hover_text("Repart de l'époque 0 avec les génomes actuels");
+                            ui.radio_value(
+                                &mut menu_config.on_max_epochs,
+                                OnMaxEpochs::LoopWithReset,
+                                "Boucler (reset)",
+                            )
+                            .on_hover_text("Repart de l'époque 0 avec des génomes aléatoires");
+                        });
+                        ui.end_row();
+
+                        ui.label("Graine aléatoire:");
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut menu_config.use_fixed_seed, "Graine fixe")
+                                .on_hover_text(
+                                    "Sans graine fixe, une graine est tirée et affichée au lancement",
+                                );
+                            ui.add_enabled(
+                                menu_config.use_fixed_seed,
+                                egui::DragValue::new(&mut menu_config.rng_seed),
+                            );
+                        });
+                        ui.end_row();
+
+                        ui.label("Interactions croisées:");
+                        ui.horizontal(|ui| {
+                            ui.checkbox(
+                                &mut menu_config.inter_simulation_interaction,
+                                "🧪 Experimental",
+                            )
+                            .on_hover_text(
+                                "Les particules des autres simulations agissent aussi, \
+                                 atténuées par le facteur (coévolution). Scores inchangés, \
+                                 calcul CPU uniquement",
+                            );
+                            ui.add_enabled(
+                                menu_config.inter_simulation_interaction,
+                                egui::DragValue::new(&mut menu_config.inter_sim_force_scale)
+                                    .range(0.0..=1.0)
+                                    .speed(0.01)
+                                    .prefix("× "),
+                            );
+                        });
+                        ui.end_row();
+
+                        ui.label("Type Plasticity:");
+                        ui.add(
+                            egui::Slider::new(&mut menu_config.type_migration_rate, 0.0..=0.01)
+                                .fixed_decimals(4),
+                        )
+                        .on_hover_text(
+                            "Probabilité par pas qu'une particule prenne un type au hasard",
+                        );
+                        ui.end_row();
+
                         ui.label("Portée max des forces:");
                         ui.add(
                             egui::DragValue::new(&mut menu_config.max_force_range)
@@ -227,6 +623,53 @@ pub fn main_menu_ui(
                                 .suffix(" unités"),
                         );
                         ui.end_row();
+
+                        ui.label("Durée de vie:");
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut menu_config.lifespan_enabled, "");
+                            ui.add_enabled(
+                                menu_config.lifespan_enabled,
+                                egui::DragValue::new(&mut menu_config.particle_lifespan)
+                                    .range(10.0..=600.0)
+                                    .suffix(" secondes"),
+                            );
+                            if !menu_config.lifespan_enabled {
+                                ui.label(
+                                    egui::RichText::new("(immortelles)")
+                                        .small()
+                                        .color(egui::Color32::GRAY),
+                                );
+                            }
+                        });
+                        ui.end_row();
+
+                        ui.label("Budget d'énergie:");
+                        ui.horizontal(|ui| {
+                            ui.checkbox(&mut menu_config.energy_budget_enabled, "")
+                                .on_hover_text(format!(
+                                    "Réserve de {} par particule, consommée à chaque pas et \
+                                     rechargée par la nourriture : à zéro, la simulation s'éteint",
+                                    ENERGY_PER_PARTICLE
+                                ));
+                            ui.add_enabled(
+                                menu_config.energy_budget_enabled,
+                                egui::DragValue::new(&mut menu_config.energy_birth_threshold)
+                                    .range(ENERGY_PER_PARTICLE..=100.0)
+                                    .speed(0.5)
+                                    .prefix("naissance > "),
+                            )
+                            .on_hover_text(
+                                "Énergie par particule au-delà de laquelle une particule naît",
+                            );
+                            if menu_config.energy_budget_enabled && menu_config.use_gpu {
+                                ui.label(
+                                    egui::RichText::new("⚠️ pas de naissance avec le GPU")
+                                        .small()
+                                        .color(egui::Color32::YELLOW),
+                                );
+                            }
+                        });
+                        ui.end_row();
                     });
 
                 ui.add_space(5.0);
@@ -258,6 +701,165 @@ pub fn main_menu_ui(
 
             ui.add_space(10.0);
 
+            // === Types de particules ===
+            let particle_types = menu_config.particle_types;
+            menu_config
+                .particle_radii
+                .resize(particle_types, PARTICLE_RADIUS);
+            menu_config
+                .particle_velocity_half_lives
+                .resize(particle_types, DEFAULT_VELOCITY_HALF_LIFE);
+            menu_config.particle_type_names.truncate(particle_types);
+            while menu_config.particle_type_names.len() < particle_types {
+                let type_index = menu_config.particle_type_names.len();
+                menu_config
+                    .particle_type_names
+                    .push(ParticleTypesConfig::default_type_name(type_index));
+            }
+
+            let color_palette = menu_config.color_palette;
+            let auto_colors: Vec<[f32; 4]> =
+                ParticleTypesConfig::generate_palette_colors(particle_types, color_palette)
+                    .iter()
+                    .map(|(color, _)| color.to_srgba().to_f32_array())
+                    .collect();
+            if let Some(custom_colors) = menu_config.custom_colors.as_mut() {
+                custom_colors.truncate(particle_types);
+                let known = custom_colors.len();
+                custom_colors.extend_from_slice(&auto_colors[known..]);
+            }
+
+            ui.group(|ui| {
+                ui.label(
+                    egui::RichText::new("Types de Particules")
+                        .size(16.0)
+                        .strong(),
+                );
+                ui.separator();
+
+                ui.horizontal(|ui| {
+                    ui.label("Colour Palette:");
+                    let mut palette = menu_config.color_palette;
+                    egui::ComboBox::from_id_salt("color_palette")
+                        .selected_text(palette.label())
+                        .show_ui(ui, |ui| {
+                            for option in ColorPalette::ALL {
+                                ui.selectable_value(&mut palette, option, option.label());
+                            }
+                        });
+                    if palette != menu_config.color_palette {
+                        menu_config.color_palette = palette;
+                        // Les matériaux des particules existantes suivent la ressource
+                        particle_config.set_palette(palette);
+                    }
+                });
+
+                ui.add_space(5.0);
+
+                let mut edited_color = None;
+
+                egui::Grid::new("particle_types_params")
+                    .num_columns(6)
+                    .spacing([10.0, 8.0])
+                    .show(ui, |ui| {
+                        let palette = menu_config
+                            .custom_colors
+                            .clone()
+                            .unwrap_or_else(|| auto_colors.clone());
+
+                        let menu_config = &mut *menu_config;
+                        for (type_index, ((radius, half_life), name)) in menu_config
+                            .particle_radii
+                            .iter_mut()
+                            .zip(menu_config.particle_velocity_half_lives.iter_mut())
+                            .zip(menu_config.particle_type_names.iter_mut())
+                            .enumerate()
+                        {
+                            let name_is_valid = ParticleTypesConfig::is_valid_type_name(name);
+                            ui.add(
+                                egui::TextEdit::singleline(name)
+                                    .char_limit(MAX_TYPE_NAME_LENGTH)
+                                    .desired_width(90.0)
+                                    .text_color_opt(
+                                        (!name_is_valid).then_some(egui::Color32::RED),
+                                    ),
+                            )
+                            .on_hover_text(format!(
+                                "Nom du type {} (1 à {} caractères)",
+                                type_index, MAX_TYPE_NAME_LENGTH
+                            ));
+
+                            ui.label("Rayon:");
+                            ui.add(
+                                egui::Slider::new(radius, 1.0..=12.0)
+                                    .suffix(" unités")
+                                    .fixed_decimals(1),
+                            );
+
+                            ui.label("Demi-vie vitesse:");
+                            ui.add(
+                                egui::Slider::new(half_life, 0.01..=1.0)
+                                    .suffix(" s")
+                                    .logarithmic(true),
+                            )
+                            .on_hover_text(
+                                "Faible : type « collant » (fort amortissement) • Élevée : type « glissant »",
+                            );
+
+                            let [r, g, b, a] = palette[type_index];
+                            let mut color = egui::Color32::from_rgba_unmultiplied(
+                                (r * 255.0).round() as u8,
+                                (g * 255.0).round() as u8,
+                                (b * 255.0).round() as u8,
+                                (a * 255.0).round() as u8,
+                            );
+                            if egui::color_picker::color_edit_button_srgba(
+                                ui,
+                                &mut color,
+                                egui::color_picker::Alpha::Opaque,
+                            )
+                            .changed()
+                            {
+                                let [r, g, b, a] = color.to_srgba_unmultiplied();
+                                edited_color = Some((
+                                    type_index,
+                                    [r, g, b, a].map(|channel| channel as f32 / 255.0),
+                                ));
+                            }
+                            ui.end_row();
+                        }
+                    });
+
+                if let Some((type_index, color)) = edited_color {
+                    menu_config
+                        .custom_colors
+                        .get_or_insert_with(|| auto_colors.clone())[type_index] = color;
+                }
+
+                ui.horizontal(|ui| {
+                    if ui
+                        .add_enabled(
+                            menu_config.custom_colors.is_some(),
+                            egui::Button::new("Couleurs automatiques"),
+                        )
+                        .on_hover_text("Revenir à la palette générée")
+                        .clicked()
+                    {
+                        menu_config.custom_colors = None;
+                    }
+
+                    if menu_config.custom_colors.is_some() {
+                        ui.label(
+                            egui::RichText::new("Palette personnalisée")
+                                .small()
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                });
+            });
+
+            ui.add_space(10.0);
+
             // === Paramètres génétiques ===
             ui.group(|ui| {
                 ui.label(
@@ -284,6 +886,15 @@ pub fn main_menu_ui(
                         ));
                         ui.end_row();
 
+                        ui.label("Taille des tournois:");
+                        ui.add(egui::DragValue::new(&mut menu_config.tournament_size).range(2..=10))
+                            .on_hover_text(
+                                "Grands tournois : pression de sélection forte, convergence rapide.\n\
+                                 Petits tournois : plus d'exploration, diversité préservée.",
+                            );
+                        ui.label(format!("({} candidats)", menu_config.tournament_size));
+                        ui.end_row();
+
                         ui.label("Taux de mutation:");
                         ui.add(
                             egui::DragValue::new(&mut menu_config.mutation_rate)
@@ -309,6 +920,220 @@ pub fn main_menu_ui(
                             menu_config.crossover_rate * 100.0
                         ));
                         ui.end_row();
+
+                        ui.label("Opérateur de crossover:");
+                        ui.horizontal(|ui| {
+                            if ui
+                                .radio(
+                                    menu_config.crossover_operator == CrossoverOperator::Uniform,
+                                    "Uniforme",
+                                )
+                                .clicked()
+                            {
+                                menu_config.crossover_operator = CrossoverOperator::Uniform;
+                            }
+                            if ui
+                                .radio(
+                                    matches!(
+                                        menu_config.crossover_operator,
+                                        CrossoverOperator::Blend(_)
+                                    ),
+                                    "BLX-α",
+                                )
+                                .clicked()
+                                && !matches!(
+                                    menu_config.crossover_operator,
+                                    CrossoverOperator::Blend(_)
+                                )
+                            {
+                                menu_config.crossover_operator =
+                                    CrossoverOperator::Blend(DEFAULT_BLEND_ALPHA);
+                            }
+                            if ui
+                                .radio(
+                                    matches!(
+                                        menu_config.crossover_operator,
+                                        CrossoverOperator::DifferentialEvolution { .. }
+                                    ),
+                                    "Évolution différentielle",
+                                )
+                                .on_hover_text("Essai a + F·(b − c) à partir de trois génomes tirés au hasard")
+                                .clicked()
+                                && !matches!(
+                                    menu_config.crossover_operator,
+                                    CrossoverOperator::DifferentialEvolution { .. }
+                                )
+                            {
+                                menu_config.crossover_operator =
+                                    CrossoverOperator::DifferentialEvolution {
+                                        f: DEFAULT_DE_SCALE,
+                                        cr: DEFAULT_DE_CROSSOVER,
+                                    };
+                            }
+                        });
+                        match &mut menu_config.crossover_operator {
+                            CrossoverOperator::Blend(alpha) => {
+                                ui.add(egui::Slider::new(alpha, 0.0..=1.0).text("α"));
+                            }
+                            CrossoverOperator::DifferentialEvolution { f, cr } => {
+                                ui.vertical(|ui| {
+                                    ui.add(egui::Slider::new(f, 0.1..=2.0).text("F"))
+                                        .on_hover_text("Échelle de la différence entre génomes");
+                                    ui.add(egui::Slider::new(cr, 0.0..=1.0).text("CR"))
+                                        .on_hover_text("Probabilité de prendre chaque gène de l'essai");
+                                });
+                            }
+                            CrossoverOperator::Uniform => {
+                                ui.label("");
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Symétrie:");
+                        ui.checkbox(&mut menu_config.symmetric_forces, "Forces réciproques");
+                        ui.label(if menu_config.symmetric_forces {
+                            "(force A→B = force B→A)"
+                        } else {
+                            "(forces libres)"
+                        });
+                        ui.end_row();
+
+                        ui.label("Encodage des gènes:");
+                        ui.horizontal(|ui| {
+                            let quantised = matches!(
+                                menu_config.genome_encoding,
+                                GenomeEncoding::Quantised(_)
+                            );
+                            if ui.radio(!quantised, "Float32").clicked() {
+                                menu_config.genome_encoding = GenomeEncoding::Float32;
+                            }
+                            if ui
+                                .radio(quantised, "Quantifié")
+                                .on_hover_text(
+                                    "Gènes sur une grille de pas 1/2^(bits−1) : \
+                                     espace de recherche discret",
+                                )
+                                .clicked()
+                                && !quantised
+                            {
+                                // Même résolution que l'indicateur de diversité
+                                let interactions =
+                                    menu_config.particle_types * menu_config.particle_types;
+                                let bits = (64 / interactions.max(1)).clamp(2, 8) as u8;
+                                menu_config.genome_encoding = GenomeEncoding::Quantised(bits);
+                            }
+                        });
+                        match &mut menu_config.genome_encoding {
+                            GenomeEncoding::Quantised(bits) => {
+                                ui.add(
+                                    egui::DragValue::new(bits)
+                                        .range(GenomeEncoding::BITS_RANGE)
+                                        .suffix(" bits"),
+                                );
+                            }
+                            GenomeEncoding::Float32 => {
+                                ui.label("");
+                            }
+                        }
+                        ui.end_row();
+
+                        ui.label("Objectifs:");
+                        ui.horizontal_wrapped(|ui| {
+                            for objective in ObjectiveFn::ALL {
+                                let mut selected = menu_config.objectives.contains(&objective);
+                                // Au moins un objectif reste sélectionné
+                                let locked = selected && menu_config.objectives.len() == 1;
+                                if ui
+                                    .add_enabled(
+                                        !locked,
+                                        egui::Checkbox::new(&mut selected, objective.label()),
+                                    )
+                                    .changed()
+                                {
+                                    if selected {
+                                        menu_config.objectives.push(objective);
+                                    } else {
+                                        menu_config.objectives.retain(|o| *o != objective);
+                                    }
+                                }
+                            }
+                        });
+                        ui.label(if menu_config.objectives.len() > 1 {
+                            "(rangs de Pareto + crowding)"
+                        } else {
+                            "(objectif unique)"
+                        });
+                        ui.end_row();
+
+                        ui.label("Spéciation:");
+                        ui.checkbox(&mut menu_config.use_speciation, "Partage de fitness");
+                        if menu_config.use_speciation {
+                            ui.add(
+                                egui::Slider::new(&mut menu_config.niche_radius, 0.1..=5.0)
+                                    .text("rayon"),
+                            );
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+
+                        ui.label("Nouveauté:");
+                        ui.checkbox(&mut menu_config.use_novelty_search, "Recherche de nouveauté")
+                            .on_hover_text(
+                                "Récompense les comportements inédits : positions moyennes finales \
+                                 de chaque type comparées à une archive (mode par époques)",
+                            );
+                        if menu_config.use_novelty_search {
+                            ui.add(
+                                egui::Slider::new(&mut menu_config.novelty_weight, 0.0..=1.0)
+                                    .text("poids")
+                                    .fixed_decimals(2),
+                            )
+                            .on_hover_text("0 = score seul, 1 = nouveauté seule");
+                        } else {
+                            ui.label("");
+                        }
+                        ui.end_row();
+
+                        ui.label("Bonus d'amas:");
+                        ui.add(
+                            egui::Slider::new(&mut menu_config.cluster_bonus_multiplier, 0.0..=1.0)
+                                .fixed_decimals(2),
+                        )
+                        .on_hover_text("Points par particule du plus grand amas, en moyenne sur l'époque");
+                        ui.label(if menu_config.cluster_bonus_multiplier > 0.0 {
+                            "(amas cohésifs favorisés)"
+                        } else {
+                            "(désactivé)"
+                        });
+                        ui.end_row();
+
+                        ui.label("Nombre d'îles:");
+                        let max_islands = menu_config.simulation_count.max(1);
+                        ui.add(
+                            egui::DragValue::new(&mut menu_config.island_count)
+                                .range(1..=max_islands),
+                        );
+                        ui.label(if menu_config.island_count > 1 {
+                            format!(
+                                "(~{} simulations par île)",
+                                menu_config.simulation_count / menu_config.island_count.max(1)
+                            )
+                        } else {
+                            "(population unique)".to_string()
+                        });
+                        ui.end_row();
+
+                        if menu_config.island_count > 1 {
+                            ui.label("Intervalle de migration:");
+                            ui.add(
+                                egui::DragValue::new(&mut menu_config.migration_interval)
+                                    .range(1..=100)
+                                    .suffix(" époques"),
+                            );
+                            ui.label("(topologie en anneau)");
+                            ui.end_row();
+                        }
                     });
 
                 ui.add_space(5.0);
@@ -359,6 +1184,26 @@ pub fn main_menu_ui(
                                 .fixed_decimals(1),
                         );
                         ui.end_row();
+
+                        ui.label("Types de nourriture:");
+                        ui.add(egui::DragValue::new(&mut menu_config.food_type_count).range(1..=5));
+                        ui.end_row();
+
+                        ui.label("Diminution par époque:");
+                        ui.add(
+                            egui::Slider::new(&mut menu_config.food_decay_per_epoch, 0.0..=5.0)
+                                .fixed_decimals(1),
+                        )
+                        .on_hover_text("Nourritures retirées à chaque époque (minimum 1)");
+                        ui.end_row();
+
+                        ui.label("Croissance par époque:");
+                        ui.add(
+                            egui::Slider::new(&mut menu_config.food_growth_per_epoch, 0.0..=5.0)
+                                .fixed_decimals(1),
+                        )
+                        .on_hover_text("Nourritures ajoutées à chaque époque");
+                        ui.end_row();
                     });
 
                 ui.add_space(5.0);
@@ -393,21 +1238,174 @@ pub fn main_menu_ui(
                         BoundaryMode::Teleport,
                         "🌀 Téléportation",
                     );
+                    let is_soft_wall =
+                        matches!(menu_config.boundary_mode, BoundaryMode::SoftWall { .. });
+                    if ui.radio(is_soft_wall, "🧱 Murs mous").clicked() && !is_soft_wall {
+                        menu_config.boundary_mode = BoundaryMode::SoftWall {
+                            stiffness: DEFAULT_SOFT_WALL_STIFFNESS,
+                            decay: DEFAULT_SOFT_WALL_DECAY,
+                        };
+                    }
                 });
 
                 ui.add_space(5.0);
-                match menu_config.boundary_mode {
+                match &mut menu_config.boundary_mode {
                     BoundaryMode::Bounce => {
                         ui.label("Les particules rebondissent sur les murs avec amortissement");
                     }
                     BoundaryMode::Teleport => {
                         ui.label("Les particules réapparaissent de l'autre côté (tore 3D)");
+                        ui.checkbox(
+                            &mut menu_config.use_ghost_particles,
+                            "👻 Particules fantômes",
+                        )
+                        .on_hover_text(
+                            "Les particules et la nourriture proches d'un mur agissent aussi \
+                             depuis le côté opposé (calcul CPU uniquement)",
+                        );
+                    }
+                    BoundaryMode::SoftWall { stiffness, decay } => {
+                        ui.label("Les murs repoussent les particules avec une force décroissante");
+                        ui.add(egui::Slider::new(stiffness, 10.0..=2000.0).text("Raideur"));
+                        ui.add(
+                            egui::Slider::new(decay, 5.0..=200.0)
+                                .text("Portée")
+                                .suffix(" unités"),
+                        );
+                    }
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // === Physique ===
+            ui.group(|ui| {
+                ui.label(egui::RichText::new("Physics").size(16.0).strong());
+                ui.separator();
+
+                ui.checkbox(&mut menu_config.gravity_enabled, "Enable gravity");
+
+                if menu_config.gravity_enabled {
+                    ui.horizontal(|ui| {
+                        ui.label("Gravité:");
+                        for (axis, label) in ["x: ", "y: ", "z: "].into_iter().enumerate() {
+                            ui.add(
+                                egui::DragValue::new(&mut menu_config.gravity[axis])
+                                    .speed(1.0)
+                                    .range(-500.0..=500.0)
+                                    .prefix(label),
+                            );
+                        }
+                    });
+                    if matches!(menu_config.boundary_mode, BoundaryMode::Teleport) {
+                        ui.label(
+                            egui::RichText::new("En mode téléportation, le sol fait rebondir les particules")
+                                .small()
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                }
+
+                ui.checkbox(&mut menu_config.enable_hard_collision, "Collisions (sphères dures)")
+                    .on_hover_text("Les particules d'une même simulation rebondissent au contact");
+                if menu_config.enable_hard_collision && menu_config.use_gpu {
+                    ui.label(
+                        egui::RichText::new("⚠️ Sans effet avec le GPU (physique CPU uniquement)")
+                            .small()
+                            .color(egui::Color32::from_rgb(255, 150, 0)),
+                    );
+                }
+            });
+
+            ui.add_space(10.0);
+
+            // === Dynamique de l'environnement ===
+            ui.group(|ui| {
+                ui.label(egui::RichText::new("Environmental Dynamics").size(16.0).strong());
+                ui.separator();
+
+                ui.checkbox(&mut menu_config.oscillating_forces, "Forces oscillantes")
+                    .on_hover_text("Les forces entre particules pulsent de ±50 % au cours de l'époque");
+
+                if menu_config.oscillating_forces {
+                    ui.add(
+                        egui::Slider::new(&mut menu_config.force_oscillation_period, 2.0..=120.0)
+                            .text("Période")
+                            .suffix(" s"),
+                    );
+                }
+
+                ui.checkbox(&mut menu_config.half_life_schedule_enabled, "Recuit de la demi-vie")
+                    .on_hover_text(
+                        "La demi-vie des vitesses passe du début à la fin au fil des époques : \
+                         beaucoup d'exploration au départ, puis de moins en moins",
+                    );
+
+                if menu_config.half_life_schedule_enabled {
+                    let (start, end) = &mut menu_config.half_life_schedule;
+                    ui.horizontal(|ui| {
+                        ui.label("Demi-vie:");
+                        ui.add(
+                            egui::DragValue::new(start)
+                                .speed(0.001)
+                                .range(0.005..=1.0)
+                                .prefix("début ")
+                                .suffix(" s"),
+                        );
+                        ui.label("→");
+                        ui.add(
+                            egui::DragValue::new(end)
+                                .speed(0.001)
+                                .range(0.005..=1.0)
+                                .prefix("fin ")
+                                .suffix(" s"),
+                        );
+                    });
+                    ui.horizontal(|ui| {
+                        ui.label("Courbe:");
+                        for decay in HalfLifeDecay::ALL {
+                            ui.radio_value(&mut menu_config.half_life_decay, decay, decay.label());
+                        }
+                    });
+                    if menu_config.use_gpu {
+                        ui.label(
+                            egui::RichText::new("⚠️ Sans effet avec le GPU (physique CPU uniquement)")
+                                .small()
+                                .color(egui::Color32::from_rgb(255, 150, 0)),
+                        );
                     }
                 }
             });
 
             ui.add_space(10.0);
 
+            // === Chaîne alimentaire ===
+            ui.group(|ui| {
+                ui.label(egui::RichText::new("Prédation").size(16.0).strong());
+                ui.separator();
+
+                ui.checkbox(
+                    &mut menu_config.predation_enabled,
+                    "Activer la chaîne alimentaire (type 0 → 1 → 2 → …)",
+                );
+
+                if menu_config.predation_enabled {
+                    ui.add(
+                        egui::Slider::new(&mut menu_config.predation_strength, 0.0..=1.0)
+                            .text("Intensité"),
+                    );
+                    ui.label(
+                        egui::RichText::new(
+                            "Chaque type mange le suivant au contact; la proie réapparaît ailleurs",
+                        )
+                        .small()
+                        .color(egui::Color32::GRAY),
+                    );
+                }
+            });
+
+            ui.add_space(10.0);
+
             // === Paramètres de performance ===
             ui.group(|ui| {
                 ui.label(egui::RichText::new("Performance").size(16.0).strong());
@@ -431,6 +1429,172 @@ pub fn main_menu_ui(
                     ui.label("Les calculs seront effectués sur le CPU");
                     ui.label("Plus flexible mais plus lent avec beaucoup de particules");
                 }
+
+                ui.add_space(5.0);
+                // Copie locale : ne déclencher la mise à jour des caméras qu'en cas de changement
+                let mut bloom = *bloom_config;
+                ui.horizontal(|ui| {
+                    ui.label("Bloom:");
+                    ui.add(egui::Slider::new(&mut bloom.intensity, 0.0..=1.0).fixed_decimals(2))
+                        .on_hover_text("0 = désactivé");
+                    ui.label("Seuil:");
+                    ui.add_enabled(
+                        bloom.intensity > 0.0,
+                        egui::Slider::new(&mut bloom.threshold, 0.0..=2.0).fixed_decimals(2),
+                    );
+                });
+                if bloom.intensity != bloom_config.intensity
+                    || bloom.threshold != bloom_config.threshold
+                {
+                    *bloom_config = bloom;
+                }
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label("Grille spatiale:");
+                    ui.add(
+                        egui::DragValue::new(&mut menu_config.spatial_grid_resolution)
+                            .range(MIN_SPATIAL_GRID_RESOLUTION..=MAX_SPATIAL_GRID_RESOLUTION)
+                            .suffix(" cellules/axe"),
+                    )
+                    .on_hover_text("Plus de cellules : moins de voisins testés, plus de mémoire");
+                    if ui.button("Auto").clicked() {
+                        menu_config.spatial_grid_resolution =
+                            SimulationParameters::default_spatial_grid_resolution(
+                                menu_config.grid_width,
+                                menu_config.max_force_range,
+                            );
+                    }
+                });
+                if menu_config.spatial_grid_resolution.pow(3) > SPATIAL_GRID_CELL_WARNING {
+                    ui.label(
+                        egui::RichText::new("⚠️ Plus d'un million de cellules")
+                            .small()
+                            .color(egui::Color32::from_rgb(255, 150, 0)),
+                    );
+                }
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label("Résolution de la carte de densité:");
+                    ui.add(
+                        egui::DragValue::new(&mut menu_config.heatmap_resolution)
+                            .range(5..=50)
+                            .suffix(" cellules"),
+                    );
+                });
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.checkbox(&mut menu_config.auto_save_enabled, "Sauvegarde automatique");
+                    ui.add_enabled(
+                        menu_config.auto_save_enabled,
+                        egui::DragValue::new(&mut menu_config.auto_save_interval)
+                            .range(1..=500)
+                            .prefix("toutes les ")
+                            .suffix(" époques"),
+                    );
+                });
+
+                ui.add_space(5.0);
+                ui.horizontal(|ui| {
+                    ui.label("Longueur des traînées:");
+                    ui.add(
+                        egui::DragValue::new(&mut menu_config.trail_length)
+                            .range(0..=200)
+                            .suffix(" positions"),
+                    );
+                    if menu_config.trail_length == 0 {
+                        ui.label(
+                            egui::RichText::new("(désactivées)")
+                                .small()
+                                .color(egui::Color32::GRAY),
+                        );
+                    }
+                });
+
+                ui.add_space(5.0);
+                ui.checkbox(&mut menu_config.speed_coloring, "Luminosité selon la vitesse")
+                    .on_hover_text("Particules lentes ternes, rapides brillantes");
+            });
+
+            ui.add_space(10.0);
+
+            // === Balayage de paramètres ===
+            ui.group(|ui| {
+                ui.label(egui::RichText::new("Balayage de Paramètres").size(16.0).strong());
+                ui.separator();
+
+                let active = sweep.is_active();
+                ui.add_enabled_ui(!active, |ui| {
+                    egui::Grid::new("sweep_params")
+                        .num_columns(2)
+                        .spacing([20.0, 5.0])
+                        .show(ui, |ui| {
+                            ui.label("Taux de mutation:");
+                            ui.text_edit_singleline(&mut sweep.mutation_rates_input)
+                                .on_hover_text("Valeurs entre 0 et 1 séparées par des virgules");
+                            ui.end_row();
+
+                            ui.label("Types de particules:");
+                            ui.text_edit_singleline(&mut sweep.particle_types_input)
+                                .on_hover_text("Valeurs entre 2 et 5 séparées par des virgules");
+                            ui.end_row();
+
+                            ui.label("Époques par combinaison:");
+                            ui.add(egui::DragValue::new(&mut sweep.sweep_epochs).range(1..=100));
+                            ui.end_row();
+
+                            ui.label("Graine:");
+                            ui.add(egui::DragValue::new(&mut sweep.seed));
+                            ui.end_row();
+                        });
+                });
+
+                ui.add_space(5.0);
+
+                if active {
+                    ui.add(
+                        egui::ProgressBar::new(sweep.progress())
+                            .text(format!("Combination {}/{}", sweep.current(), sweep.total())),
+                    );
+                    if ui.button("⏹ Interrompre le balayage").clicked() {
+                        sweep.cancel();
+                    }
+                } else {
+                    let grid = sweep.parse_grid();
+                    ui.horizontal(|ui| {
+                        let hover_text = match &grid {
+                            Some(combinations) => format!(
+                                "{} combinaisons × {} époques, résultats dans {}",
+                                combinations.len(),
+                                sweep.sweep_epochs,
+                                SWEEP_RESULTS_PATH
+                            ),
+                            None => "Listes de valeurs invalides".to_string(),
+                        };
+                        if ui
+                            .add_enabled(grid.is_some(), egui::Button::new("🔬 Lancer le balayage"))
+                            .on_hover_text(&hover_text)
+                            .on_disabled_hover_text(&hover_text)
+                            .clicked()
+                        {
+                            sweep.start(&menu_config);
+                        }
+                    });
+
+                    if !sweep.results().is_empty() {
+                        ui.label(
+                            egui::RichText::new(format!(
+                                "✅ {} combinaisons terminées, résultats dans {}",
+                                sweep.results().len(),
+                                SWEEP_RESULTS_PATH
+                            ))
+                            .small()
+                            .color(egui::Color32::GRAY),
+                        );
+                    }
+                }
             });
 
             ui.add_space(20.0);
@@ -456,6 +1620,31 @@ pub fn main_menu_ui(
 
                     ui.add_space(10.0);
 
+                    // Bouton Bac à sable
+                    if ui
+                        .add_sized(
+                            [140.0, 50.0],
+                            egui::Button::new(egui::RichText::new("Bac à sable").size(16.0))
+                                .fill(egui::Color32::from_rgb(150, 100, 30)),
+                        )
+                        .on_hover_text(
+                            "Une simulation sans algorithme génétique, forces éditables en direct",
+                        )
+                        .clicked()
+                    {
+                        apply_configuration(&mut commands, &menu_config);
+                        // Physique CPU : le calcul GPU n'est branché que sur la simulation
+                        commands.insert_resource(ComputeEnabled(false));
+                        commands.insert_resource(VisualizerGenome(Genotype::random(
+                            menu_config.particle_types,
+                            menu_config.food_type_count,
+                            &mut rand::rng(),
+                        )));
+                        next_state.set(AppState::Sandbox);
+                    }
+
+                    ui.add_space(10.0);
+
                     // Bouton Visualiseur
                     if ui
                         .add_sized(
@@ -490,13 +1679,27 @@ pub fn main_menu_ui(
                 // Bouton secondaire : Réinitialiser
                 if ui
                     .button(egui::RichText::new("⚙ Réinitialiser").size(14.0))
-                    .on_hover_text("Remet tous les paramètres aux valeurs par défaut")
+                    .on_hover_text(
+                        "Remet tous les paramètres aux valeurs par défaut et efface le bilan",
+                    )
                     .clicked()
                 {
-                    *menu_config = MenuConfig::default();
+                    let presets = std::mem::take(&mut menu_config.presets);
+                    *menu_config = MenuConfig::from_global_config(&global_config);
+                    menu_config.presets = presets;
+                    *selected_preset = None;
+                    session_stats.clear();
                 }
             });
 
+            // === Bilan de la session ===
+            ui.add_space(10.0);
+            egui::CollapsingHeader::new("📊 Session Summary")
+                .default_open(!session_stats.is_empty())
+                .show(ui, |ui| {
+                    session_stats.display_egui(ui);
+                });
+
             ui.add_space(20.0);
 
             // === Informations système ===
@@ -523,42 +1726,225 @@ pub fn main_menu_ui(
     });
 }
 
-fn apply_configuration(commands: &mut Commands, config: &MenuConfig) {
+/// Propose de reprendre la session interrompue trouvée au démarrage
+fn resume_session_dialog(
+    ctx: &egui::Context,
+    previous_session: &mut PreviousSession,
+    menu_config: &mut MenuConfig,
+    commands: &mut Commands,
+    next_state: &mut NextState<AppState>,
+) {
+    let Some(session) = previous_session.0.as_ref() else {
+        return;
+    };
+
+    let mut resume = false;
+    let mut dismiss = false;
+
+    egui::Window::new("Session précédente")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label("Reprendre la session précédente ?");
+            ui.label(
+                egui::RichText::new(format!(
+                    "Époque {} • {} simulations",
+                    session.epoch,
+                    session.genomes.len()
+                ))
+                .small()
+                .color(egui::Color32::GRAY),
+            );
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add(
+                        egui::Button::new("▶ Oui, reprendre")
+                            .fill(egui::Color32::from_rgb(0, 120, 215)),
+                    )
+                    .clicked()
+                {
+                    resume = true;
+                }
+                if ui.button("Non").clicked() {
+                    dismiss = true;
+                }
+            });
+        });
+
+    if resume {
+        let Some(session) = previous_session.0.take() else {
+            return;
+        };
+
+        // La forme des génomes sauvegardés impose le nombre de simulations et de types
+        if let Some(first) = session.genomes.first() {
+            menu_config.simulation_count = session.genomes.len();
+            menu_config.particle_types = first.genotype.type_count;
+            menu_config.food_type_count = first.genotype.food_type_count;
+            menu_config.particle_radii.resize(first.genotype.type_count, PARTICLE_RADIUS);
+            menu_config
+                .particle_velocity_half_lives
+                .resize(first.genotype.type_count, DEFAULT_VELOCITY_HALF_LIFE);
+            if let Some(custom_colors) = menu_config.custom_colors.as_mut() {
+                custom_colors.truncate(first.genotype.type_count);
+                if custom_colors.len() < first.genotype.type_count {
+                    menu_config.custom_colors = None;
+                }
+            }
+        }
+
+        apply_configuration(commands, menu_config);
+        commands.insert_resource(ResumeSession(session));
+        next_state.set(AppState::Simulation);
+    } else if dismiss {
+        previous_session.0 = None;
+    }
+}
+
+pub fn apply_configuration(commands: &mut Commands, config: &MenuConfig) {
     // Insérer les ressources configurées
     commands.insert_resource(GridParameters {
         width: config.grid_width,
         height: config.grid_height,
-        depth: config.grid_depth,
+        depth: if config.dimension.is_flat() {
+            FLAT_GRID_DEPTH
+        } else {
+            config.grid_depth
+        },
     });
 
+    // Graine effective : celle du menu (ou de --seed), sinon tirée au hasard
+    let rng_seed = if config.use_fixed_seed {
+        config.rng_seed
+    } else {
+        rand::rng().next_u64()
+    };
+    commands.insert_resource(RunSeed(Some(rng_seed)));
+    info!("🎲 Graine de la simulation: {}", rng_seed);
+
+    if config.enable_hard_collision && config.use_gpu {
+        warn!("⚠️ Collisions entre particules ignorées avec le calcul GPU");
+    }
+    if config.inter_simulation_interaction && config.use_gpu {
+        warn!("⚠️ Interactions entre simulations ignorées avec le calcul GPU");
+    }
+    if config.energy_budget_enabled && config.use_gpu {
+        warn!("⚠️ Budget d'énergie sans naissances avec le calcul GPU");
+    }
+
+    let velocity_half_life_schedule = config
+        .half_life_schedule_enabled
+        .then_some(config.half_life_schedule);
+
     commands.insert_resource(SimulationParameters {
         current_epoch: 0,
         max_epochs: config.max_epochs,
+        on_max_epochs: config.on_max_epochs,
         epoch_duration: config.epoch_duration,
         epoch_timer: Timer::from_seconds(config.epoch_duration, TimerMode::Once),
         simulation_count: config.simulation_count,
         particle_count: config.particle_count,
         particle_types: config.particle_types,
         simulation_speed: SimulationSpeed::Normal,
+        simulation_mode: config.simulation_mode,
+        dimension: config.dimension,
+        rolling_selection_interval: config.rolling_selection_interval,
+        rng_seed: Some(rng_seed),
+        auto_save_interval: if config.auto_save_enabled {
+            Some(config.auto_save_interval.max(1))
+        } else {
+            None
+        },
         max_force_range: config.max_force_range,
-        velocity_half_life: 0.043,
+        velocity_half_life: velocity_half_life_schedule
+            .map_or(DEFAULT_VELOCITY_HALF_LIFE, |(start, _)| start),
+        velocity_half_life_schedule,
+        decay_curve: config.half_life_decay,
+        spatial_grid_resolution: SimulationParameters::clamp_spatial_grid_resolution(
+            config.spatial_grid_resolution,
+        ),
+        use_ghost_particles: config.use_ghost_particles,
+        inter_simulation_interaction: config.inter_simulation_interaction,
+        inter_sim_force_scale: config.inter_sim_force_scale,
+        type_migration_rate: config.type_migration_rate,
+        particle_lifespan: if config.lifespan_enabled {
+            Some(config.particle_lifespan)
+        } else {
+            None
+        },
+        energy_budget: config
+            .energy_budget_enabled
+            .then_some(config.energy_birth_threshold),
+        gravity: config.gravity_enabled.then(|| Vec3::from_array(config.gravity)),
+        enable_hard_collision: config.enable_hard_collision,
+        oscillating_forces: config.oscillating_forces,
+        force_oscillation_period: config.force_oscillation_period,
         elite_ratio: config.elite_ratio,
+        tournament_size: config.tournament_size.clamp(2, 10),
         mutation_rate: config.mutation_rate,
         crossover_rate: config.crossover_rate,
+        crossover_operator: config.crossover_operator,
+        genome_encoding: config.genome_encoding,
+        gene_expression_threshold: 0.0,
+        symmetric_forces: config.symmetric_forces,
+        objectives: if config.objectives.is_empty() {
+            vec![ObjectiveFn::FoodEaten]
+        } else {
+            config.objectives.clone()
+        },
+        use_speciation: config.use_speciation,
+        niche_radius: config.niche_radius,
+        use_novelty_search: config.use_novelty_search,
+        novelty_weight: config.novelty_weight,
+        island_count: config.island_count,
+        migration_interval: config.migration_interval,
+        stagnation_window: DEFAULT_STAGNATION_WINDOW,
+        stagnation_threshold: DEFAULT_STAGNATION_THRESHOLD,
+        cluster_bonus_multiplier: config.cluster_bonus_multiplier,
+        trail_length: config.trail_length,
+        speed_coloring: config.speed_coloring,
     });
 
-    commands.insert_resource(ParticleTypesConfig::new(config.particle_types));
+    let mut particle_config = ParticleTypesConfig::new(config.particle_types);
+    particle_config.set_palette(config.color_palette);
+    for (type_index, radius) in config.particle_radii.iter().enumerate() {
+        if let Some(target) = particle_config.radii.get_mut(type_index) {
+            *target = *radius;
+        }
+    }
+    for (type_index, half_life) in config.particle_velocity_half_lives.iter().enumerate() {
+        if let Some(target) = particle_config.velocity_half_lives.get_mut(type_index) {
+            *target = *half_life;
+        }
+    }
+    if let Some(custom_colors) = &config.custom_colors {
+        particle_config.apply_custom_colors(custom_colors);
+    }
+    particle_config.type_names =
+        ParticleTypesConfig::sanitize_type_names(&config.particle_type_names, config.particle_types);
+    commands.insert_resource(particle_config);
 
     commands.insert_resource(FoodParameters {
         food_count: config.food_count,
         respawn_enabled: config.food_respawn_enabled,
         respawn_cooldown: config.food_respawn_time,
         food_value: config.food_value,
+        food_type_count: config.food_type_count,
+        food_decay_per_epoch: config.food_decay_per_epoch,
+        food_growth_per_epoch: config.food_growth_per_epoch,
+        ..default()
     });
 
     commands.insert_resource(config.boundary_mode);
 
+    commands.insert_resource(PredationEnabled(config.predation_enabled));
+    commands.insert_resource(PredationStrength(config.predation_strength));
+
     commands.insert_resource(ComputeEnabled(config.use_gpu));
+    commands.insert_resource(DensityHeatmap::new(config.heatmap_resolution));
 
     info!("Configuration appliquée:");
     info!(
@@ -577,11 +1963,18 @@ fn apply_configuration(commands: &mut Commands, config: &MenuConfig) {
             .min(8))
     );
     info!(
-        "  • Algorithme génétique: {:.0}% élites, {:.0}% mutation, {:.0}% crossover",
+        "  • Algorithme génétique: {:.0}% élites, {:.0}% mutation, {:.0}% crossover, tournois de {}",
         config.elite_ratio * 100.0,
         config.mutation_rate * 100.0,
-        config.crossover_rate * 100.0
+        config.crossover_rate * 100.0,
+        config.tournament_size
     );
+    if config.island_count > 1 {
+        info!(
+            "  • Modèle en îles: {} îles, migration toutes les {} époques",
+            config.island_count, config.migration_interval
+        );
+    }
     info!(
         "  • GPU Compute: {}",
         if config.use_gpu {
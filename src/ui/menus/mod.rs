@@ -1,2 +1,3 @@
 pub mod main_menu;
+pub mod presets;
 pub mod visualizer_menu;
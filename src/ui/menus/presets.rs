@@ -0,0 +1,112 @@
+use crate::resources::config::particle_types::ParticleTypesConfig;
+use crate::ui::menus::main_menu::MenuConfig;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+
+/// Fichier des configurations nommées
+pub const PRESETS_FILE_PATH: &str = "presets.toml";
+
+/// Configuration complète du menu enregistrée sous un nom
+#[derive(Clone, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    pub config: MenuConfig,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct PresetsFile {
+    #[serde(default)]
+    presets: Vec<Preset>,
+}
+
+/// Préréglages livrés avec le programme
+pub fn builtin_presets() -> Vec<Preset> {
+    let standard = MenuConfig::default();
+
+    let quick_test = MenuConfig {
+        particle_count: 50,
+        particle_types: 2,
+        epoch_duration: 20.0,
+        particle_radii: ParticleTypesConfig::generate_radii(2),
+        particle_velocity_half_lives: ParticleTypesConfig::generate_velocity_half_lives(2),
+        particle_type_names: ParticleTypesConfig::generate_type_names(2),
+        ..standard.clone()
+    };
+
+    let large_scale = MenuConfig {
+        simulation_count: 5,
+        particle_count: 500,
+        particle_types: 4,
+        particle_radii: ParticleTypesConfig::generate_radii(4),
+        particle_velocity_half_lives: ParticleTypesConfig::generate_velocity_half_lives(4),
+        particle_type_names: ParticleTypesConfig::generate_type_names(4),
+        use_gpu: true,
+        ..standard.clone()
+    };
+
+    vec![
+        Preset {
+            name: "Quick Test".to_string(),
+            config: quick_test,
+        },
+        Preset {
+            name: "Standard".to_string(),
+            config: standard,
+        },
+        Preset {
+            name: "Large Scale".to_string(),
+            config: large_scale,
+        },
+    ]
+}
+
+/// Charge `presets.toml`, ou l'écrit avec les préréglages intégrés s'il n'existe pas
+pub fn load_presets() -> Vec<Preset> {
+    let path = Path::new(PRESETS_FILE_PATH);
+
+    if !path.exists() {
+        let presets = builtin_presets();
+        match write_presets(&presets) {
+            Ok(()) => info!("Préréglages par défaut créés: {:?}", path),
+            Err(e) => warn!("Impossible d'écrire {:?}: {}", path, e),
+        }
+        return presets;
+    }
+
+    match fs::read_to_string(path).map(|content| toml::from_str::<PresetsFile>(&content)) {
+        Ok(Ok(file)) => {
+            info!("{} préréglages chargés depuis {:?}", file.presets.len(), path);
+            file.presets
+        }
+        Ok(Err(e)) => {
+            warn!("Erreur de lecture de {:?}, préréglages intégrés utilisés: {}", path, e);
+            builtin_presets()
+        }
+        Err(e) => {
+            warn!("Impossible de lire {:?}: {}", path, e);
+            builtin_presets()
+        }
+    }
+}
+
+/// Ajoute ou remplace un préréglage puis réécrit `presets.toml`
+pub fn save_preset(presets: &mut Vec<Preset>, preset: Preset) {
+    match presets.iter_mut().find(|existing| existing.name == preset.name) {
+        Some(existing) => *existing = preset,
+        None => presets.push(preset),
+    }
+
+    if let Err(e) = write_presets(presets) {
+        warn!("Impossible d'écrire {:?}: {}", PRESETS_FILE_PATH, e);
+    }
+}
+
+fn write_presets(presets: &[Preset]) -> Result<(), Box<dyn std::error::Error>> {
+    let file = PresetsFile {
+        presets: presets.to_vec(),
+    };
+    fs::write(PRESETS_FILE_PATH, toml::to_string_pretty(&file)?)?;
+    Ok(())
+}
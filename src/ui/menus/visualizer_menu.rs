@@ -1,14 +1,62 @@
 use crate::components::genetics::genotype::Genotype;
+use crate::resources::config::random::RunSeed;
 use crate::states::app::AppState;
+use crate::systems::persistence::archive::{PopulationExport, PopulationExportState};
 use crate::systems::persistence::population_save::*;
+use crate::systems::persistence::watcher::FileWatcher;
+use crate::ui::dialogs::save_population::SavePopulationUI;
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
 
-#[derive(Resource, Default)]
+#[derive(Resource)]
 pub struct VisualizerSelection {
     pub selected_population: Option<SavedPopulation>,
     pub search_filter: String,
+    pub tag_filter: String,
+    pub starred_only: bool,
+    pub tag_input: String, // Étiquettes en cours d'édition dans la fenêtre de détails
+    pub min_score: f32,
+    pub max_score: f32,
     pub sort_by: PopulationSortBy,
+    pub delete_pending: Option<String>, // Chemin du fichier à supprimer
+    pub merge_selection: Vec<String>,   // Chemins des populations cochées pour la fusion
+    pub show_merge_dialog: bool,
+    pub merge_mode: MergeMode,
+    pub merge_blend: f32, // 0 = population A seule, 1 = population B seule
+    pub export_selection: Vec<String>,  // Chemins des populations cochées pour l'export ZIP
+    pub similar_to: Option<SavedPopulation>, // Référence du tri par distance génétique
+    pub distance_matrix: Option<DistanceMatrix>,
+}
+
+impl Default for VisualizerSelection {
+    fn default() -> Self {
+        Self {
+            selected_population: None,
+            search_filter: String::new(),
+            tag_filter: String::new(),
+            starred_only: false,
+            tag_input: String::new(),
+            min_score: 0.0,
+            max_score: f32::MAX,
+            sort_by: PopulationSortBy::default(),
+            delete_pending: None,
+            merge_selection: Vec::new(),
+            show_merge_dialog: false,
+            merge_mode: MergeMode::default(),
+            merge_blend: 0.5,
+            export_selection: Vec::new(),
+            similar_to: None,
+            distance_matrix: None,
+        }
+    }
+}
+
+/// Fusion de deux populations : interpolation des gènes ou crossover uniforme
+#[derive(Clone, Copy, Default, PartialEq, Eq)]
+pub enum MergeMode {
+    #[default]
+    Blend,
+    Crossover,
 }
 
 #[derive(Default, PartialEq)]
@@ -18,6 +66,36 @@ pub enum PopulationSortBy {
     Name,
     Score,
     ParticleCount,
+    Distance, // Distance génétique à `similar_to`
+}
+
+/// Distances génétiques entre toutes les populations chargées
+pub struct DistanceMatrix {
+    pub names: Vec<String>,
+    pub distances: Vec<Vec<f32>>,
+    pub max_distance: f32,
+}
+
+impl DistanceMatrix {
+    pub fn compute(populations: &[SavedPopulation]) -> Self {
+        let distances: Vec<Vec<f32>> = populations
+            .iter()
+            .map(|a| {
+                populations
+                    .iter()
+                    .map(|b| genotype_distance(&a.genotype, &b.genotype))
+                    .collect()
+            })
+            .collect();
+
+        let max_distance = distances.iter().flatten().copied().fold(0.0, f32::max);
+
+        Self {
+            names: populations.iter().map(|population| population.name.clone()).collect(),
+            distances,
+            max_distance,
+        }
+    }
 }
 
 /// Ressource pour stocker le génome à visualiser
@@ -29,7 +107,10 @@ pub fn visualizer_ui(
     mut visualizer: ResMut<VisualizerSelection>,
     mut available: ResMut<AvailablePopulations>, // Changé en mut
     mut next_state: ResMut<NextState<AppState>>,
+    mut save_ui: ResMut<SavePopulationUI>,
     mut commands: Commands,
+    watcher: Option<Res<FileWatcher>>,
+    mut export_state: ResMut<PopulationExportState>,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -60,6 +141,43 @@ pub fn visualizer_ui(
             ui.label("Recherche:");
             ui.text_edit_singleline(&mut visualizer.search_filter);
 
+            ui.label("Tag:");
+            ui.add(egui::TextEdit::singleline(&mut visualizer.tag_filter).desired_width(80.0));
+
+            ui.checkbox(&mut visualizer.starred_only, "⭐ Starred only");
+
+            ui.separator();
+
+            ui.label("Score ≥");
+            let max_score = visualizer.max_score;
+            ui.add(
+                egui::DragValue::new(&mut visualizer.min_score)
+                    .speed(1.0)
+                    .range(0.0..=max_score),
+            );
+            ui.label("≤");
+            let min_score = visualizer.min_score;
+            ui.add(
+                egui::DragValue::new(&mut visualizer.max_score)
+                    .speed(1.0)
+                    .range(min_score..=f32::MAX)
+                    .custom_formatter(|value, _| {
+                        if value >= f32::MAX as f64 {
+                            "∞".to_string()
+                        } else {
+                            format!("{:.0}", value)
+                        }
+                    }),
+            );
+            if ui
+                .small_button("Effacer")
+                .on_hover_text("Réinitialiser la plage de score")
+                .clicked()
+            {
+                visualizer.min_score = 0.0;
+                visualizer.max_score = f32::MAX;
+            }
+
             ui.separator();
 
             ui.label("Trier par:");
@@ -69,6 +187,7 @@ pub fn visualizer_ui(
                     PopulationSortBy::Name => "Nom",
                     PopulationSortBy::Score => "Score",
                     PopulationSortBy::ParticleCount => "Nb. Particules",
+                    PopulationSortBy::Distance => "Distance",
                 })
                 .show_ui(ui, |ui| {
                     ui.selectable_value(&mut visualizer.sort_by, PopulationSortBy::Date, "Date");
@@ -79,10 +198,40 @@ pub fn visualizer_ui(
                         PopulationSortBy::ParticleCount,
                         "Nb. Particules",
                     );
+                    if visualizer.similar_to.is_some() {
+                        ui.selectable_value(
+                            &mut visualizer.sort_by,
+                            PopulationSortBy::Distance,
+                            "Distance",
+                        );
+                    }
                 });
 
+            if let Some(reference) = &visualizer.similar_to {
+                ui.label(
+                    egui::RichText::new(format!("≈ « {} »", reference.name))
+                        .color(egui::Color32::GRAY),
+                )
+                .on_hover_text("Populations triées par distance génétique à cette référence");
+                if ui.small_button("✖").on_hover_text("Oublier la référence").clicked() {
+                    visualizer.similar_to = None;
+                    visualizer.sort_by = PopulationSortBy::Date;
+                }
+            }
+
             ui.separator();
 
+            if ui
+                .add_enabled(
+                    available.populations.len() >= 2,
+                    egui::Button::new("📐 Distance Matrix"),
+                )
+                .on_hover_text("Distances génétiques entre toutes les populations chargées")
+                .clicked()
+            {
+                visualizer.distance_matrix = Some(DistanceMatrix::compute(&available.populations));
+            }
+
             if ui
                 .button("🔄 Recharger")
                 .on_hover_text("Recharge les populations du dossier")
@@ -122,6 +271,7 @@ pub fn visualizer_ui(
         let mut filtered_populations: Vec<_> = available
             .populations
             .iter()
+            .filter(|pop| pop.score >= visualizer.min_score && pop.score <= visualizer.max_score)
             .filter(|pop| {
                 if visualizer.search_filter.is_empty() {
                     true
@@ -134,6 +284,11 @@ pub fn visualizer_ui(
                             .map_or(false, |d| d.to_lowercase().contains(&filter))
                 }
             })
+            .filter(|pop| !visualizer.starred_only || pop.starred)
+            .filter(|pop| {
+                visualizer.tag_filter.trim().is_empty()
+                    || pop.has_tag_matching(visualizer.tag_filter.trim())
+            })
             .collect();
 
         match visualizer.sort_by {
@@ -157,20 +312,169 @@ pub fn visualizer_ui(
                         .cmp(&a.simulation_params.particle_count)
                 });
             }
+            PopulationSortBy::Distance => {
+                if let Some(reference) = &visualizer.similar_to {
+                    filtered_populations.sort_by(|a, b| {
+                        genotype_distance(&a.genotype, &reference.genotype)
+                            .total_cmp(&genotype_distance(&b.genotype, &reference.genotype))
+                    });
+                }
+            }
         }
 
-        ui.label(format!(
-            "Populations trouvées: {} / {}",
-            filtered_populations.len(),
-            available.populations.len()
-        ));
+        // Tri stable : les favoris passent devant sans casser l'ordre choisi
+        filtered_populations.sort_by_key(|pop| !pop.starred);
+
+        ui.horizontal(|ui| {
+            if watcher.is_some() {
+                ui.add(egui::Spinner::new().size(12.0))
+                    .on_hover_text("Surveillance de populations/ : la liste se met à jour seule");
+            }
+
+            ui.label(format!(
+                "Populations trouvées: {} / {}",
+                filtered_populations.len(),
+                available.populations.len()
+            ));
+
+            ui.separator();
+
+            let merge_pair = merge_pair(&visualizer.merge_selection, &available);
+
+            let can_merge = merge_pair.len() == 2 && merge_pair[0].is_mergeable_with(merge_pair[1]);
+            let hover_text = if merge_pair.len() != 2 {
+                "Cochez exactement deux populations"
+            } else if !can_merge {
+                "Les deux génomes n'ont pas le même nombre de types"
+            } else {
+                "Nouvelle population mêlant les génomes des deux populations"
+            };
+
+            if ui
+                .add_enabled(can_merge, egui::Button::new("🧬 Merge Populations"))
+                .on_hover_text(hover_text)
+                .on_disabled_hover_text(hover_text)
+                .clicked()
+            {
+                visualizer.show_merge_dialog = true;
+            }
+
+            if !visualizer.export_selection.is_empty() {
+                ui.separator();
+
+                if ui
+                    .add_enabled(
+                        export_state.job.is_none(),
+                        egui::Button::new(format!(
+                            "📦 Export selected ({})",
+                            visualizer.export_selection.len()
+                        )),
+                    )
+                    .on_hover_text("Regrouper les populations cochées dans une archive ZIP")
+                    .clicked()
+                {
+                    let selected: Vec<SavedPopulation> = available
+                        .populations
+                        .iter()
+                        .filter(|pop| {
+                            let path = population_file_path(pop).to_string_lossy().into_owned();
+                            visualizer.export_selection.contains(&path)
+                        })
+                        .cloned()
+                        .collect();
+
+                    match PopulationExport::start(selected) {
+                        Ok(job) => {
+                            info!("Export de {} population(s) démarré", job.total());
+                            export_state.job = Some(job);
+                            export_state.result = None;
+                        }
+                        Err(e) => {
+                            error!("Impossible de créer l'archive: {}", e);
+                            export_state.result = Some(Err(e.to_string()));
+                        }
+                    }
+                }
+            }
+        });
+
+        let mut repair_requested: Option<String> = None;
+        let mut star_toggled: Option<String> = None;
 
         egui::ScrollArea::vertical().show(ui, |ui| {
             for population in filtered_populations {
                 ui.group(|ui| {
                     ui.horizontal(|ui| {
+                        let path = population_file_path(population).to_string_lossy().into_owned();
+                        let mut checked = visualizer.merge_selection.contains(&path);
+                        if ui
+                            .checkbox(&mut checked, "")
+                            .on_hover_text("Sélectionner pour la fusion")
+                            .changed()
+                        {
+                            if checked {
+                                visualizer.merge_selection.push(path.clone());
+                            } else {
+                                visualizer.merge_selection.retain(|selected| *selected != path);
+                            }
+                        }
+
+                        let mut export_checked = visualizer.export_selection.contains(&path);
+                        if ui
+                            .checkbox(&mut export_checked, "📦")
+                            .on_hover_text("Sélectionner pour l'export")
+                            .changed()
+                        {
+                            if export_checked {
+                                visualizer.export_selection.push(path);
+                            } else {
+                                visualizer.export_selection.retain(|selected| *selected != path);
+                            }
+                        }
+
+                        if population.is_corrupted {
+                            ui.label(egui::RichText::new("⚠").size(16.0).color(egui::Color32::RED))
+                                .on_hover_text(
+                                    "Checksum incorrect : le fichier a pu être modifié ou corrompu",
+                                );
+                        }
+
+                        let star = if population.starred {
+                            egui::RichText::new("⭐").color(egui::Color32::GOLD)
+                        } else {
+                            egui::RichText::new("⭐").color(egui::Color32::DARK_GRAY)
+                        };
+                        if ui
+                            .add(egui::Button::new(star).frame(false))
+                            .on_hover_text("Ajouter ou retirer des favoris")
+                            .clicked()
+                        {
+                            star_toggled = Some(
+                                population_file_path(population).to_string_lossy().into_owned(),
+                            );
+                        }
+
                         ui.label(egui::RichText::new(&population.name).size(16.0).strong());
 
+                        for tag in &population.tags {
+                            ui.label(
+                                egui::RichText::new(format!("#{}", tag))
+                                    .small()
+                                    .color(egui::Color32::WHITE)
+                                    .background_color(egui::Color32::from_rgb(60, 90, 140)),
+                            );
+                        }
+
+                        if let Some(reference) = &visualizer.similar_to {
+                            let distance =
+                                genotype_distance(&population.genotype, &reference.genotype);
+                            ui.label(
+                                egui::RichText::new(format!("Δ {:.2}", distance))
+                                    .color(egui::Color32::GRAY),
+                            )
+                            .on_hover_text(format!("Distance génétique à « {} »", reference.name));
+                        }
+
                         ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
                             ui.label(
                                 egui::RichText::new(&population.timestamp)
@@ -217,6 +521,7 @@ pub fn visualizer_ui(
                             ui.label(match population.boundary_mode {
                                 SavedBoundaryMode::Bounce => "Rebond",
                                 SavedBoundaryMode::Teleport => "Téléport",
+                                SavedBoundaryMode::SoftWall { .. } => "Murs mous",
                             });
                             ui.end_row();
                         });
@@ -249,6 +554,49 @@ pub fn visualizer_ui(
                             .clicked()
                         {
                             visualizer.selected_population = Some(population.clone());
+                            visualizer.tag_input = population.tags.join(", ");
+                        }
+
+                        // Bouton discret, révélé en rouge au survol pour éviter les clics accidentels
+                        let hover_id = ui.make_persistent_id(("delete_hover", &population.timestamp));
+                        let revealed = ui
+                            .ctx()
+                            .data(|data| data.get_temp::<bool>(hover_id))
+                            .unwrap_or(false);
+
+                        let delete_button = if revealed {
+                            egui::Button::new(
+                                egui::RichText::new("🗑 Supprimer").color(egui::Color32::WHITE),
+                            )
+                            .fill(egui::Color32::from_rgb(180, 40, 40))
+                        } else {
+                            egui::Button::new(
+                                egui::RichText::new("🗑").color(egui::Color32::DARK_GRAY),
+                            )
+                            .frame(false)
+                        };
+
+                        let response = ui
+                            .add_sized([120.0, 40.0], delete_button)
+                            .on_hover_text("Supprimer définitivement cette population");
+                        ui.ctx()
+                            .data_mut(|data| data.insert_temp(hover_id, response.hovered()));
+
+                        if revealed && response.clicked() {
+                            visualizer.delete_pending = Some(
+                                population_file_path(population).to_string_lossy().into_owned(),
+                            );
+                        }
+
+                        if population.is_corrupted
+                            && ui
+                                .add_sized([160.0, 40.0], egui::Button::new("🔧 Réparer le checksum"))
+                                .on_hover_text("Réécrit le fichier avec un checksum recalculé")
+                                .clicked()
+                        {
+                            repair_requested = Some(
+                                population_file_path(population).to_string_lossy().into_owned(),
+                            );
                         }
                     });
                 });
@@ -257,18 +605,372 @@ pub fn visualizer_ui(
             }
         });
 
+        if let Some(population) = repair_requested.and_then(|path| {
+            available
+                .populations
+                .iter_mut()
+                .find(|population| population_file_path(population).to_string_lossy() == path)
+        }) {
+            if let Err(e) = repair_population_checksum(population) {
+                error!("Impossible de réparer '{}': {}", population.name, e);
+            }
+        }
+
+        if let Some(population) = star_toggled.and_then(|path| {
+            available
+                .populations
+                .iter_mut()
+                .find(|population| population_file_path(population).to_string_lossy() == path)
+        }) {
+            population.starred = !population.starred;
+            if let Err(e) = update_population_metadata(population) {
+                error!("Impossible de mettre à jour '{}': {}", population.name, e);
+            }
+        }
+
         if let Some(ref selected) = visualizer.selected_population.clone() {
-            show_population_details(ctx, &mut visualizer.selected_population, selected);
+            let selection = &mut *visualizer;
+            let actions = show_population_details(
+                ctx,
+                &mut selection.selected_population,
+                &mut selection.tag_input,
+                selected,
+            );
+            if actions.find_similar {
+                visualizer.similar_to = Some(selected.clone());
+                visualizer.sort_by = PopulationSortBy::Distance;
+            }
+
+            let path = population_file_path(selected).to_string_lossy().into_owned();
+            let edited = available
+                .populations
+                .iter_mut()
+                .find(|population| population_file_path(population).to_string_lossy() == path);
+            if let Some((tags, population)) = actions.new_tags.zip(edited) {
+                population.tags = tags;
+                match update_population_metadata(population) {
+                    Ok(()) => info!("Étiquettes de '{}' mises à jour", population.name),
+                    Err(e) => error!("Impossible de mettre à jour '{}': {}", population.name, e),
+                }
+                if let Some(details) = visualizer.selected_population.as_mut() {
+                    details.tags = population.tags.clone();
+                }
+            }
         }
+
+        let matrix_closed = visualizer
+            .distance_matrix
+            .as_ref()
+            .is_some_and(|matrix| !show_distance_matrix(ctx, matrix));
+        if matrix_closed {
+            visualizer.distance_matrix = None;
+        }
+
+        if let Some(path) = visualizer.delete_pending.clone() {
+            show_delete_confirmation(ctx, &mut visualizer, &mut available, &path);
+        }
+
+        if visualizer.show_merge_dialog {
+            show_merge_dialog(ctx, &mut visualizer, &available, &mut save_ui);
+        }
+
+        show_export_progress(ctx, &mut export_state);
     });
 }
 
+/// Populations cochées pour la fusion, dans l'ordre où elles ont été cochées
+fn merge_pair<'a>(
+    merge_selection: &[String],
+    available: &'a AvailablePopulations,
+) -> Vec<&'a SavedPopulation> {
+    merge_selection
+        .iter()
+        .filter_map(|path| {
+            available
+                .populations
+                .iter()
+                .find(|pop| population_file_path(pop).to_string_lossy() == *path)
+        })
+        .collect()
+}
+
+/// Réglage de la fusion (A = première population cochée), puis dialogue de sauvegarde
+fn show_merge_dialog(
+    ctx: &egui::Context,
+    visualizer: &mut VisualizerSelection,
+    available: &AvailablePopulations,
+    save_ui: &mut SavePopulationUI,
+) {
+    let pair = merge_pair(&visualizer.merge_selection, available);
+    let [a, b] = pair.as_slice() else {
+        // Sélection modifiée entre-temps
+        visualizer.show_merge_dialog = false;
+        return;
+    };
+
+    let mut open = true;
+    let mut merged = None;
+
+    egui::Window::new("🧬 Merge Populations")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .open(&mut open)
+        .show(ctx, |ui| {
+            ui.label(format!("A : {}", a.name));
+            ui.label(format!("B : {}", b.name));
+            ui.add_space(5.0);
+
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut visualizer.merge_mode, MergeMode::Blend, "Interpolation")
+                    .on_hover_text("Chaque gène vaut A × (1 − blend) + B × blend");
+                ui.radio_value(&mut visualizer.merge_mode, MergeMode::Crossover, "Crossover")
+                    .on_hover_text("Chaque gène vient de A ou de B, au hasard");
+            });
+
+            ui.add_enabled(
+                visualizer.merge_mode == MergeMode::Blend,
+                egui::Slider::new(&mut visualizer.merge_blend, 0.0..=1.0).text("Blend (A → B)"),
+            );
+
+            ui.label(
+                egui::RichText::new("Paramètres de simulation repris de A")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                if ui.button("✅ Fusionner").clicked() {
+                    merged = Some(match visualizer.merge_mode {
+                        MergeMode::Blend => merge_populations(a, b, visualizer.merge_blend),
+                        MergeMode::Crossover => crossover_populations(a, b),
+                    });
+                }
+                if ui.button("❌ Annuler").clicked() {
+                    visualizer.show_merge_dialog = false;
+                }
+            });
+        });
+
+    if let Some(merged) = merged {
+        info!("Fusion de '{}' et '{}'", a.name, b.name);
+        save_ui.open_for_merge(merged);
+        visualizer.show_merge_dialog = false;
+    }
+    if !open {
+        visualizer.show_merge_dialog = false;
+    }
+}
+
+fn show_export_progress(ctx: &egui::Context, export_state: &mut PopulationExportState) {
+    if let Some(job) = &export_state.job {
+        egui::Window::new("Export des populations")
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.add(
+                    egui::ProgressBar::new(job.progress())
+                        .text(format!("{}/{}", job.written(), job.total()))
+                        .desired_width(300.0),
+                );
+            });
+        return;
+    }
+
+    let Some(result) = &export_state.result else {
+        return;
+    };
+
+    let mut acknowledged = false;
+    egui::Window::new("Export des populations")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            match result {
+                Ok(path) => {
+                    ui.label("✅ Archive créée :");
+                    ui.monospace(path.display().to_string());
+                }
+                Err(e) => {
+                    ui.colored_label(egui::Color32::RED, format!("❌ Échec de l'export: {}", e));
+                }
+            }
+            ui.add_space(10.0);
+            if ui.button("OK").clicked() {
+                acknowledged = true;
+            }
+        });
+
+    if acknowledged {
+        export_state.result = None;
+    }
+}
+
+fn show_delete_confirmation(
+    ctx: &egui::Context,
+    visualizer: &mut VisualizerSelection,
+    available: &mut AvailablePopulations,
+    path: &str,
+) {
+    let is_target = |population: &SavedPopulation| {
+        population_file_path(population).to_string_lossy() == path
+    };
+    let name = available
+        .populations
+        .iter()
+        .find(|population| is_target(population))
+        .map(|population| population.name.clone())
+        .unwrap_or_else(|| path.to_string());
+
+    let mut confirmed = false;
+    let mut cancelled = false;
+
+    egui::Window::new("Confirmer la suppression")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            ui.label(format!("Supprimer « {} » ? Cette action est irréversible.", name));
+            ui.add_space(10.0);
+
+            ui.horizontal(|ui| {
+                if ui
+                    .add(
+                        egui::Button::new(
+                            egui::RichText::new("🗑 Supprimer").color(egui::Color32::WHITE),
+                        )
+                        .fill(egui::Color32::from_rgb(180, 40, 40)),
+                    )
+                    .clicked()
+                {
+                    confirmed = true;
+                }
+
+                if ui.button("Annuler").clicked() {
+                    cancelled = true;
+                }
+            });
+        });
+
+    if confirmed {
+        let removed = match std::fs::remove_file(path) {
+            Ok(()) => {
+                info!("Population '{}' supprimée", name);
+                true
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                warn!("Fichier déjà absent, retiré de la liste: {}", path);
+                true
+            }
+            Err(e) => {
+                error!("Impossible de supprimer {}: {}", path, e);
+                false
+            }
+        };
+
+        if removed {
+            // Copie binaire éventuelle
+            let binary_path = std::path::Path::new(path).with_extension("bin");
+            match std::fs::remove_file(&binary_path) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+                Err(e) => warn!("Impossible de supprimer {:?}: {}", binary_path, e),
+            }
+
+            available.populations.retain(|population| !is_target(population));
+            visualizer.merge_selection.retain(|selected| selected != path);
+            visualizer.export_selection.retain(|selected| selected != path);
+            if visualizer
+                .selected_population
+                .as_ref()
+                .is_some_and(|population| is_target(population))
+            {
+                visualizer.selected_population = None;
+            }
+        }
+
+        visualizer.delete_pending = None;
+    } else if cancelled {
+        visualizer.delete_pending = None;
+    }
+}
+
+/// Fenêtre de la matrice des distances ; renvoie `false` une fois fermée
+fn show_distance_matrix(ctx: &egui::Context, matrix: &DistanceMatrix) -> bool {
+    let mut is_open = true;
+    let short_name = |name: &String| name.chars().take(10).collect::<String>();
+
+    egui::Window::new("📐 Distance Matrix")
+        .open(&mut is_open)
+        .resizable(true)
+        .default_width(500.0)
+        .show(ctx, |ui| {
+            ui.label(
+                egui::RichText::new("Vert = génomes proches • Rouge = génomes éloignés")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+            ui.add_space(5.0);
+
+            egui::ScrollArea::both().show(ui, |ui| {
+                egui::Grid::new("distance_matrix")
+                    .spacing([4.0, 4.0])
+                    .show(ui, |ui| {
+                        ui.label("");
+                        for name in &matrix.names {
+                            ui.label(egui::RichText::new(short_name(name)).small())
+                                .on_hover_text(name);
+                        }
+                        ui.end_row();
+
+                        for (name, row) in matrix.names.iter().zip(&matrix.distances) {
+                            ui.label(egui::RichText::new(short_name(name)).small())
+                                .on_hover_text(name);
+                            for distance in row {
+                                let t = if matrix.max_distance > 0.0 {
+                                    distance / matrix.max_distance
+                                } else {
+                                    0.0
+                                };
+                                let color = egui::Color32::from_rgb(
+                                    (60.0 + 170.0 * t) as u8,
+                                    (190.0 - 130.0 * t) as u8,
+                                    60,
+                                );
+                                ui.label(
+                                    egui::RichText::new(format!("{:.2}", distance))
+                                        .small()
+                                        .color(egui::Color32::BLACK)
+                                        .background_color(color),
+                                );
+                            }
+                            ui.end_row();
+                        }
+                    });
+            });
+        });
+
+    is_open
+}
+
+/// Actions demandées depuis la fenêtre de détails
+#[derive(Default)]
+struct DetailsActions {
+    find_similar: bool,
+    new_tags: Option<Vec<String>>, // Validées avec Entrée
+}
+
 fn show_population_details(
     ctx: &egui::Context,
     selected_ref: &mut Option<SavedPopulation>,
+    tag_input: &mut String,
     population: &SavedPopulation,
-) {
+) -> DetailsActions {
     let mut is_open = true;
+    let mut actions = DetailsActions::default();
 
     egui::Window::new(format!("Détails: {}", population.name))
         .resizable(true)
@@ -305,6 +1007,25 @@ fn show_population_details(
                         ui.label("Description:");
                         ui.label(desc);
                     }
+
+                    ui.horizontal(|ui| {
+                        ui.label("Tags:");
+                        let response = ui
+                            .text_edit_singleline(tag_input)
+                            .on_hover_text("Séparées par des virgules, validées avec Entrée");
+                        if response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter)) {
+                            actions.new_tags = Some(parse_tags(tag_input));
+                        }
+                    });
+
+                    ui.add_space(5.0);
+                    if ui
+                        .button("🔎 Find similar")
+                        .on_hover_text("Trier les populations par distance génétique à celle-ci")
+                        .clicked()
+                    {
+                        actions.find_similar = true;
+                    }
                 });
 
                 ui.add_space(10.0);
@@ -342,6 +1063,12 @@ fn show_population_details(
                                 population.simulation_params.velocity_half_life
                             ));
                             ui.end_row();
+
+                            if let Some(seed) = population.simulation_params.rng_seed {
+                                ui.label("Graine:");
+                                ui.label(seed.to_string());
+                                ui.end_row();
+                            }
                         });
                 });
 
@@ -389,9 +1116,21 @@ fn show_population_details(
                             ui.label(match population.boundary_mode {
                                 SavedBoundaryMode::Bounce => "Rebond",
                                 SavedBoundaryMode::Teleport => "Téléportation",
+                                SavedBoundaryMode::SoftWall { .. } => "Murs mous",
                             });
                             ui.end_row();
 
+                            if let SavedBoundaryMode::SoftWall { stiffness, decay } =
+                                population.boundary_mode
+                            {
+                                ui.label("Murs mous:");
+                                ui.label(format!(
+                                    "raideur {:.0}, portée {:.0}",
+                                    stiffness, decay
+                                ));
+                                ui.end_row();
+                            }
+
                             ui.label("Nourritures:");
                             ui.label(format!("{}", population.food_params.food_count));
                             ui.end_row();
@@ -420,17 +1159,23 @@ fn show_population_details(
     if !is_open {
         *selected_ref = None;
     }
+
+    actions
 }
 
 fn load_population_for_visualization(commands: &mut Commands, population: SavedPopulation) {
-    let (genotype, sim_params, grid_params, food_params, particle_config, boundary_mode) =
+    let (genotype, sim_params, grid_params, food_params, particle_config, boundary_mode, obstacles) =
         population.to_bevy_resources();
 
+    // Même graine que la session d'origine : placement initial reproductible
+    commands.insert_resource(RunSeed(sim_params.rng_seed));
+
     commands.insert_resource(sim_params);
     commands.insert_resource(grid_params);
     commands.insert_resource(food_params);
     commands.insert_resource(particle_config);
     commands.insert_resource(boundary_mode);
+    commands.insert_resource(obstacles);
     commands.insert_resource(VisualizerGenome(genotype));
 
     info!(
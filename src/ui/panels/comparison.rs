@@ -0,0 +1,202 @@
+use crate::components::entities::particle::{Particle, Velocity};
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::score::{FoodEatenCount, Score};
+use crate::globals::PARTICLE_RADIUS;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+use std::collections::{HashMap, HashSet};
+
+/// Taille des cellules utilisées pour estimer le nombre d'amas
+const CLUSTER_CELL_SIZE: f32 = PARTICLE_RADIUS * 10.0;
+/// Nombre minimal de particules pour qu'une cellule compte dans un amas
+const CLUSTER_MIN_PARTICLES: usize = 3;
+/// Écart relatif à partir duquel une différence est mise en évidence
+const SIGNIFICANT_DIFFERENCE: f32 = 0.2;
+
+/// Statistiques d'une simulation affichées dans la comparaison
+struct ComparedStats {
+    score: f32,
+    food_eaten: u32,
+    average_speed: f32,
+    cluster_count: usize,
+    self_interaction: f32,
+    food_force_mean: f32,
+}
+
+impl ComparedStats {
+    fn rows(&self) -> [(&'static str, f32, usize); 6] {
+        [
+            ("Score", self.score, 1),
+            ("Nourriture mangée", self.food_eaten as f32, 0),
+            ("Vitesse moyenne", self.average_speed, 1),
+            ("Amas (approx.)", self.cluster_count as f32, 0),
+            ("Auto-interaction", self.self_interaction, 2),
+            ("Force nourriture moy.", self.food_force_mean, 2),
+        ]
+    }
+}
+
+/// Panneau de comparaison détaillée de deux simulations
+pub fn comparison_panel_ui(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<ForceMatrixUI>,
+    simulations: Query<
+        (Entity, &SimulationId, &Score, &FoodEatenCount, &Genotype),
+        With<Simulation>,
+    >,
+    particles: Query<(&Transform, &Velocity, &ChildOf), With<Particle>>,
+) {
+    let Some((first_id, second_id)) = ui_state.comparison else {
+        return;
+    };
+
+    let stats_for = |sim_id: usize| {
+        simulations
+            .iter()
+            .find(|(_, id, ..)| id.0 == sim_id)
+            .map(|(entity, _, score, food_eaten, genotype)| {
+                compute_stats(entity, score, food_eaten, genotype, &particles)
+            })
+    };
+
+    let (Some(first), Some(second)) = (stats_for(first_id), stats_for(second_id)) else {
+        ui_state.comparison = None;
+        return;
+    };
+
+    let ctx = contexts.ctx_mut();
+    let mut is_open = true;
+    let mut close_requested = false;
+
+    egui::Window::new("⚖ Comparaison")
+        .open(&mut is_open)
+        .collapsible(true)
+        .resizable(false)
+        .show(ctx, |ui| {
+            egui::Grid::new("comparison_grid")
+                .num_columns(3)
+                .spacing([20.0, 4.0])
+                .striped(true)
+                .show(ui, |ui| {
+                    ui.label("");
+                    ui.label(egui::RichText::new(format!("Sim #{}", first_id + 1)).strong());
+                    ui.label(egui::RichText::new(format!("Sim #{}", second_id + 1)).strong());
+                    ui.end_row();
+
+                    for ((label, a, precision), (_, b, _)) in
+                        first.rows().into_iter().zip(second.rows())
+                    {
+                        ui.label(label);
+                        ui.label(comparison_cell(a, b, precision));
+                        ui.label(comparison_cell(b, a, precision));
+                        ui.end_row();
+                    }
+                });
+
+            ui.add_space(5.0);
+            ui.label(
+                egui::RichText::new("Vert/rouge : écart de plus de 20%")
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+
+            if ui.button("✖ Fermer la comparaison").clicked() {
+                close_requested = true;
+            }
+        });
+
+    if close_requested || !is_open {
+        ui_state.comparison = None;
+    }
+}
+
+fn compute_stats(
+    simulation_entity: Entity,
+    score: &Score,
+    food_eaten: &FoodEatenCount,
+    genotype: &Genotype,
+    particles: &Query<(&Transform, &Velocity, &ChildOf), With<Particle>>,
+) -> ComparedStats {
+    let mut positions = Vec::new();
+    let mut total_speed = 0.0;
+
+    for (transform, velocity, parent) in particles.iter() {
+        if parent.parent() == simulation_entity {
+            positions.push(transform.translation);
+            total_speed += velocity.0.length();
+        }
+    }
+
+    let self_interaction = (0..genotype.type_count)
+        .map(|i| genotype.get_force(i, i))
+        .sum::<f32>()
+        / genotype.type_count.max(1) as f32;
+
+    let food_force_mean =
+        genotype.food_forces.iter().sum::<f32>() / genotype.food_forces.len().max(1) as f32;
+
+    ComparedStats {
+        score: score.get(),
+        food_eaten: food_eaten.0,
+        average_speed: total_speed / positions.len().max(1) as f32,
+        cluster_count: approximate_cluster_count(&positions),
+        self_interaction,
+        food_force_mean,
+    }
+}
+
+/// Compte les composantes connexes de cellules suffisamment peuplées
+fn approximate_cluster_count(positions: &[Vec3]) -> usize {
+    let mut cells: HashMap<IVec3, usize> = HashMap::new();
+    for position in positions {
+        *cells
+            .entry((*position / CLUSTER_CELL_SIZE).floor().as_ivec3())
+            .or_default() += 1;
+    }
+
+    let mut dense: HashSet<IVec3> = cells
+        .into_iter()
+        .filter(|(_, count)| *count >= CLUSTER_MIN_PARTICLES)
+        .map(|(cell, _)| cell)
+        .collect();
+
+    let mut clusters = 0;
+    while let Some(&start) = dense.iter().next() {
+        dense.remove(&start);
+        clusters += 1;
+
+        let mut stack = vec![start];
+        while let Some(cell) = stack.pop() {
+            for dx in -1..=1 {
+                for dy in -1..=1 {
+                    for dz in -1..=1 {
+                        let neighbor = cell + IVec3::new(dx, dy, dz);
+                        if dense.remove(&neighbor) {
+                            stack.push(neighbor);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    clusters
+}
+
+/// Valeur colorée en vert (ou rouge) si elle dépasse (ou est en dessous de) l'autre de plus de 20%
+fn comparison_cell(value: f32, other: f32, precision: usize) -> egui::RichText {
+    let text = egui::RichText::new(format!("{:.*}", precision, value)).monospace();
+    let reference = value.abs().max(other.abs());
+
+    if reference <= f32::EPSILON || (value - other).abs() / reference <= SIGNIFICANT_DIFFERENCE {
+        return text;
+    }
+
+    if value > other {
+        text.color(egui::Color32::from_rgb(100, 220, 100))
+    } else {
+        text.color(egui::Color32::from_rgb(230, 100, 100))
+    }
+}
@@ -0,0 +1,94 @@
+use crate::plugins::simulation::compute::ComputeEnabled;
+use crate::resources::config::food::FoodParameters;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::resources::world::boundary::BoundaryMode;
+use crate::resources::world::grid::GridParameters;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::diagnostic::{DiagnosticsStore, FrameTimeDiagnosticsPlugin};
+use bevy::ecs::entity::Entities;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+/// Valeurs courantes des ressources globales, relues à chaque frame (builds de debug)
+pub fn debug_resources_window(
+    mut contexts: EguiContexts,
+    mut ui_state: ResMut<ForceMatrixUI>,
+    diagnostics: Res<DiagnosticsStore>,
+    entities: &Entities,
+    sim_params: Option<Res<SimulationParameters>>,
+    grid: Option<Res<GridParameters>>,
+    food_params: Option<Res<FoodParameters>>,
+    boundary_mode: Option<Res<BoundaryMode>>,
+    compute_enabled: Option<Res<ComputeEnabled>>,
+) {
+    if !ui_state.show_debug_resources {
+        return;
+    }
+
+    let mut open = true;
+    egui::Window::new("🐞 Debug Resources")
+        .open(&mut open)
+        .default_width(420.0)
+        .vscroll(true)
+        .show(contexts.ctx_mut(), |ui| {
+            egui::Grid::new("debug_resources_grid")
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("FPS:");
+                    let fps = diagnostics
+                        .get(&FrameTimeDiagnosticsPlugin::FPS)
+                        .and_then(|fps| fps.smoothed());
+                    ui.label(fps.map_or("—".to_string(), |fps| format!("{:.1}", fps)));
+                    ui.end_row();
+
+                    ui.label("Entités:");
+                    ui.label(entities.len().to_string());
+                    ui.end_row();
+
+                    ui.label("Mémoire:");
+                    ui.label(resident_memory_kib().map_or("—".to_string(), |kib| {
+                        format!("{:.1} Mo", kib as f32 / 1024.0)
+                    }));
+                    ui.end_row();
+                });
+
+            ui.separator();
+            resource_section(ui, "SimulationParameters", sim_params.as_deref());
+            resource_section(ui, "GridParameters", grid.as_deref());
+            resource_section(ui, "FoodParameters", food_params.as_deref());
+            resource_section(ui, "BoundaryMode", boundary_mode.as_deref());
+            resource_section(ui, "ComputeEnabled", compute_enabled.as_deref());
+        });
+
+    if !open {
+        ui_state.show_debug_resources = false;
+    }
+}
+
+/// Contenu d'une ressource en lecture seule, absente hors de la simulation
+fn resource_section<T: std::fmt::Debug>(ui: &mut egui::Ui, name: &str, resource: Option<&T>) {
+    egui::CollapsingHeader::new(name)
+        .default_open(true)
+        .show(ui, |ui| match resource {
+            Some(resource) => {
+                ui.label(egui::RichText::new(format!("{:#?}", resource)).monospace().small());
+            }
+            None => {
+                ui.label(egui::RichText::new("(absente)").small().color(egui::Color32::GRAY));
+            }
+        });
+}
+
+/// Mémoire résidente du processus en Kio (Linux uniquement, lue dans /proc)
+fn resident_memory_kib() -> Option<u64> {
+    let status = std::fs::read_to_string("/proc/self/status").ok()?;
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("VmRSS:"))?
+        .trim()
+        .trim_end_matches("kB")
+        .trim()
+        .parse()
+        .ok()
+}
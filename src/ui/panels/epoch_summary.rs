@@ -0,0 +1,84 @@
+use crate::systems::simulation::reset::EpochEndSummary;
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+/// Fenêtre de résumé affichée après chaque fin d'époque, fermée automatiquement
+pub fn epoch_summary_ui(
+    mut contexts: EguiContexts,
+    mut summary: ResMut<EpochEndSummary>,
+    mut ui_state: ResMut<ForceMatrixUI>,
+    time: Res<Time>,
+) {
+    if !summary.is_fresh {
+        return;
+    }
+
+    if summary.timer.tick(time.delta()).finished() {
+        summary.dismiss();
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+    let mut is_open = true;
+    let mut jump_to_winner = false;
+
+    egui::Window::new(format!("🏁 Fin de l'époque {}", summary.epoch))
+        .open(&mut is_open)
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::RIGHT_BOTTOM, [-10.0, -10.0])
+        .show(ctx, |ui| {
+            egui::Grid::new("epoch_summary_grid")
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Gagnante:");
+                    ui.label(
+                        egui::RichText::new(format!("Simulation #{}", summary.winner + 1))
+                            .color(egui::Color32::from_rgb(100, 200, 255))
+                            .strong(),
+                    );
+                    ui.end_row();
+
+                    ui.label("Meilleur score:");
+                    ui.label(
+                        egui::RichText::new(format!("{:.1}", summary.best_score))
+                            .color(egui::Color32::from_rgb(0, 255, 0)),
+                    );
+                    ui.end_row();
+
+                    ui.label("Score moyen:");
+                    ui.label(format!("{:.1}", summary.average_score));
+                    ui.end_row();
+
+                    ui.label("Pire score:");
+                    ui.label(
+                        egui::RichText::new(format!("{:.1}", summary.worst_score))
+                            .color(egui::Color32::from_rgb(255, 100, 100)),
+                    );
+                    ui.end_row();
+                });
+
+            ui.add_space(5.0);
+
+            if ui.button("🎯 Voir la gagnante").clicked() {
+                jump_to_winner = true;
+            }
+
+            let remaining = summary.timer.remaining_secs().ceil();
+            ui.label(
+                egui::RichText::new(format!("Fermeture dans {:.0} s", remaining))
+                    .small()
+                    .color(egui::Color32::GRAY),
+            );
+        });
+
+    if jump_to_winner {
+        ui_state.selected_simulation = Some(summary.winner);
+        ui_state.show_matrix_window = true;
+        summary.dismiss();
+    } else if !is_open {
+        summary.dismiss();
+    }
+}
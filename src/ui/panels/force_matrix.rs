@@ -1,19 +1,61 @@
+use crate::components::entities::particle::{Particle, ParticleType};
 use crate::components::entities::simulation::{Simulation, SimulationId};
-use crate::components::genetics::genotype::Genotype;
+use crate::components::genetics::genotype::{Genotype, average_entropy, expressed};
+use crate::components::genetics::score::Score;
+use crate::globals::{MAX_RECORDED_FRAMES, MIN_EPOCH_DURATION};
 use crate::plugins::simulation::compute::ComputeEnabled;
+use crate::resources::config::audio::AudioSettings;
+use crate::resources::config::global::GlobalConfig;
+use crate::resources::config::food::FoodParameters;
+use crate::resources::world::camera::CameraSettings;
+use crate::states::app::AppState;
 use crate::resources::config::particle_types::ParticleTypesConfig;
-use crate::resources::config::simulation::{SimulationParameters, SimulationSpeed};
+use crate::resources::config::simulation::{SimulationMode, SimulationParameters, SimulationSpeed};
+use crate::systems::persistence::stats_export::export_epoch_history_csv;
+use crate::systems::rendering::screenshot::{CaptureScreenshot, ScreenshotState};
 use crate::systems::rendering::viewport_manager::UISpace;
+use crate::ui::menus::visualizer_menu::VisualizerGenome;
+use crate::ui::dialogs::save_population::{SavePopulationUI, best_simulation};
+use crate::ui::panels::obstacles::ObstacleEditorUI;
+use crate::systems::simulation::hall_of_fame::HallOfFame;
+use crate::systems::simulation::lineage::LineageForest;
+use crate::systems::simulation::mutation::ForceMutateNow;
+use crate::systems::simulation::physics::{force_profile, repulsion_distance};
+use crate::systems::simulation::replay::SimulationRecorder;
+use crate::systems::simulation::reset::{
+    ConvergenceEstimator, EpochHistoryResource, FitnessHistory, PopulationDiversityHistory,
+};
 use bevy::prelude::*;
 use bevy_egui::{EguiContexts, egui};
+use egui_plot::{Line, Plot, PlotPoints, VLine};
 use std::collections::HashSet;
 
+/// Nombre d'époques remontées dans l'arbre de lignée
+const LINEAGE_DEPTH: usize = 5;
+
+/// Disposition des viewports : automatique selon leur nombre, ou grille colonnes × lignes
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ViewportLayout {
+    #[default]
+    Auto,
+    Grid(u32, u32),
+}
+
 #[derive(Resource)]
 pub struct ForceMatrixUI {
     pub selected_simulation: Option<usize>,
     pub show_matrix_window: bool,
     pub show_simulations_list: bool,
     pub selected_simulations: HashSet<usize>,
+    pub show_velocity_arrows: bool,
+    pub show_grid_bounds: bool,
+    pub show_density_heatmap: bool,
+    pub viewport_layout: ViewportLayout,
+    pub comparison: Option<(usize, usize)>, // Deux simulations comparées côte à côte
+    pub export_positions_requested: bool,
+    pub export_positions_every_epoch: bool, // Export CSV automatique en fin d'époque
+    pub genome_copy_source: Option<usize>,  // Mode copie : simulation dont le génome est copié
+    pub show_debug_resources: bool,         // Fenêtre « Debug Resources » (builds de debug)
 }
 
 impl Default for ForceMatrixUI {
@@ -26,6 +68,15 @@ impl Default for ForceMatrixUI {
             show_matrix_window: false,
             show_simulations_list: true,
             selected_simulations,
+            show_velocity_arrows: false,
+            show_grid_bounds: true,
+            show_density_heatmap: false,
+            viewport_layout: ViewportLayout::default(),
+            comparison: None,
+            export_positions_requested: false,
+            export_positions_every_epoch: false,
+            genome_copy_source: None,
+            show_debug_resources: false,
         }
     }
 }
@@ -33,9 +84,30 @@ impl Default for ForceMatrixUI {
 pub fn speed_control_ui(
     mut contexts: EguiContexts,
     mut sim_params: ResMut<SimulationParameters>,
+    mut ui_state: ResMut<ForceMatrixUI>,
     mut ui_space: ResMut<UISpace>,
     mut compute_enabled: ResMut<ComputeEnabled>,
+    // Statistiques en lecture seule, regroupées (limite de 16 paramètres par système)
+    (epoch_history, convergence, food_params): (
+        Res<EpochHistoryResource>,
+        Res<ConvergenceEstimator>,
+        Res<FoodParameters>,
+    ),
+    camera_settings: Res<CameraSettings>,
+    screenshot_state: Res<ScreenshotState>,
+    mut capture_events: EventWriter<CaptureScreenshot>,
+    mut audio_settings: ResMut<AudioSettings>,
+    scores: Query<(&SimulationId, &Score, &Genotype), With<Simulation>>,
     time: Res<Time>,
+    mut export_status: Local<Option<Result<String, String>>>,
+    // Outils regroupés : limite de 16 paramètres par système
+    (mut obstacle_editor, mut recorder, mut save_ui): (
+        ResMut<ObstacleEditorUI>,
+        ResMut<SimulationRecorder>,
+        ResMut<SavePopulationUI>,
+    ),
+    app_state: Res<State<AppState>>,
+    mut mutate_events: EventWriter<ForceMutateNow>,
 ) {
     let ctx = contexts.ctx_mut();
 
@@ -43,44 +115,26 @@ pub fn speed_control_ui(
         ui.horizontal(|ui| {
             ui.label("Vitesse:");
 
-            if ui
-                .selectable_label(
-                    sim_params.simulation_speed == SimulationSpeed::Paused,
-                    "⏸ Pause",
-                )
-                .clicked()
-            {
-                sim_params.simulation_speed = SimulationSpeed::Paused;
-            }
-
-            if ui
-                .selectable_label(
-                    sim_params.simulation_speed == SimulationSpeed::Normal,
-                    "▶ Normal",
-                )
-                .clicked()
-            {
-                sim_params.simulation_speed = SimulationSpeed::Normal;
-            }
-
-            if ui
-                .selectable_label(
-                    sim_params.simulation_speed == SimulationSpeed::Fast,
-                    "⏩ Rapide (2x)",
-                )
-                .clicked()
-            {
-                sim_params.simulation_speed = SimulationSpeed::Fast;
+            // Raccourci clavier affiché en indice après chaque vitesse
+            for (speed, label, key) in [
+                (SimulationSpeed::Paused, "⏸ Pause", "0"),
+                (SimulationSpeed::Normal, "▶ Normal", "1"),
+                (SimulationSpeed::Fast, "⏩ Rapide (2x)", "2"),
+                (SimulationSpeed::VeryFast, "⏭ Très rapide (4x)", "4"),
+                (SimulationSpeed::UltraFast, "🚀 Ultra (8x)", "8"),
+            ] {
+                if ui
+                    .selectable_label(sim_params.simulation_speed == speed, label)
+                    .clicked()
+                {
+                    sim_params.simulation_speed = speed;
+                }
+                ui.label(egui::RichText::new(key).small().weak());
             }
 
-            if ui
-                .selectable_label(
-                    sim_params.simulation_speed == SimulationSpeed::VeryFast,
-                    "⏭ Très rapide (4x)",
-                )
-                .clicked()
-            {
-                sim_params.simulation_speed = SimulationSpeed::VeryFast;
+            if *app_state.get() == AppState::Simulation {
+                ui.separator();
+                mutation_controls(ui, &mut sim_params, &scores, &mut mutate_events);
             }
 
             ui.separator();
@@ -112,21 +166,307 @@ pub fn speed_control_ui(
                     .desired_width(150.0),
             );
 
+            ui.label(format!("🍎 {}", food_params.food_count))
+                .on_hover_text("Nourritures de l'époque en cours (varie d'une époque à l'autre)");
+
+            let epochs_remaining = sim_params.max_epochs.saturating_sub(sim_params.current_epoch);
+            if sim_params.simulation_mode == SimulationMode::EpochBased && epochs_remaining < 10 {
+                ui.label(
+                    egui::RichText::new(format!("{} epochs remaining", epochs_remaining))
+                        .color(egui::Color32::from_rgb(255, 165, 0)),
+                );
+            }
+
+            let mut epoch_duration = sim_params.epoch_duration;
+            if ui
+                .add(
+                    egui::DragValue::new(&mut epoch_duration)
+                        .range(MIN_EPOCH_DURATION..=300.0)
+                        .suffix(" s"),
+                )
+                .on_hover_text("Durée d'une époque, ajustable sans redémarrer")
+                .changed()
+            {
+                sim_params.set_epoch_duration(epoch_duration);
+            }
+
+            if let Some((start, end)) = sim_params.velocity_half_life_schedule {
+                ui.label(format!("🧊 Demi-vie: {:.3} s", sim_params.velocity_half_life))
+                    .on_hover_text(format!(
+                        "Recuit {} : {:.3} s → {:.3} s sur {} époques",
+                        sim_params.decay_curve.label().to_lowercase(),
+                        start,
+                        end,
+                        sim_params.max_epochs
+                    ));
+            }
+
+            let mut threshold = sim_params.gene_expression_threshold;
+            if ui
+                .add(egui::Slider::new(&mut threshold, 0.0..=0.5).text("Expression"))
+                .on_hover_text("Seuil d'expression : les forces plus faibles sont ignorées")
+                .changed()
+            {
+                sim_params.gene_expression_threshold = threshold;
+            }
+
+            // Diversité courante : écart-type / moyenne des scores
+            let values: Vec<f32> = scores.iter().map(|(_, score, _)| score.get()).collect();
+            let average = values.iter().sum::<f32>() / values.len().max(1) as f32;
+            if average > 0.0 {
+                let variance = values.iter().map(|v| (v - average).powi(2)).sum::<f32>()
+                    / values.len() as f32;
+                let diversity = variance.sqrt() / average * 100.0;
+                let color = if diversity > 50.0 {
+                    egui::Color32::from_rgb(100, 200, 100)
+                } else if diversity >= 20.0 {
+                    egui::Color32::from_rgb(220, 200, 80)
+                } else {
+                    egui::Color32::from_rgb(220, 90, 90)
+                };
+                ui.label(egui::RichText::new(format!("Diversité: {:.0}%", diversity)).color(color))
+                    .on_hover_text("Écart-type / moyenne des scores de la population");
+            } else {
+                ui.label("Diversité: —");
+            }
+
+            if sim_params.oscillating_forces {
+                let phase = sim_params.force_oscillation_phase();
+                ui.label(format!("〰 {:.0}°", phase.to_degrees())).on_hover_text(format!(
+                    "Phase de l'oscillation des forces (×{:.2})",
+                    sim_params.force_oscillation_factor()
+                ));
+            }
+
+            let entropy = average_entropy(scores.iter().map(|(_, _, genotype)| genotype));
+            ui.label(format!("Entropy: {:.2} bits", entropy))
+                .on_hover_text("Entropie de Shannon moyenne des matrices de forces (32 classes)");
+
+            let trend = convergence
+                .label()
+                .filter(|_| *app_state.get() == AppState::Simulation);
+            if let Some(trend) = trend {
+                ui.label(trend)
+                    .on_hover_text("Régression linéaire sur les 10 derniers meilleurs scores");
+            }
+
             ui.separator();
 
             let fps = 1.0 / time.delta_secs();
             ui.label(format!("FPS: {:.0}", fps));
+
+            ui.separator();
+
+            // Copie locale : ne marquer ForceMatrixUI modifié qu'en cas de changement réel
+            let mut show_velocity_arrows = ui_state.show_velocity_arrows;
+            if ui
+                .checkbox(&mut show_velocity_arrows, "➶ Vitesses")
+                .on_hover_text("Flèches de vitesse de la simulation sélectionnée")
+                .changed()
+            {
+                ui_state.show_velocity_arrows = show_velocity_arrows;
+            }
+
+            let mut show_grid_bounds = ui_state.show_grid_bounds;
+            if ui
+                .checkbox(&mut show_grid_bounds, "⬚ Grille")
+                .on_hover_text("Cadre des limites de la grille")
+                .changed()
+            {
+                ui_state.show_grid_bounds = show_grid_bounds;
+            }
+
+            let mut show_density_heatmap = ui_state.show_density_heatmap;
+            if ui
+                .checkbox(&mut show_density_heatmap, "▦ Densité")
+                .on_hover_text("Carte de densité au sol de la simulation sélectionnée")
+                .changed()
+            {
+                ui_state.show_density_heatmap = show_density_heatmap;
+            }
+
+            if *app_state.get() == AppState::Simulation
+                && ui
+                    .selectable_label(obstacle_editor.show_panel, "🧱 Obstacles")
+                    .on_hover_text("Ouvrir le panneau « Edit Obstacles »")
+                    .clicked()
+            {
+                obstacle_editor.show_panel = !obstacle_editor.show_panel;
+                obstacle_editor.placing &= obstacle_editor.show_panel;
+            }
+
+            if *app_state.get() == AppState::Visualization
+                && ui
+                    .selectable_label(recorder.recording, "⏺ Enregistrer")
+                    .on_hover_text(format!(
+                        "Capturer chaque frame pour le rejeu (max {} images)",
+                        MAX_RECORDED_FRAMES
+                    ))
+                    .clicked()
+            {
+                if recorder.recording {
+                    recorder.recording = false;
+                } else {
+                    recorder.resume_live();
+                    recorder.recording = true;
+                }
+            }
+
+            if *app_state.get() == AppState::Simulation
+                && ui
+                    .button("💾 Save Best")
+                    .on_hover_text(
+                        "Sauvegarder la simulation au meilleur score (Ctrl+S : sans dialogue)",
+                    )
+                    .clicked()
+            {
+                let best = best_simulation(scores.iter().map(|(sim_id, score, _)| (sim_id, score)));
+                if let Some((simulation_id, score)) = best {
+                    save_ui.open_for_best(simulation_id, sim_params.current_epoch + 1, score);
+                }
+            }
+
+            ui.separator();
+
+            ui.label(format!("🎥 {}", camera_settings.mode.label()))
+                .on_hover_text("F : basculer orbite / vol libre (WASD, Q/E, clic-glisser)");
+
+            ui.separator();
+
+            if ui
+                .add_enabled(!screenshot_state.pending, egui::Button::new("📷"))
+                .on_hover_text("Capture d'écran (Impr. écran) dans screenshots/")
+                .clicked()
+            {
+                capture_events.write(CaptureScreenshot);
+            }
+
+            if let Some(message) = screenshot_state.notice_message() {
+                ui.label(egui::RichText::new(message).small().color(egui::Color32::GRAY));
+            }
+
+            #[cfg(debug_assertions)]
+            if ui
+                .selectable_label(ui_state.show_debug_resources, "🐞 Debug Resources")
+                .on_hover_text("Valeurs courantes des ressources globales")
+                .clicked()
+            {
+                ui_state.show_debug_resources = !ui_state.show_debug_resources;
+            }
+
+            ui.separator();
+
+            let audio_icon = if audio_settings.audio_enabled { "🔊" } else { "🔇" };
+            ui.checkbox(&mut audio_settings.audio_enabled, audio_icon)
+                .on_hover_text("Sons : nourriture mangée par la meilleure simulation, fin d'époque");
+            ui.scope(|ui| {
+                ui.spacing_mut().slider_width = 60.0;
+                let enabled = audio_settings.audio_enabled;
+                ui.add_enabled(
+                    enabled,
+                    egui::Slider::new(&mut audio_settings.sfx_volume, 0.0..=1.0)
+                        .show_value(false),
+                )
+                .on_hover_text("Volume des effets sonores");
+            });
+
+            ui.separator();
+
+            if ui
+                .add_enabled(!epoch_history.is_empty(), egui::Button::new("📄 Export CSV"))
+                .on_hover_text("Exporte les statistiques des époques dans populations/")
+                .on_disabled_hover_text("Aucune époque terminée")
+                .clicked()
+            {
+                *export_status = Some(
+                    export_epoch_history_csv(&epoch_history)
+                        .map(|path| format!("Exporté: {}", path.display()))
+                        .map_err(|e| format!("Erreur d'export: {}", e)),
+                );
+            }
+
+            match export_status.as_ref() {
+                Some(Ok(message)) => {
+                    ui.label(egui::RichText::new(message).small().color(egui::Color32::GRAY));
+                }
+                Some(Err(message)) => {
+                    ui.label(egui::RichText::new(message).small().color(egui::Color32::RED));
+                }
+                None => {}
+            }
         });
     });
 
     ui_space.top_panel_height = top_panel_response.response.rect.height();
 }
 
+/// Taux de mutation modifiable en direct, taux effectif des génomes et « Mutate Now »
+fn mutation_controls(
+    ui: &mut egui::Ui,
+    sim_params: &mut ResMut<SimulationParameters>,
+    scores: &Query<(&SimulationId, &Score, &Genotype), With<Simulation>>,
+    mutate_events: &mut EventWriter<ForceMutateNow>,
+) {
+    // Copie locale : SimulationParameters n'est marqué modifié qu'au changement réel
+    let mut mutation_rate = sim_params.mutation_rate;
+    if ui
+        .add(
+            egui::Slider::new(&mut mutation_rate, 0.0..=0.5)
+                .text("Mutation")
+                .fixed_decimals(3),
+        )
+        .on_hover_text("Appliqué aux génomes à la prochaine fin d'époque")
+        .changed()
+    {
+        sim_params.mutation_rate = mutation_rate;
+    }
+
+    // Taux auto-adaptatif porté par chaque génome, en moyenne
+    let (sum, count) = scores
+        .iter()
+        .fold((0.0, 0), |(sum, count), (_, _, genotype)| {
+            (sum + genotype.meta_mutation_rate, count + 1)
+        });
+    if count > 0 {
+        ui.label(
+            egui::RichText::new(format!("eff. {:.3}", sum / count as f32))
+                .small()
+                .weak(),
+        )
+        .on_hover_text("Taux de mutation effectif moyen des génomes (auto-adaptatif)");
+    }
+
+    if ui
+        .button("🧬 Mutate Now")
+        .on_hover_text("Mute immédiatement les simulations hors élite")
+        .clicked()
+    {
+        mutate_events.write(ForceMutateNow);
+    }
+}
+
 pub fn force_matrix_window(
+    mut commands: Commands,
     mut contexts: EguiContexts,
     mut ui_state: ResMut<ForceMatrixUI>,
     particle_config: Res<ParticleTypesConfig>,
-    simulations: Query<(&SimulationId, &Genotype), With<Simulation>>,
+    global_config: Res<GlobalConfig>,
+    sim_params: Res<SimulationParameters>,
+    lineage_forest: Res<LineageForest>,
+    // Historiques regroupés : limite de 16 paramètres par système
+    (diversity_history, fitness_history, epoch_history): (
+        Res<PopulationDiversityHistory>,
+        Res<FitnessHistory>,
+        Res<EpochHistoryResource>,
+    ),
+    hall_of_fame: Res<HallOfFame>,
+    app_state: Res<State<AppState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut simulations: Query<(Entity, &SimulationId, &mut Genotype), With<Simulation>>,
+    particles: Query<(&ParticleType, &ChildOf, &Visibility), With<Particle>>,
+    mut edit_buffer: Local<Option<(usize, Vec<f32>)>>,
+    mut drag_in_progress: Local<bool>,
+    mut show_all_trajectories: Local<bool>,
 ) {
     if !ui_state.show_matrix_window || ui_state.selected_simulation.is_none() {
         return;
@@ -134,6 +474,9 @@ pub fn force_matrix_window(
 
     let ctx = contexts.ctx_mut();
     let selected_sim = ui_state.selected_simulation.unwrap();
+    let mut genome_to_visualize: Option<Genotype> = None;
+    let mut export_positions = false;
+    let mut export_every_epoch = ui_state.export_positions_every_epoch;
 
     egui::Window::new(format!(
         "Matrice des Forces - Simulation #{}",
@@ -144,13 +487,50 @@ pub fn force_matrix_window(
     .min_width(500.0)
     .open(&mut ui_state.show_matrix_window)
     .show(ctx, |ui| {
-        if let Some((_, genotype)) = simulations
-            .iter()
-            .find(|(sim_id, _)| sim_id.0 == selected_sim)
+        if let Some((simulation_entity, _, mut genotype)) = simulations
+            .iter_mut()
+            .find(|(_, sim_id, _)| sim_id.0 == selected_sim)
         {
             let type_count = particle_config.type_count;
 
+            // Copie de travail : resynchronisée avec le génome tant qu'aucun drag n'est en cours
+            let needs_refresh = match edit_buffer.as_ref() {
+                Some((sim, _)) => *sim != selected_sim || !*drag_in_progress,
+                None => true,
+            };
+            if needs_refresh {
+                *edit_buffer = Some((selected_sim, genotype.force_matrix.clone()));
+            }
+            let mut commit_edit = false;
+            let mut any_dragging = false;
+            // Dans le bac à sable, chaque modification est appliquée immédiatement
+            let live_edit = *app_state.get() == AppState::Sandbox;
+
             ui.label(format!("Types de particules: {}", type_count));
+
+            // 0 : interactions parfaitement réciproques
+            let asymmetry = genotype.asymmetry();
+            ui.label(
+                egui::RichText::new(format!("Asymétrie: {:.3}", asymmetry)).color(
+                    if asymmetry < 0.01 {
+                        egui::Color32::from_rgb(100, 200, 100)
+                    } else {
+                        egui::Color32::from_rgb(200, 200, 200)
+                    },
+                ),
+            )
+            .on_hover_text("Moyenne de |force(i, j) − force(j, i)| sur les paires de types");
+
+            let threshold = sim_params.gene_expression_threshold;
+            ui.label(format!(
+                "Gènes actifs: {:.0}%",
+                genotype.active_gene_ratio(threshold) * 100.0
+            ))
+            .on_hover_text(format!(
+                "Forces non nulles après le seuil d'expression ({:.2})",
+                threshold
+            ));
+
             ui.label(
                 egui::RichText::new("Forces normalisées entre -2.000 et +2.000")
                     .small()
@@ -176,7 +556,7 @@ pub fn force_matrix_window(
                     for j in 0..type_count {
                         let (color, _) = particle_config.get_color_for_type(j);
                         ui.label(
-                            egui::RichText::new(format!("Type {}", j))
+                            egui::RichText::new(particle_config.get_name_for_type(j))
                                 .color(egui::Color32::from_rgb(
                                     (color.to_srgba().red * 255.0) as u8,
                                     (color.to_srgba().green * 255.0) as u8,
@@ -195,7 +575,7 @@ pub fn force_matrix_window(
                     for i in 0..type_count {
                         let (color, _) = particle_config.get_color_for_type(i);
                         ui.label(
-                            egui::RichText::new(format!("Type {}", i))
+                            egui::RichText::new(particle_config.get_name_for_type(i))
                                 .color(egui::Color32::from_rgb(
                                     (color.to_srgba().red * 255.0) as u8,
                                     (color.to_srgba().green * 255.0) as u8,
@@ -205,11 +585,20 @@ pub fn force_matrix_window(
                         );
 
                         for j in 0..type_count {
-                            let force = genotype.get_force(i, j);
+                            let index = i * type_count + j;
+                            let Some((_, buffer)) = edit_buffer.as_mut() else {
+                                continue;
+                            };
+                            let Some(force) = buffer.get_mut(index) else {
+                                ui.label("-");
+                                continue;
+                            };
 
-                            let color = if force.abs() < 0.05 {
+                            // Gène silencieux : gris quel que soit le signe
+                            let color = if force.abs() < 0.05 || expressed(*force, threshold) == 0.0
+                            {
                                 egui::Color32::from_rgb(120, 120, 120)
-                            } else if force > 0.0 {
+                            } else if *force > 0.0 {
                                 let intensity = (force.abs() * 127.5 + 127.5) as u8;
                                 egui::Color32::from_rgb(0, intensity.max(100), 0)
                             } else {
@@ -217,17 +606,80 @@ pub fn force_matrix_window(
                                 egui::Color32::from_rgb(intensity.max(100), 0, 0)
                             };
 
-                            ui.label(
-                                egui::RichText::new(format!("{:+.3}", force))
-                                    .color(color)
-                                    .monospace()
-                                    .size(11.0),
-                            );
+                            let response = ui
+                                .scope(|ui| {
+                                    ui.visuals_mut().override_text_color = Some(color);
+                                    ui.add(
+                                        egui::DragValue::new(force)
+                                            .range(-2.0..=2.0)
+                                            .speed(0.01)
+                                            .fixed_decimals(3),
+                                    )
+                                })
+                                .inner;
+
+                            // On ne modifie l'ECS qu'au relâchement (ou après saisie clavier)
+                            if response.dragged() {
+                                any_dragging = true;
+                            }
+                            if response.drag_stopped()
+                                || (response.changed() && (live_edit || !response.dragged()))
+                            {
+                                commit_edit = true;
+                            }
                         }
                         ui.end_row();
                     }
                 });
 
+            *drag_in_progress = any_dragging;
+
+            if commit_edit {
+                if let Some((_, buffer)) = edit_buffer.as_ref() {
+                    if buffer.len() == genotype.force_matrix.len() {
+                        genotype.force_matrix.copy_from_slice(buffer);
+                    }
+                }
+            }
+
+            ui.add_space(5.0);
+            ui.horizontal(|ui| {
+                if ui
+                    .button("🎲 Réinitialiser (aléatoire)")
+                    .on_hover_text("Remplace le génome par un génome aléatoire")
+                    .clicked()
+                {
+                    *genotype = Genotype::random_encoded(
+                        genotype.type_count,
+                        genotype.food_type_count,
+                        sim_params.genome_encoding,
+                        &mut rand::rng(),
+                    );
+                    *edit_buffer = None;
+                }
+
+                if ui
+                    .button("✨ Appliquer le preset")
+                    .on_hover_text("Applique une configuration de forces prédéfinie")
+                    .clicked()
+                {
+                    genotype.set_interesting_forces(&mut rand::rng());
+                    *edit_buffer = None;
+                }
+            });
+
+            ui.horizontal(|ui| {
+                if ui
+                    .button("📍 Export Positions")
+                    .on_hover_text("Positions et vitesses des particules en CSV dans positions/")
+                    .clicked()
+                {
+                    export_positions = true;
+                }
+                ui.checkbox(&mut export_every_epoch, "Export every epoch")
+                    .on_hover_text("Exporte automatiquement cette simulation à chaque fin d'époque");
+            });
+
             ui.add_space(10.0);
             ui.separator();
 
@@ -239,15 +691,18 @@ pub fn force_matrix_window(
             );
             ui.add_space(5.0);
 
+            let food_type_count = genotype.food_type_count.max(1);
+
             egui::Grid::new("food_forces_grid")
-                .num_columns(type_count)
+                .num_columns(type_count + 1)
                 .spacing([20.0, 5.0])
                 .min_col_width(70.0)
                 .show(ui, |ui| {
+                    ui.label("Nourriture");
                     for i in 0..type_count {
                         let (color, _) = particle_config.get_color_for_type(i);
                         ui.label(
-                            egui::RichText::new(format!("Type {}", i))
+                            egui::RichText::new(particle_config.get_name_for_type(i))
                                 .color(egui::Color32::from_rgb(
                                     (color.to_srgba().red * 255.0) as u8,
                                     (color.to_srgba().green * 255.0) as u8,
@@ -258,27 +713,30 @@ pub fn force_matrix_window(
                     }
                     ui.end_row();
 
-                    for i in 0..type_count {
-                        let food_force = genotype.get_food_force(i);
-
-                        let color = if food_force.abs() < 0.05 {
-                            egui::Color32::from_rgb(120, 120, 120)
-                        } else if food_force > 0.0 {
-                            let intensity = (food_force.abs() * 127.5 + 127.5) as u8;
-                            egui::Color32::from_rgb(0, intensity.max(100), 0)
-                        } else {
-                            let intensity = (food_force.abs() * 127.5 + 127.5) as u8;
-                            egui::Color32::from_rgb(intensity.max(100), 0, 0)
-                        };
+                    for food_type in 0..food_type_count {
+                        ui.label(format!("Type {}", food_type));
+                        for i in 0..type_count {
+                            let food_force = genotype.get_food_force(i, food_type);
 
-                        ui.label(
-                            egui::RichText::new(format!("{:+.3}", food_force))
-                                .color(color)
-                                .monospace()
-                                .size(12.0),
-                        );
+                            let color = if food_force.abs() < 0.05 {
+                                egui::Color32::from_rgb(120, 120, 120)
+                            } else if food_force > 0.0 {
+                                let intensity = (food_force.abs() * 127.5 + 127.5) as u8;
+                                egui::Color32::from_rgb(0, intensity.max(100), 0)
+                            } else {
+                                let intensity = (food_force.abs() * 127.5 + 127.5) as u8;
+                                egui::Color32::from_rgb(intensity.max(100), 0, 0)
+                            };
+
+                            ui.label(
+                                egui::RichText::new(format!("{:+.3}", food_force))
+                                    .color(color)
+                                    .monospace()
+                                    .size(12.0),
+                            );
+                        }
+                        ui.end_row();
                     }
-                    ui.end_row();
                 });
 
             ui.add_space(10.0);
@@ -295,9 +753,339 @@ pub fn force_matrix_window(
                 ));
                 ui.label(format!("Types de particules: {}", genotype.type_count));
                 ui.separator();
-                ui.label(egui::RichText::new("Facteur de force appliqué: 80.0").strong());
-                ui.label("Forces réelles = valeurs × 80.0");
+                ui.label(
+                    egui::RichText::new(format!(
+                        "Facteur de force appliqué: {:.1}",
+                        global_config.force_scale_factor
+                    ))
+                    .strong(),
+                );
+                ui.label(format!(
+                    "Forces réelles = valeurs × {:.1}",
+                    global_config.force_scale_factor
+                ));
+
+                if !diversity_history.0.is_empty() {
+                    ui.separator();
+                    ui.label("Diversité de la population par époque:");
+
+                    let points: PlotPoints = diversity_history
+                        .0
+                        .iter()
+                        .enumerate()
+                        .map(|(epoch, diversity)| [epoch as f64, *diversity as f64 * 100.0])
+                        .collect();
+
+                    Plot::new("diversity_sparkline")
+                        .height(60.0)
+                        .show_axes([false, true])
+                        .allow_drag(false)
+                        .allow_zoom(false)
+                        .allow_scroll(false)
+                        .show(ui, |plot_ui| {
+                            plot_ui.line(Line::new(points).name("Diversité (%)"));
+                        });
+                }
+            });
+
+            ui.collapsing("Physics Details", |ui| {
+                // min_r dépend des rayons des deux types en interaction
+                let radii: Vec<f32> = (0..genotype.type_count)
+                    .map(|particle_type| particle_config.get_radius_for_type(particle_type))
+                    .collect();
+                let (min_r_low, min_r_high) = radii
+                    .iter()
+                    .flat_map(|&a| {
+                        radii.iter().map(move |&b| repulsion_distance(a, b))
+                    })
+                    .fold((f32::MAX, 0.0_f32), |(low, high), min_r| {
+                        (low.min(min_r), high.max(min_r))
+                    });
+                let min_r_low = min_r_low.min(min_r_high);
+                let force_range = sim_params.max_force_range;
+
+                if min_r_high - min_r_low < 0.05 {
+                    ui.label(format!("min_r = {:.1} unités", min_r_high));
+                } else {
+                    ui.label(format!(
+                        "min_r = {:.1} – {:.1} unités (selon les rayons)",
+                        min_r_low, min_r_high
+                    ));
+                }
+                ui.label(format!("force_range = {:.0} unités", force_range));
+                ui.label(format!("timestep = {} s", global_config.physics_timestep));
+                ui.label(format!("half_life = {:.3} s", sim_params.velocity_half_life));
+
+                // Profil au min_r moyen, pour une attraction et une répulsion maximales
+                let min_r = (min_r_low + min_r_high) / 2.0;
+                Plot::new("force_profile")
+                    .height(120.0)
+                    .legend(egui_plot::Legend::default())
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .show(ui, |plot_ui| {
+                        for (attraction, name, color) in [
+                            (1.0, "Attraction +1", egui::Color32::from_rgb(100, 220, 100)),
+                            (-1.0, "Attraction −1", egui::Color32::from_rgb(220, 100, 100)),
+                        ] {
+                            let points = PlotPoints::from_explicit_callback(
+                                move |distance| {
+                                    force_profile(min_r, distance as f32, attraction, force_range)
+                                        as f64
+                                },
+                                0.0..force_range as f64,
+                                200,
+                            );
+                            plot_ui.line(Line::new(points).name(name).color(color));
+                        }
+                        plot_ui.vline(
+                            VLine::new(min_r as f64)
+                                .name("min_r")
+                                .color(egui::Color32::GRAY)
+                                .style(egui_plot::LineStyle::dashed_loose()),
+                        );
+                    });
+                ui.label(
+                    egui::RichText::new(
+                        "Sous min_r, répulsion quelle que soit la matrice : \
+                         des types qui s'attirent forment des amas sans se chevaucher",
+                    )
+                    .small()
+                    .color(egui::Color32::GRAY),
+                );
+            });
+
+            ui.collapsing("Lignée", |ui| {
+                match &genotype.lineage {
+                    Some(parents) => {
+                        let parents_text = parents
+                            .iter()
+                            .map(|id| format!("#{}", id + 1))
+                            .collect::<Vec<_>>()
+                            .join(" × ");
+                        ui.label(format!("Parents du génome actuel: {}", parents_text));
+                    }
+                    None => {
+                        ui.label("Génome initial (aucun parent)");
+                    }
+                }
+
+                let Some(last_epoch) = lineage_forest.last_epoch() else {
+                    ui.label(
+                        egui::RichText::new("Aucune époque terminée")
+                            .small()
+                            .color(egui::Color32::from_rgb(150, 150, 150)),
+                    );
+                    return;
+                };
+
+                ui.separator();
+                ui.label(format!("Ascendance (depuis l'époque {}):", last_epoch));
+
+                let mut parents = genotype.lineage.clone().unwrap_or_default();
+                parents.dedup();
+
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        for parent in parents {
+                            for line in
+                                lineage_forest.ancestry_lines(last_epoch, parent, LINEAGE_DEPTH)
+                            {
+                                ui.monospace(line);
+                            }
+                        }
+                    });
+            });
+
+            ui.collapsing("Trajectoire de fitness", |ui| {
+                if fitness_history.0.is_empty() {
+                    ui.label(
+                        egui::RichText::new("Aucune époque terminée")
+                            .small()
+                            .color(egui::Color32::from_rgb(150, 150, 150)),
+                    );
+                    return;
+                }
+
+                ui.checkbox(&mut *show_all_trajectories, "Toutes les simulations");
+
+                let mut simulation_ids: Vec<usize> = if *show_all_trajectories {
+                    fitness_history.0.keys().copied().collect()
+                } else {
+                    vec![selected_sim]
+                };
+                simulation_ids.sort();
+                let simulation_total = fitness_history.0.len().max(1);
+
+                Plot::new("fitness_trajectory")
+                    .height(150.0)
+                    .legend(egui_plot::Legend::default())
+                    .show(ui, |plot_ui| {
+                        for sim_id in simulation_ids {
+                            let points: PlotPoints = fitness_history
+                                .scores(sim_id)
+                                .iter()
+                                .enumerate()
+                                .map(|(epoch, score)| [epoch as f64, *score as f64])
+                                .collect();
+
+                            // Une teinte par simulation, la simulation sélectionnée en gras
+                            let color: egui::Color32 = egui::ecolor::Hsva::new(
+                                sim_id as f32 / simulation_total as f32,
+                                0.8,
+                                0.9,
+                                1.0,
+                            )
+                            .into();
+                            let width = if sim_id == selected_sim { 2.5 } else { 1.0 };
+
+                            plot_ui.line(
+                                Line::new(points)
+                                    .name(format!("Sim #{}", sim_id + 1))
+                                    .color(color)
+                                    .width(width),
+                            );
+                        }
+
+                        // Entropie moyenne des génomes, en pointillés
+                        let entropy_points: PlotPoints = epoch_history
+                            .stats
+                            .iter()
+                            .map(|stats| [stats.epoch as f64, stats.average_entropy as f64])
+                            .collect();
+                        plot_ui.line(
+                            Line::new(entropy_points)
+                                .name("Entropie (bits)")
+                                .color(egui::Color32::from_rgb(180, 180, 255))
+                                .style(egui_plot::LineStyle::dashed_loose()),
+                        );
+
+                        // Époques où la stagnation a déclenché une injection de diversité
+                        for (index, epoch) in epoch_history.stagnation_epochs.iter().enumerate() {
+                            let line = VLine::new(*epoch as f64)
+                                .color(egui::Color32::from_rgb(255, 140, 0))
+                                .style(egui_plot::LineStyle::dashed_loose());
+                            plot_ui.vline(if index == 0 {
+                                line.name("Stagnation")
+                            } else {
+                                line
+                            });
+                        }
+                    });
+
+                if epoch_history.stagnation_events > 0 {
+                    ui.label(
+                        egui::RichText::new(format!(
+                            "⚠ {} stagnation(s) : diversité réinjectée",
+                            epoch_history.stagnation_events
+                        ))
+                        .small()
+                        .color(egui::Color32::from_rgb(255, 140, 0)),
+                    );
+                }
+            });
+
+            ui.collapsing("🏆 Panthéon", |ui| {
+                if hall_of_fame.is_empty() {
+                    ui.label(
+                        egui::RichText::new("Aucun record enregistré")
+                            .small()
+                            .color(egui::Color32::from_rgb(150, 150, 150)),
+                    );
+                    return;
+                }
+
+                egui::ScrollArea::vertical()
+                    .max_height(200.0)
+                    .show(ui, |ui| {
+                        egui::Grid::new("hall_of_fame_grid")
+                            .num_columns(3)
+                            .spacing([20.0, 4.0])
+                            .striped(true)
+                            .show(ui, |ui| {
+                                ui.label(egui::RichText::new("Époque").strong());
+                                ui.label(egui::RichText::new("Score").strong());
+                                ui.label("");
+                                ui.end_row();
+
+                                for (epoch, (entry_genotype, score)) in
+                                    hall_of_fame.entries.iter().rev()
+                                {
+                                    ui.label(format!("{}", epoch));
+                                    ui.label(
+                                        egui::RichText::new(format!("{:.1}", score)).monospace(),
+                                    );
+                                    if ui
+                                        .small_button("👁 Visualiser")
+                                        .on_hover_text("Quitte la simulation et visualise ce génome")
+                                        .clicked()
+                                    {
+                                        genome_to_visualize = Some(entry_genotype.clone());
+                                    }
+                                    ui.end_row();
+                                }
+                            });
+                    });
+            });
+
+            ui.collapsing("Répartition des types", |ui| {
+                // Seules les particules visibles sont comptées
+                let mut counts = vec![0usize; type_count];
+                for (particle_type, parent, visibility) in particles.iter() {
+                    if parent.parent() == simulation_entity
+                        && *visibility != Visibility::Hidden
+                        && particle_type.0 < type_count
+                    {
+                        counts[particle_type.0] += 1;
+                    }
+                }
+                let total = counts.iter().sum::<usize>().max(1);
+
+                let bar_width = 30.0;
+                let chart_height = 100.0;
+                let (rect, _) = ui.allocate_exact_size(
+                    egui::vec2((bar_width + 6.0) * type_count as f32, chart_height),
+                    egui::Sense::hover(),
+                );
+                let painter = ui.painter_at(rect);
+
+                for (type_index, &count) in counts.iter().enumerate() {
+                    let (color, _) = particle_config.get_color_for_type(type_index);
+                    let height = chart_height * count as f32 / total as f32;
+                    let left = rect.left() + type_index as f32 * (bar_width + 6.0);
+                    let bar = egui::Rect::from_min_max(
+                        egui::pos2(left, rect.bottom() - height),
+                        egui::pos2(left + bar_width, rect.bottom()),
+                    );
+                    painter.rect_filled(
+                        bar,
+                        2.0,
+                        egui::Color32::from_rgb(
+                            (color.to_srgba().red * 255.0) as u8,
+                            (color.to_srgba().green * 255.0) as u8,
+                            (color.to_srgba().blue * 255.0) as u8,
+                        ),
+                    );
+                }
+
+                for (type_index, count) in counts.iter().enumerate() {
+                    ui.label(format!(
+                        "{}: {} particules",
+                        particle_config.get_name_for_type(type_index),
+                        count
+                    ));
+                }
             });
         }
     });
+
+    ui_state.export_positions_requested |= export_positions;
+    ui_state.export_positions_every_epoch = export_every_epoch;
+
+    if let Some(genotype) = genome_to_visualize {
+        commands.insert_resource(VisualizerGenome(genotype));
+        next_app_state.set(AppState::Visualization);
+    }
 }
@@ -0,0 +1,112 @@
+use bevy::input::ButtonState;
+use bevy::input::keyboard::{Key, KeyboardInput};
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+/// Aide-mémoire des raccourcis clavier
+#[derive(Resource, Default)]
+pub struct HelpUI {
+    pub show_help: bool,
+}
+
+/// Raccourcis affichés dans l'aide, regroupés par catégorie
+const SHORTCUTS: &[(&str, &[(&str, &str)])] = &[
+    (
+        "Navigation",
+        &[
+            ("Échap", "Retour au menu principal"),
+            ("F", "Basculer entre caméra orbite et vol libre"),
+            ("Molette", "Zoom de la vue survolée (mode orbite)"),
+            ("W / A / S / D", "Déplacement (vol libre)"),
+            ("Q / E", "Descendre / monter (vol libre)"),
+            ("Clic gauche + glisser", "Orbite de la vue survolée / orienter la caméra (vol libre)"),
+            ("Clic gauche sur une vue", "Ouvrir la matrice de forces de cette simulation"),
+        ],
+    ),
+    (
+        "Simulation",
+        &[
+            ("Espace", "Pause / reprise"),
+            ("0 / 1 / 2 / 4 / 8", "Vitesse : pause, x1, x2, x4, x8"),
+            ("Ctrl + S", "Sauvegarde rapide de la meilleure simulation"),
+            ("R", "Forces aléatoires (bac à sable)"),
+            ("P", "Génome prédéfini (bac à sable)"),
+            ("Z", "Remise à zéro des forces (bac à sable)"),
+        ],
+    ),
+    (
+        "Interface",
+        &[
+            ("?", "Afficher / masquer cette aide"),
+            ("Impr. écran", "Capture d'écran dans screenshots/"),
+        ],
+    ),
+];
+
+/// Bascule l'aide avec `?` (touche logique, indépendante de la disposition du clavier)
+pub fn toggle_help_input(
+    mut keyboard_events: EventReader<KeyboardInput>,
+    mut help_ui: ResMut<HelpUI>,
+    mut contexts: EguiContexts,
+) {
+    // `Key` n'est pas `Copy` : pas de `ButtonInput<Key>`, on lit les événements clavier.
+    // Lus avant tout retour anticipé pour ne pas les traiter une frame plus tard
+    let help_presses = keyboard_events
+        .read()
+        .filter(|event| {
+            event.state == ButtonState::Pressed
+                && !event.repeat
+                && matches!(&event.logical_key, Key::Character(text) if text.as_str() == "?")
+        })
+        .count();
+
+    // Ne pas intercepter la saisie dans un champ de texte
+    if contexts
+        .try_ctx_mut()
+        .is_some_and(|ctx| ctx.wants_keyboard_input())
+    {
+        return;
+    }
+
+    if help_presses > 0 {
+        help_ui.show_help = !help_ui.show_help;
+    }
+}
+
+pub fn help_window(mut contexts: EguiContexts, mut help_ui: ResMut<HelpUI>) {
+    if !help_ui.show_help {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+    let mut is_open = true;
+
+    egui::Window::new("⌨ Raccourcis clavier")
+        .open(&mut is_open)
+        .resizable(false)
+        .collapsible(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            for (category, shortcuts) in SHORTCUTS {
+                ui.label(egui::RichText::new(*category).strong());
+
+                egui::Grid::new(format!("help_{}", category))
+                    .num_columns(2)
+                    .spacing([20.0, 4.0])
+                    .striped(true)
+                    .show(ui, |ui| {
+                        for (key, description) in *shortcuts {
+                            ui.label(egui::RichText::new(*key).monospace());
+                            ui.label(*description);
+                            ui.end_row();
+                        }
+                    });
+
+                ui.add_space(8.0);
+            }
+        });
+
+    if !is_open {
+        help_ui.show_help = false;
+    }
+}
@@ -1 +1,8 @@
-pub mod force_matrix;
\ No newline at end of file
+pub mod comparison;
+pub mod debug_resources;
+pub mod epoch_summary;
+pub mod force_matrix;
+pub mod help;
+pub mod obstacles;
+pub mod replay;
+pub mod simulation_complete;
@@ -0,0 +1,117 @@
+use crate::components::entities::obstacle::{Obstacle, ObstacleShape};
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum ObstacleKind {
+    #[default]
+    Sphere,
+    Box,
+}
+
+/// Panneau d'édition des obstacles et forme du prochain obstacle placé au clic
+#[derive(Resource)]
+pub struct ObstacleEditorUI {
+    pub show_panel: bool,
+    pub placing: bool,
+    pub kind: ObstacleKind,
+    pub radius: f32,
+    pub half_extents: Vec3,
+}
+
+impl Default for ObstacleEditorUI {
+    fn default() -> Self {
+        Self {
+            show_panel: false,
+            placing: false,
+            kind: ObstacleKind::default(),
+            radius: 40.0,
+            half_extents: Vec3::splat(30.0),
+        }
+    }
+}
+
+impl ObstacleEditorUI {
+    pub fn shape(&self) -> ObstacleShape {
+        match self.kind {
+            ObstacleKind::Sphere => ObstacleShape::Sphere {
+                radius: self.radius,
+            },
+            ObstacleKind::Box => ObstacleShape::Box {
+                half_extents: self.half_extents,
+            },
+        }
+    }
+}
+
+pub fn obstacle_editor_window(
+    mut contexts: EguiContexts,
+    mut editor: ResMut<ObstacleEditorUI>,
+    mut commands: Commands,
+    obstacles: Query<Entity, With<Obstacle>>,
+) {
+    if !editor.show_panel {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+    let mut is_open = true;
+
+    egui::Window::new("🧱 Edit Obstacles")
+        .open(&mut is_open)
+        .resizable(false)
+        .default_pos([20.0, 120.0])
+        .show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                ui.label("Forme:");
+                ui.selectable_value(&mut editor.kind, ObstacleKind::Sphere, "Sphère");
+                ui.selectable_value(&mut editor.kind, ObstacleKind::Box, "Boîte");
+            });
+
+            match editor.kind {
+                ObstacleKind::Sphere => {
+                    ui.add(
+                        egui::Slider::new(&mut editor.radius, 5.0..=200.0)
+                            .text("Rayon")
+                            .suffix(" unités"),
+                    );
+                }
+                ObstacleKind::Box => {
+                    ui.horizontal(|ui| {
+                        ui.label("Demi-dimensions:");
+                        for (axis, label) in ["x: ", "y: ", "z: "].into_iter().enumerate() {
+                            ui.add(
+                                egui::DragValue::new(&mut editor.half_extents[axis])
+                                    .speed(1.0)
+                                    .range(5.0..=200.0)
+                                    .prefix(label),
+                            );
+                        }
+                    });
+                }
+            }
+
+            ui.separator();
+
+            ui.checkbox(&mut editor.placing, "Placer au clic")
+                .on_hover_text("Clic gauche dans une vue : obstacle posé sur le plan médian de la grille");
+
+            ui.horizontal(|ui| {
+                ui.label(format!("Obstacles: {}", obstacles.iter().count()));
+                if ui
+                    .add_enabled(!obstacles.is_empty(), egui::Button::new("🗑 Tout supprimer"))
+                    .clicked()
+                {
+                    for entity in obstacles.iter() {
+                        commands.entity(entity).despawn();
+                    }
+                    info!("Obstacles supprimés");
+                }
+            });
+        });
+
+    if !is_open {
+        editor.show_panel = false;
+        editor.placing = false;
+    }
+}
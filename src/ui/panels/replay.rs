@@ -0,0 +1,77 @@
+use crate::systems::simulation::replay::SimulationRecorder;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+/// Barre de rejeu du visualiseur : curseur d'image, lecture et pas à pas
+pub fn replay_controls_ui(mut contexts: EguiContexts, mut recorder: ResMut<SimulationRecorder>) {
+    if !recorder.has_recording() {
+        return;
+    }
+
+    let ctx = contexts.ctx_mut();
+
+    egui::TopBottomPanel::bottom("replay_bar").show(ctx, |ui| {
+        ui.horizontal(|ui| {
+            ui.label("🎞 Rejeu:");
+
+            let play_label = if recorder.playing { "⏸" } else { "▶" };
+            if ui
+                .button(play_label)
+                .on_hover_text("Lecture / pause de l'enregistrement")
+                .clicked()
+            {
+                if recorder.playing {
+                    recorder.playing = false;
+                } else {
+                    // Relancer depuis le début si la lecture était arrivée au bout
+                    let start = if recorder.cursor >= recorder.last_frame() {
+                        0
+                    } else {
+                        recorder.cursor
+                    };
+                    recorder.seek(start);
+                    recorder.playing = true;
+                }
+            }
+
+            if ui.button("⏮").on_hover_text("Image précédente").clicked() {
+                let frame = recorder.cursor.saturating_sub(1);
+                recorder.seek(frame);
+                recorder.playing = false;
+            }
+
+            if ui.button("⏭").on_hover_text("Image suivante").clicked() {
+                let frame = recorder.cursor + 1;
+                recorder.seek(frame);
+                recorder.playing = false;
+            }
+
+            let last_frame = recorder.last_frame();
+            let mut frame = recorder.cursor;
+            if ui
+                .add(egui::Slider::new(&mut frame, 0..=last_frame).text("image"))
+                .changed()
+            {
+                recorder.seek(frame);
+                recorder.playing = false;
+            }
+
+            ui.label(format!("{} images", recorder.frames.len()));
+
+            ui.separator();
+
+            if ui
+                .add_enabled(recorder.replaying, egui::Button::new("⏵ Direct"))
+                .on_hover_text("Reprendre la physique depuis l'image affichée")
+                .clicked()
+            {
+                recorder.resume_live();
+            }
+
+            if ui.button("🗑 Clear recording").clicked() {
+                recorder.clear();
+                info!("Enregistrement effacé");
+            }
+        });
+    });
+}
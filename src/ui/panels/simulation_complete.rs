@@ -0,0 +1,112 @@
+use crate::components::entities::simulation::{Simulation, SimulationId};
+use crate::components::genetics::score::Score;
+use crate::resources::config::simulation::SimulationParameters;
+use crate::states::app::AppState;
+use crate::states::simulation::SimulationState;
+use crate::systems::lifecycle::queue_final_save;
+use crate::systems::persistence::population_save::PopulationSaveEvents;
+use crate::systems::simulation::session_stats::{SessionStats, format_duration};
+use crate::ui::panels::force_matrix::ForceMatrixUI;
+use bevy::prelude::*;
+use bevy_egui::{EguiContexts, egui};
+
+/// Époques ajoutées par « Continue Evolving »
+const CONTINUE_EPOCHS: usize = 50;
+
+/// Fenêtre de fin de simulation, tant que l'état `Finished` dure
+pub fn simulation_complete_ui(
+    mut contexts: EguiContexts,
+    mut sim_params: ResMut<SimulationParameters>,
+    mut next_state: ResMut<NextState<SimulationState>>,
+    mut next_app_state: ResMut<NextState<AppState>>,
+    mut save_events: ResMut<PopulationSaveEvents>,
+    mut ui_state: ResMut<ForceMatrixUI>,
+    session_stats: Res<SessionStats>,
+    simulations: Query<(&SimulationId, &Score), With<Simulation>>,
+    mut best_saved: Local<bool>,
+) {
+    let ctx = contexts.ctx_mut();
+    let best = simulations
+        .iter()
+        .max_by(|a, b| a.1.get().partial_cmp(&b.1.get()).unwrap());
+
+    egui::Window::new("🏁 Simulation Complete!")
+        .collapsible(false)
+        .resizable(false)
+        .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+        .show(ctx, |ui| {
+            egui::Grid::new("simulation_complete_grid")
+                .num_columns(2)
+                .spacing([20.0, 4.0])
+                .show(ui, |ui| {
+                    ui.label("Époques:");
+                    ui.label(sim_params.max_epochs.to_string());
+                    ui.end_row();
+
+                    if let Some((sim_id, score)) = best {
+                        ui.label("Gagnante:");
+                        if ui
+                            .link(
+                                egui::RichText::new(format!("Simulation #{}", sim_id.0 + 1))
+                                    .color(egui::Color32::from_rgb(100, 200, 255))
+                                    .strong(),
+                            )
+                            .on_hover_text("Ouvrir sa matrice des forces")
+                            .clicked()
+                        {
+                            ui_state.selected_simulation = Some(sim_id.0);
+                            ui_state.show_matrix_window = true;
+                        }
+                        ui.end_row();
+
+                        ui.label("Meilleur score:");
+                        ui.label(
+                            egui::RichText::new(format!("{:.1}", score.get()))
+                                .color(egui::Color32::from_rgb(0, 255, 0)),
+                        );
+                        ui.end_row();
+                    }
+
+                    ui.label("Temps écoulé:");
+                    ui.label(format_duration(session_stats.run_seconds));
+                    ui.end_row();
+                });
+
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                if ui
+                    .add_enabled(
+                        best.is_some() && !*best_saved,
+                        egui::Button::new(if *best_saved {
+                            "✅ Saved"
+                        } else {
+                            "💾 Save Best"
+                        }),
+                    )
+                    .clicked()
+                {
+                    queue_final_save(&sim_params, &mut save_events, &simulations);
+                    *best_saved = true;
+                }
+
+                if ui
+                    .button("🧬 Continue Evolving")
+                    .on_hover_text(format!("Ajoute {} époques et reprend", CONTINUE_EPOCHS))
+                    .clicked()
+                {
+                    sim_params.max_epochs += CONTINUE_EPOCHS;
+                    *best_saved = false;
+                    next_state.set(SimulationState::Starting);
+                }
+
+                if ui
+                    .button("🏠 New Simulation")
+                    .on_hover_text("Retour au menu principal")
+                    .clicked()
+                {
+                    *best_saved = false;
+                    next_app_state.set(AppState::MainMenu);
+                }
+            });
+        });
+}